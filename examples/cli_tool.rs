@@ -1,4 +1,4 @@
-use examine::examine;
+use examine::{examine_with_options, ExamineOptions};
 use std::env;
 use std::process;
 
@@ -14,8 +14,14 @@ fn main() {
 
     match command.as_str() {
         "analyze" => {
-            let project_path = args.get(2).unwrap_or(&".".to_string()).clone();
-            if let Err(error_message) = analyze_project(&project_path) {
+            let scan_imports = args[2..].iter().any(|arg| arg == "--scan-imports");
+            let project_path = args[2..]
+                .iter()
+                .find(|arg| !arg.starts_with("--"))
+                .cloned()
+                .unwrap_or_else(|| ".".to_string());
+            let options = ExamineOptions::new().with_import_scan(scan_imports);
+            if let Err(error_message) = analyze_project(&project_path, &options) {
                 eprintln!("Error: {}", error_message);
                 process::exit(1);
             }
@@ -31,19 +37,20 @@ fn main() {
 
 fn print_usage(program_name: &str) {
     println!("Examine CLI - Project Analysis Tool");
-    println!("Usage: {} analyze [PATH]", program_name);
+    println!("Usage: {} analyze [PATH] [--scan-imports]", program_name);
     println!("       {} help", program_name);
     println!();
     println!("Examples:");
     println!("  {} analyze .", program_name);
     println!("  {} analyze /path/to/project", program_name);
+    println!("  {} analyze . --scan-imports", program_name);
 }
 
-fn analyze_project(project_path: &str) -> Result<(), String> {
+fn analyze_project(project_path: &str, options: &ExamineOptions) -> Result<(), String> {
     println!("Analyzing project at: {}", project_path);
     println!();
 
-    let project_info = examine(project_path)?;
+    let project_info = examine_with_options(project_path, options)?;
 
     println!("📁 Project: {}", project_info.project_path);
 
@@ -54,13 +61,210 @@ fn analyze_project(project_path: &str) -> Result<(), String> {
     println!("🔤 Language: {}", project_info.language);
 
     if let Some(ref language_version) = project_info.language_version {
-        println!("📋 Version: {}", language_version);
+        match project_info.language_version_source {
+            Some(ref version_source) => {
+                println!("📋 Version: {} (from {})", language_version, version_source)
+            }
+            None => println!("📋 Version: {}", language_version),
+        }
         println!("⚡ Status: {}", project_info.language_status);
     } else {
         println!("📋 Version: Unknown");
         println!("⚡ Status: {}", project_info.language_status);
     }
 
+    for version_conflict in &project_info.version_conflicts {
+        println!(
+            "   ⚠️  {} pins {} {}",
+            version_conflict.conflicting_source,
+            version_conflict.language,
+            version_conflict.conflicting_version
+        );
+    }
+
+    for version_consistency in &project_info.version_consistency {
+        if version_consistency.is_consistent() {
+            continue;
+        }
+        println!("🔀 {} version mismatch:", version_consistency.language);
+        for version_conflict in &version_consistency.conflicts {
+            println!(
+                "   ⚠️  {} ({}) vs {} ({})",
+                version_conflict.version,
+                version_conflict
+                    .version_source
+                    .as_deref()
+                    .unwrap_or("unknown"),
+                version_conflict.conflicting_version,
+                version_conflict.conflicting_source
+            );
+        }
+    }
+
+    if !project_info.tool_versions.is_empty() {
+        let pinned_tools: Vec<String> = project_info
+            .tool_versions
+            .iter()
+            .map(|tool_version| format!("{} {}", tool_version.tool, tool_version.version))
+            .collect();
+        println!("🧰 Tool Versions: {}", pinned_tools.join(", "));
+    }
+
+    if let Some(ref rust_toolchain) = project_info.rust_toolchain {
+        if let Some(ref edition) = rust_toolchain.edition {
+            println!("🦀 Edition: {}", edition);
+        }
+        let mut toolchain_targets: Vec<&str> = Vec::new();
+        for target_name in rust_toolchain
+            .targets
+            .iter()
+            .chain(rust_toolchain.build_targets.iter())
+        {
+            if !toolchain_targets.contains(&target_name.as_str()) {
+                toolchain_targets.push(target_name);
+            }
+        }
+        if !toolchain_targets.is_empty() {
+            println!("🎯 Targets: {}", toolchain_targets.join(", "));
+        }
+    }
+
+    if !project_info.build_tools.is_empty() {
+        let build_tool_names: Vec<String> = project_info
+            .build_tools
+            .iter()
+            .map(|build_tool| match build_tool.version {
+                Some(ref tool_version) => format!("{} {}", build_tool.name, tool_version),
+                None => build_tool.name.clone(),
+            })
+            .collect();
+        println!("🔨 Build: {}", build_tool_names.join(", "));
+    }
+
+    if let Some(ref test_setup) = project_info.testing {
+        let test_framework_names: Vec<&str> = test_setup
+            .frameworks
+            .iter()
+            .map(|test_framework| test_framework.name.as_str())
+            .collect();
+        if !test_framework_names.is_empty() {
+            println!("🧪 Tests: {}", test_framework_names.join(", "));
+        }
+        if !test_setup.test_directories.is_empty() {
+            println!("   Directories: {}", test_setup.test_directories.join(", "));
+        }
+    }
+
+    for code_quality_tool in &project_info.code_quality {
+        let tool_settings: Vec<String> = code_quality_tool
+            .settings
+            .iter()
+            .map(|(setting_key, setting_value)| format!("{}={}", setting_key, setting_value))
+            .collect();
+        if tool_settings.is_empty() {
+            println!(
+                "🧹 {}: {} ({})",
+                code_quality_tool.kind, code_quality_tool.name, code_quality_tool.config_file
+            );
+        } else {
+            println!(
+                "🧹 {}: {} ({}) {}",
+                code_quality_tool.kind,
+                code_quality_tool.name,
+                code_quality_tool.config_file,
+                tool_settings.join(" ")
+            );
+        }
+    }
+
+    for ci_pipeline in &project_info.ci_pipelines {
+        let tested_versions: Vec<String> = ci_pipeline
+            .tested_versions
+            .iter()
+            .map(|(language, versions)| format!("{} {}", language, versions.join("/")))
+            .collect();
+        if tested_versions.is_empty() {
+            println!(
+                "🚦 CI: {} ({})",
+                ci_pipeline.provider, ci_pipeline.config_file
+            );
+        } else {
+            println!(
+                "🚦 CI: {} ({}) tests {}",
+                ci_pipeline.provider,
+                ci_pipeline.config_file,
+                tested_versions.join(", ")
+            );
+        }
+    }
+
+    for dockerfile in &project_info.dockerfiles {
+        println!(
+            "🐳 {}: {} stage(s)",
+            dockerfile.path,
+            dockerfile.stages.len()
+        );
+        for build_stage in &dockerfile.stages {
+            let stage_label = build_stage.name.as_deref().unwrap_or("(unnamed)");
+            match build_stage.runtime {
+                Some(ref image_runtime) => println!(
+                    "   {}: {} ({} {} {})",
+                    stage_label,
+                    build_stage.base_image,
+                    image_runtime.language,
+                    image_runtime.version,
+                    image_runtime.status
+                ),
+                None => println!("   {}: {}", stage_label, build_stage.base_image),
+            }
+        }
+        if let Some(final_stage) = dockerfile.final_stage() {
+            if !final_stage.exposed_ports.is_empty() {
+                println!("   Ports: {}", final_stage.exposed_ports.join(", "));
+            }
+            if let Some(ref entrypoint) = final_stage.entrypoint {
+                println!("   Entrypoint: {}", entrypoint);
+            }
+            if let Some(ref cmd) = final_stage.cmd {
+                println!("   Cmd: {}", cmd);
+            }
+        }
+    }
+
+    for compose_service in &project_info.compose_services {
+        let image_label = compose_service.image.as_deref().unwrap_or("built locally");
+        match (&compose_service.technology, &compose_service.kind) {
+            (Some(technology), Some(kind)) => println!(
+                "🧱 Service {}: {} {} ({}) {}",
+                compose_service.name,
+                technology,
+                compose_service.version.as_deref().unwrap_or("latest"),
+                kind,
+                compose_service.status
+            ),
+            _ => println!("🧱 Service {}: {}", compose_service.name, image_label),
+        }
+    }
+
+    if let Some(ref notebook_summary) = project_info.notebooks {
+        match notebook_summary.kernel_name {
+            Some(ref kernel_name) => println!(
+                "📓 Notebooks: {} (kernel: {})",
+                notebook_summary.notebook_count, kernel_name
+            ),
+            None => println!("📓 Notebooks: {}", notebook_summary.notebook_count),
+        }
+    }
+
+    if let Some(ref runtime_name) = project_info.runtime {
+        match project_info.runtime_version {
+            Some(ref runtime_version) => {
+                println!("⚙️  Runtime: {} {}", runtime_name, runtime_version)
+            }
+            None => println!("⚙️  Runtime: {}", runtime_name),
+        }
+    }
+
     if let Some(ref framework_name) = project_info.framework {
         println!("🚀 Framework: {}", framework_name);
 
@@ -68,6 +272,10 @@ fn analyze_project(project_path: &str) -> Result<(), String> {
             println!("   Version: {}", framework_version);
         }
 
+        if let Some(framework_category) = project_info.framework_category {
+            println!("   Category: {}", framework_category);
+        }
+
         if let Some(ref framework_details) = project_info.framework_details {
             println!("   Type: {}", framework_details.framework_type);
             println!(
@@ -94,6 +302,39 @@ fn analyze_project(project_path: &str) -> Result<(), String> {
         println!("🚀 Framework: None detected");
     }
 
+    if let Some(ref dependency_report) = project_info.dependency_report {
+        println!(
+            "🔎 Imports: {} source files scanned",
+            dependency_report.source_file_count
+        );
+        if !dependency_report.unused_dependencies.is_empty() {
+            println!(
+                "   Unused: {}",
+                dependency_report.unused_dependencies.join(", ")
+            );
+        }
+        if !dependency_report.undeclared_imports.is_empty() {
+            println!(
+                "   Undeclared: {}",
+                dependency_report.undeclared_imports.join(", ")
+            );
+        }
+    }
+
+    if !project_info.members.is_empty() {
+        println!("🧩 Members:");
+        for member_project in &project_info.members {
+            println!(
+                "   {} ({})",
+                member_project
+                    .project_name
+                    .as_deref()
+                    .unwrap_or(&member_project.project_path),
+                member_project.summary()
+            );
+        }
+    }
+
     println!();
     println!("✨ Summary: {}", project_info.summary());
 
@@ -23,6 +23,13 @@ fn main() -> Result<(), String> {
         println!("⚡ Status: {}", info.language_status);
     }
 
+    if let Some(ref runtime) = info.runtime {
+        match info.runtime_version {
+            Some(ref runtime_version) => println!("⚙️  Runtime: {} {}", runtime, runtime_version),
+            None => println!("⚙️  Runtime: {}", runtime),
+        }
+    }
+
     if let Some(ref framework) = info.framework {
         println!("🚀 Framework: {}", framework);
 
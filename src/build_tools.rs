@@ -0,0 +1,402 @@
+//! Build system detection.
+//!
+//! Build tools are found from their own files (`pom.xml`, `CMakeLists.txt`,
+//! `vite.config.ts`, ...) and, for JavaScript bundlers, from `package.json`.
+//! Versions come from wrapper and pin files (`gradle-wrapper.properties`,
+//! `.bazelversion`, `project/build.properties`), minimum-version declarations
+//! (`cmake_minimum_required`, `AC_PREREQ`) or the declared dependency.
+
+use crate::project_info::BuildTool;
+use crate::requirements;
+use std::fs;
+use std::path::Path;
+
+/// JavaScript bundlers and compilers: `(package, tool, config file prefixes)`
+const JAVASCRIPT_BUILD_TOOLS: [(&str, &str, &[&str]); 6] = [
+    ("vite", "Vite", &["vite.config."]),
+    ("webpack", "Webpack", &["webpack.config."]),
+    ("rollup", "Rollup", &["rollup.config."]),
+    ("esbuild", "esbuild", &[]),
+    ("parcel", "Parcel", &[".parcelrc"]),
+    ("typescript", "tsc", &["tsconfig.json"]),
+];
+
+/// PEP 517 backends: `(build-backend, tool, distribution)`
+const PYTHON_BUILD_BACKENDS: [(&str, &str, &str); 8] = [
+    ("setuptools.build_meta", "setuptools", "setuptools"),
+    ("hatchling.build", "hatchling", "hatchling"),
+    ("maturin", "maturin", "maturin"),
+    ("poetry.core.masonry.api", "poetry-core", "poetry-core"),
+    ("flit_core.buildapi", "flit-core", "flit-core"),
+    ("pdm.backend", "pdm-backend", "pdm-backend"),
+    (
+        "scikit_build_core.build",
+        "scikit-build-core",
+        "scikit-build-core",
+    ),
+    ("mesonpy", "meson-python", "meson-python"),
+];
+
+/// Every build tool the project uses, primary build system first
+pub fn detect_build_tools(project_path: &Path) -> Vec<BuildTool> {
+    let mut build_tools = Vec::new();
+
+    if project_path.join("Cargo.toml").exists() {
+        push_build_tool(&mut build_tools, "Cargo", None, "Cargo.toml");
+    }
+
+    if project_path.join("pom.xml").exists() {
+        let maven_version = read_properties_value(
+            &project_path.join(".mvn/wrapper/maven-wrapper.properties"),
+            "distributionUrl",
+        )
+        .and_then(|distribution_url| distribution_version(&distribution_url, "apache-maven-"));
+        push_build_tool(&mut build_tools, "Maven", maven_version, "pom.xml");
+    }
+
+    if let Some(gradle_file) = [
+        "build.gradle.kts",
+        "build.gradle",
+        "settings.gradle.kts",
+        "settings.gradle",
+    ]
+    .into_iter()
+    .find(|gradle_file| project_path.join(gradle_file).exists())
+    {
+        let gradle_version = read_properties_value(
+            &project_path.join("gradle/wrapper/gradle-wrapper.properties"),
+            "distributionUrl",
+        )
+        .and_then(|distribution_url| distribution_version(&distribution_url, "gradle-"));
+        push_build_tool(&mut build_tools, "Gradle", gradle_version, gradle_file);
+    }
+
+    if project_path.join("build.sbt").exists() {
+        let sbt_version = read_properties_value(
+            &project_path.join("project/build.properties"),
+            "sbt.version",
+        );
+        push_build_tool(&mut build_tools, "sbt", sbt_version, "build.sbt");
+    }
+
+    if let Some(bazel_file) = ["MODULE.bazel", "WORKSPACE.bazel", "WORKSPACE"]
+        .into_iter()
+        .find(|bazel_file| project_path.join(bazel_file).exists())
+    {
+        let bazel_version = read_first_line(&project_path.join(".bazelversion"));
+        push_build_tool(&mut build_tools, "Bazel", bazel_version, bazel_file);
+    }
+
+    if project_path.join(".buckconfig").exists() {
+        push_build_tool(&mut build_tools, "Buck2", None, ".buckconfig");
+    }
+
+    if let Ok(pants_content) = fs::read_to_string(project_path.join("pants.toml")) {
+        let pants_version =
+            toml::from_str::<toml::Value>(&pants_content)
+                .ok()
+                .and_then(|pants_toml| {
+                    pants_toml
+                        .get("GLOBAL")?
+                        .get("pants_version")?
+                        .as_str()
+                        .map(|version| version.to_string())
+                });
+        push_build_tool(&mut build_tools, "Pants", pants_version, "pants.toml");
+    }
+
+    if let Ok(cmake_content) = fs::read_to_string(project_path.join("CMakeLists.txt")) {
+        let cmake_version =
+            regex::Regex::new(r"(?i)cmake_minimum_required\s*\(\s*VERSION\s+([0-9]+(?:\.[0-9]+)*)")
+                .unwrap()
+                .captures(&cmake_content)
+                .map(|captures| captures[1].to_string());
+        push_build_tool(&mut build_tools, "CMake", cmake_version, "CMakeLists.txt");
+    }
+
+    if let Ok(meson_content) = fs::read_to_string(project_path.join("meson.build")) {
+        let meson_version =
+            regex::Regex::new(r#"meson_version\s*:\s*'[<>=!~\s]*([0-9]+(?:\.[0-9]+)*)"#)
+                .unwrap()
+                .captures(&meson_content)
+                .map(|captures| captures[1].to_string());
+        push_build_tool(&mut build_tools, "Meson", meson_version, "meson.build");
+    }
+
+    if project_path.join("build.ninja").exists() {
+        push_build_tool(&mut build_tools, "Ninja", None, "build.ninja");
+    }
+
+    if let Some(autoconf_file) = ["configure.ac", "configure.in", "Makefile.am"]
+        .into_iter()
+        .find(|autoconf_file| project_path.join(autoconf_file).exists())
+    {
+        let autoconf_version = fs::read_to_string(project_path.join(autoconf_file))
+            .ok()
+            .and_then(|autoconf_content| {
+                regex::Regex::new(r"AC_PREREQ\(\s*\[?([0-9]+(?:\.[0-9]+)*)")
+                    .unwrap()
+                    .captures(&autoconf_content)
+                    .map(|captures| captures[1].to_string())
+            });
+        push_build_tool(
+            &mut build_tools,
+            "Autotools",
+            autoconf_version,
+            autoconf_file,
+        );
+    }
+
+    if let Some(scons_file) = ["SConstruct", "SConscript"]
+        .into_iter()
+        .find(|scons_file| project_path.join(scons_file).exists())
+    {
+        push_build_tool(&mut build_tools, "SCons", None, scons_file);
+    }
+
+    detect_javascript_build_tools(project_path, &mut build_tools);
+    detect_python_build_backend(project_path, &mut build_tools);
+
+    build_tools
+}
+
+fn detect_javascript_build_tools(project_path: &Path, build_tools: &mut Vec<BuildTool>) {
+    let package_json: Option<serde_json::Value> =
+        fs::read_to_string(project_path.join("package.json"))
+            .ok()
+            .and_then(|package_content| serde_json::from_str(&package_content).ok());
+    let declared_version = |package_name: &str| {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .filter_map(|dependency_group| package_json.as_ref()?.get(dependency_group))
+            .find_map(|dependencies| dependencies.get(package_name))
+            .map(|version| version.as_str().unwrap_or_default().to_string())
+    };
+    let project_files: Vec<String> = fs::read_dir(project_path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|directory_entry| directory_entry.file_name().to_string_lossy().to_string())
+        .collect();
+
+    // Next.js builds with Turbopack when a script passes `--turbo` or `--turbopack`
+    let uses_turbopack = package_json
+        .as_ref()
+        .and_then(|package_json| package_json.get("scripts"))
+        .and_then(|scripts| scripts.as_object())
+        .is_some_and(|scripts| {
+            scripts
+                .values()
+                .filter_map(|script| script.as_str())
+                .any(|script| {
+                    script.contains("next ")
+                        && script
+                            .split_whitespace()
+                            .any(|argument| argument == "--turbo" || argument == "--turbopack")
+                })
+        });
+    if uses_turbopack {
+        push_build_tool(
+            build_tools,
+            "Turbopack",
+            declared_version("next"),
+            "package.json",
+        );
+    }
+
+    for (package_name, tool_name, config_prefixes) in JAVASCRIPT_BUILD_TOOLS {
+        let config_file = project_files.iter().find(|file_name| {
+            config_prefixes
+                .iter()
+                .any(|config_prefix| file_name.starts_with(config_prefix))
+        });
+        match (declared_version(package_name), config_file) {
+            (Some(tool_version), _) => {
+                let tool_version = (!tool_version.is_empty()).then_some(tool_version);
+                push_build_tool(build_tools, tool_name, tool_version, "package.json");
+            }
+            (None, Some(config_file)) => push_build_tool(build_tools, tool_name, None, config_file),
+            (None, None) => {}
+        }
+    }
+}
+
+fn detect_python_build_backend(project_path: &Path, build_tools: &mut Vec<BuildTool>) {
+    let build_system = fs::read_to_string(project_path.join("pyproject.toml"))
+        .ok()
+        .and_then(|pyproject_content| toml::from_str::<toml::Value>(&pyproject_content).ok())
+        .and_then(|pyproject| pyproject.get("build-system").cloned());
+
+    let Some(build_system) = build_system else {
+        // Without `[build-system]`, pip falls back to setuptools' legacy `setup.py` path
+        if project_path.join("setup.py").exists() {
+            push_build_tool(build_tools, "setuptools", None, "setup.py");
+        }
+        return;
+    };
+
+    let build_requirements: Vec<requirements::Requirement> = build_system
+        .get("requires")
+        .and_then(|requires| requires.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|requirement_value| requirement_value.as_str())
+        .filter_map(requirements::parse_requirement)
+        .collect();
+    let build_backend = build_system
+        .get("build-backend")
+        .and_then(|backend| backend.as_str())
+        // PEP 517 defaults to setuptools' legacy backend
+        .unwrap_or("setuptools.build_meta");
+
+    if let Some((_, tool_name, distribution_name)) =
+        PYTHON_BUILD_BACKENDS.iter().find(|(backend_module, _, _)| {
+            build_backend == *backend_module
+                || build_backend.starts_with(&format!("{}:", backend_module))
+                || build_backend.starts_with(&format!("{}.", backend_module))
+        })
+    {
+        let backend_version = build_requirements
+            .iter()
+            .find(|build_requirement| build_requirement.name == *distribution_name)
+            .and_then(|build_requirement| build_requirement.version());
+        push_build_tool(build_tools, tool_name, backend_version, "pyproject.toml");
+    }
+}
+
+fn push_build_tool(
+    build_tools: &mut Vec<BuildTool>,
+    tool_name: &str,
+    tool_version: Option<String>,
+    source_file: &str,
+) {
+    if build_tools
+        .iter()
+        .any(|build_tool| build_tool.name == tool_name)
+    {
+        return;
+    }
+    build_tools.push(BuildTool {
+        name: tool_name.to_string(),
+        version: tool_version,
+        source: source_file.to_string(),
+    });
+}
+
+/// A `key=value` entry from a Java `.properties` file
+fn read_properties_value(properties_path: &Path, key: &str) -> Option<String> {
+    let properties_content = fs::read_to_string(properties_path).ok()?;
+    properties_content.lines().find_map(|content_line| {
+        let (line_key, line_value) = content_line.split_once('=')?;
+        (line_key.trim() == key).then(|| line_value.trim().to_string())
+    })
+}
+
+/// `.../gradle-8.5-bin.zip` becomes `8.5`
+fn distribution_version(distribution_url: &str, archive_prefix: &str) -> Option<String> {
+    let archive_name = distribution_url.rsplit('/').next()?;
+    let archive_version = archive_name.strip_prefix(archive_prefix)?;
+    let archive_version = archive_version
+        .strip_suffix("-bin.zip")
+        .or_else(|| archive_version.strip_suffix("-all.zip"))
+        .or_else(|| archive_version.strip_suffix(".zip"))
+        .unwrap_or(archive_version);
+    (!archive_version.is_empty()).then(|| archive_version.to_string())
+}
+
+fn read_first_line(file_path: &Path) -> Option<String> {
+    let file_content = fs::read_to_string(file_path).ok()?;
+    let first_line = file_content.lines().next()?.trim();
+    (!first_line.is_empty()).then(|| first_line.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_jvm_and_native_build_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("gradle/wrapper")).unwrap();
+        fs::write(
+            temp_dir.path().join("build.gradle.kts"),
+            "plugins { java }\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("gradle/wrapper/gradle-wrapper.properties"),
+            "distributionBase=GRADLE_USER_HOME\ndistributionUrl=https\\://services.gradle.org/distributions/gradle-8.5-bin.zip\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("CMakeLists.txt"),
+            "cmake_minimum_required(VERSION 3.16...3.28)\nproject(native)\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("MODULE.bazel"),
+            "module(name = \"app\")\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join(".bazelversion"), "7.0.2\n").unwrap();
+
+        let build_tools = detect_build_tools(temp_dir.path());
+        let tool_summary: Vec<(&str, Option<&str>)> = build_tools
+            .iter()
+            .map(|build_tool| (build_tool.name.as_str(), build_tool.version.as_deref()))
+            .collect();
+        assert_eq!(
+            tool_summary,
+            vec![
+                ("Gradle", Some("8.5")),
+                ("Bazel", Some("7.0.2")),
+                ("CMake", Some("3.16")),
+            ]
+        );
+        assert_eq!(build_tools[0].source, "build.gradle.kts");
+    }
+
+    #[test]
+    fn test_detect_javascript_and_python_build_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"scripts": {"dev": "next dev --turbopack"},
+                "dependencies": {"next": "14.2.0"},
+                "devDependencies": {"typescript": "^5.4.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("rollup.config.mjs"),
+            "export default {};\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[build-system]\nrequires = [\"maturin>=1.4,<2.0\"]\nbuild-backend = \"maturin\"\n",
+        )
+        .unwrap();
+
+        let build_tools = detect_build_tools(temp_dir.path());
+        let tool_summary: Vec<(&str, Option<&str>, &str)> = build_tools
+            .iter()
+            .map(|build_tool| {
+                (
+                    build_tool.name.as_str(),
+                    build_tool.version.as_deref(),
+                    build_tool.source.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            tool_summary,
+            vec![
+                ("Turbopack", Some("14.2.0"), "package.json"),
+                ("Rollup", None, "rollup.config.mjs"),
+                ("tsc", Some("^5.4.0"), "package.json"),
+                ("maturin", Some("1.4"), "pyproject.toml"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! CI/CD pipeline detection.
+//!
+//! Pipelines are found from each provider's config file. The YAML is read
+//! line by line for the keys that pin a language version: setup action
+//! inputs (`node-version`, `python-version`, ...), Rust toolchains, Azure
+//! tool tasks and container images. Matrix references such as
+//! `${{ matrix.node }}` or `$(python.version)` are resolved against the
+//! values of that key elsewhere in the same file.
+
+use crate::config_files::{strip_yaml_comment, yaml_indentation};
+use crate::container_images::image_language_version;
+use crate::project_info::CiPipeline;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Config files per provider; entries ending in `/` are directories of YAML files
+const CI_CONFIG_FILES: [(&str, &[&str]); 8] = [
+    ("GitHub Actions", &[".github/workflows/"]),
+    ("GitLab CI", &[".gitlab-ci.yml", ".gitlab-ci.yaml"]),
+    (
+        "CircleCI",
+        &[".circleci/config.yml", ".circleci/config.yaml"],
+    ),
+    (
+        "Azure Pipelines",
+        &[
+            "azure-pipelines.yml",
+            "azure-pipelines.yaml",
+            ".azure-pipelines.yml",
+        ],
+    ),
+    ("Jenkins", &["Jenkinsfile"]),
+    (
+        "Buildkite",
+        &[".buildkite/pipeline.yml", ".buildkite/pipeline.yaml"],
+    ),
+    ("Drone", &[".drone.yml", ".drone.yaml"]),
+    (
+        "Woodpecker",
+        &[".woodpecker.yml", ".woodpecker.yaml", ".woodpecker/"],
+    ),
+];
+
+/// Setup action inputs and the language whose version they set
+const VERSION_KEYS: [(&str, &str); 7] = [
+    ("node-version", "JavaScript"),
+    ("python-version", "Python"),
+    ("go-version", "Go"),
+    ("java-version", "Java"),
+    ("ruby-version", "Ruby"),
+    ("php-version", "PHP"),
+    ("elixir-version", "Elixir"),
+];
+
+/// Azure Pipelines tasks whose `versionSpec` input sets a language version
+const AZURE_TOOL_TASKS: [(&str, &str); 6] = [
+    ("NodeTool", "JavaScript"),
+    ("UseNode", "JavaScript"),
+    ("UsePythonVersion", "Python"),
+    ("GoTool", "Go"),
+    ("JavaToolInstaller", "Java"),
+    ("UseRubyVersion", "Ruby"),
+];
+
+/// Rust channel names accepted as toolchain versions besides numbered releases
+const RUST_CHANNELS: [&str; 3] = ["stable", "beta", "nightly"];
+
+pub fn detect_ci_pipelines(project_path: &Path) -> Vec<CiPipeline> {
+    let mut ci_pipelines = Vec::new();
+
+    for (provider, config_files) in CI_CONFIG_FILES {
+        for config_file in config_files {
+            if let Some(config_directory) = config_file.strip_suffix('/') {
+                let Ok(directory_entries) = fs::read_dir(project_path.join(config_directory))
+                else {
+                    continue;
+                };
+                let mut yaml_file_names: Vec<String> = directory_entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .filter(|file_name| file_name.ends_with(".yml") || file_name.ends_with(".yaml"))
+                    .collect();
+                yaml_file_names.sort();
+                for yaml_file_name in yaml_file_names {
+                    ci_pipelines.extend(read_ci_pipeline(
+                        project_path,
+                        provider,
+                        &format!("{}/{}", config_directory, yaml_file_name),
+                    ));
+                }
+            } else {
+                ci_pipelines.extend(read_ci_pipeline(project_path, provider, config_file));
+            }
+        }
+    }
+
+    ci_pipelines
+}
+
+fn read_ci_pipeline(project_path: &Path, provider: &str, config_file: &str) -> Option<CiPipeline> {
+    let config_content = fs::read_to_string(project_path.join(config_file)).ok()?;
+    let tested_versions = if provider == "Jenkins" {
+        extract_jenkinsfile_versions(&config_content)
+    } else {
+        extract_yaml_versions(&config_content)
+    };
+
+    Some(CiPipeline {
+        provider: provider.to_string(),
+        config_file: config_file.to_string(),
+        tested_versions,
+    })
+}
+
+/// Language versions from setup inputs, toolchains, Azure tasks and images
+fn extract_yaml_versions(yaml_content: &str) -> BTreeMap<String, Vec<String>> {
+    let yaml_lines: Vec<&str> = yaml_content.lines().map(strip_yaml_comment).collect();
+    let mut tested_versions = BTreeMap::new();
+    let mut azure_task_language: Option<&str> = None;
+
+    for (line_index, yaml_line) in yaml_lines.iter().enumerate() {
+        let Some((yaml_key, yaml_value)) = split_yaml_entry(yaml_line) else {
+            continue;
+        };
+
+        if let Some((_, language)) = VERSION_KEYS
+            .iter()
+            .find(|(version_key, _)| *version_key == yaml_key)
+        {
+            for version in resolve_yaml_values(&yaml_lines, line_index) {
+                add_tested_version(&mut tested_versions, language, &version);
+            }
+            continue;
+        }
+
+        match yaml_key {
+            // actions-rs/toolchain and dtolnay/rust-toolchain inputs
+            "toolchain" => {
+                for version in resolve_yaml_values(&yaml_lines, line_index) {
+                    add_tested_version(&mut tested_versions, "Rust", &version);
+                }
+            }
+            // `dtolnay/rust-toolchain@1.75` pins the toolchain in the action ref
+            "uses" => {
+                if let Some(toolchain_ref) = yaml_value.strip_prefix("dtolnay/rust-toolchain@") {
+                    add_tested_version(&mut tested_versions, "Rust", toolchain_ref);
+                }
+            }
+            "image" | "container" => {
+                if let Some((language, version)) = image_language_version(yaml_value) {
+                    add_tested_version(&mut tested_versions, language, &version);
+                }
+            }
+            "task" => {
+                let task_name = yaml_value.split('@').next().unwrap_or(yaml_value);
+                azure_task_language = AZURE_TOOL_TASKS
+                    .iter()
+                    .find(|(tool_task, _)| *tool_task == task_name)
+                    .map(|(_, language)| *language);
+            }
+            "versionSpec" => {
+                if let Some(language) = azure_task_language {
+                    for version in resolve_yaml_values(&yaml_lines, line_index) {
+                        add_tested_version(&mut tested_versions, language, &version);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tested_versions
+}
+
+/// Language versions from the Docker agents of a declarative or scripted Jenkinsfile
+fn extract_jenkinsfile_versions(jenkinsfile_content: &str) -> BTreeMap<String, Vec<String>> {
+    let image_regex = regex::Regex::new(r#"image\s*\(?\s*['"]([^'"]+)['"]"#).unwrap();
+    let mut tested_versions = BTreeMap::new();
+
+    for captures in image_regex.captures_iter(jenkinsfile_content) {
+        if let Some((language, version)) = image_language_version(&captures[1]) {
+            add_tested_version(&mut tested_versions, language, &version);
+        }
+    }
+
+    tested_versions
+}
+
+fn add_tested_version(
+    tested_versions: &mut BTreeMap<String, Vec<String>>,
+    language: &str,
+    raw_version: &str,
+) {
+    let mut version = raw_version.trim().trim_matches(['"', '\'']);
+    // `18.x` and `3.*` mean the latest release of that line
+    while let Some(version_prefix) = version
+        .strip_suffix(".x")
+        .or_else(|| version.strip_suffix(".*"))
+    {
+        version = version_prefix;
+    }
+
+    let is_version = version.starts_with(|c: char| c.is_ascii_digit())
+        || (language == "Rust"
+            && RUST_CHANNELS
+                .iter()
+                .any(|channel| version.starts_with(channel)));
+    if !is_version || version.contains(['$', ' ']) {
+        return;
+    }
+
+    let language_versions = tested_versions.entry(language.to_string()).or_default();
+    if !language_versions.iter().any(|known| known == version) {
+        language_versions.push(version.to_string());
+    }
+}
+
+/// The values of the key on `line_index`, with matrix and variable references resolved
+fn resolve_yaml_values(yaml_lines: &[&str], line_index: usize) -> Vec<String> {
+    let mut resolved_values = Vec::new();
+
+    for yaml_value in read_yaml_values(yaml_lines, line_index) {
+        match variable_reference(&yaml_value) {
+            Some(variable_name) => {
+                for (defining_index, defining_line) in yaml_lines.iter().enumerate() {
+                    if split_yaml_entry(defining_line)
+                        .is_some_and(|(yaml_key, _)| yaml_key == variable_name)
+                    {
+                        resolved_values.extend(
+                            read_yaml_values(yaml_lines, defining_index)
+                                .into_iter()
+                                .filter(|value| variable_reference(value).is_none()),
+                        );
+                    }
+                }
+            }
+            None => resolved_values.push(yaml_value),
+        }
+    }
+
+    resolved_values
+}
+
+/// `node` for `${{ matrix.node }}`, `python.version` for Azure's `$(python.version)`
+fn variable_reference(yaml_value: &str) -> Option<&str> {
+    if let Some(expression) = yaml_value
+        .strip_prefix("${{")
+        .and_then(|expression| expression.strip_suffix("}}"))
+    {
+        return expression.trim().strip_prefix("matrix.");
+    }
+    yaml_value
+        .strip_prefix("$(")
+        .and_then(|variable_name| variable_name.strip_suffix(')'))
+}
+
+/// A scalar, a `[a, b]` flow list, or the `- a` block list under the key
+fn read_yaml_values(yaml_lines: &[&str], line_index: usize) -> Vec<String> {
+    let Some((_, yaml_value)) = split_yaml_entry(yaml_lines[line_index]) else {
+        return Vec::new();
+    };
+
+    if let Some(flow_list) = yaml_value
+        .strip_prefix('[')
+        .and_then(|flow_list| flow_list.strip_suffix(']'))
+    {
+        return flow_list
+            .split(',')
+            .map(|list_item| list_item.trim().trim_matches(['"', '\'']).to_string())
+            .filter(|list_item| !list_item.is_empty())
+            .collect();
+    }
+    if !yaml_value.is_empty() {
+        return vec![yaml_value.to_string()];
+    }
+
+    let key_indent = yaml_indentation(yaml_lines[line_index]);
+    let mut block_items = Vec::new();
+    for yaml_line in &yaml_lines[line_index + 1..] {
+        let trimmed_line = yaml_line.trim();
+        if trimmed_line.is_empty() {
+            continue;
+        }
+        match trimmed_line.strip_prefix("- ") {
+            Some(list_item)
+                if yaml_indentation(yaml_line) >= key_indent && !list_item.contains(": ") =>
+            {
+                block_items.push(list_item.trim().trim_matches(['"', '\'']).to_string());
+            }
+            _ => break,
+        }
+    }
+    block_items
+}
+
+/// The key and value of a `key: value` line, also inside a `- key: value` list item
+fn split_yaml_entry(yaml_line: &str) -> Option<(&str, &str)> {
+    let trimmed_line = yaml_line.trim();
+    let trimmed_line = trimmed_line.strip_prefix("- ").unwrap_or(trimmed_line);
+    let (yaml_key, yaml_value) = trimmed_line.split_once(':')?;
+    let yaml_key = yaml_key.trim().trim_matches(['"', '\'']);
+    if yaml_key.is_empty() || yaml_key.contains(' ') {
+        return None;
+    }
+    Some((yaml_key, yaml_value.trim().trim_matches(['"', '\''])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_github_actions_matrix_versions() {
+        let workflow = r#"
+name: CI
+on: [push]
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    strategy:
+      matrix:
+        node: [18.x, "20.x"] # LTS lines
+        python-version:
+          - "3.11"
+          - "3.12"
+    steps:
+      - uses: actions/setup-node@v4
+        with:
+          node-version: ${{ matrix.node }}
+      - uses: actions/setup-python@v5
+        with:
+          python-version: ${{ matrix.python-version }}
+      - uses: dtolnay/rust-toolchain@1.75
+      - uses: actions-rs/toolchain@v1
+        with:
+          toolchain: nightly
+"#;
+        let tested_versions = extract_yaml_versions(workflow);
+        assert_eq!(tested_versions["JavaScript"], vec!["18", "20"]);
+        assert_eq!(tested_versions["Python"], vec!["3.11", "3.12"]);
+        assert_eq!(tested_versions["Rust"], vec!["1.75", "nightly"]);
+    }
+
+    #[test]
+    fn test_detect_ci_pipelines() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join(".github/workflows")).unwrap();
+        fs::write(
+            project_path.join(".github/workflows/ci.yml"),
+            "jobs:\n  build:\n    container: golang:1.22\n",
+        )
+        .unwrap();
+        fs::write(
+            project_path.join(".gitlab-ci.yml"),
+            "test:\n  image: python:3.9-slim\n",
+        )
+        .unwrap();
+        fs::write(
+            project_path.join("azure-pipelines.yml"),
+            "strategy:\n  matrix:\n    py38:\n      python.version: '3.8'\nsteps:\n- task: UsePythonVersion@0\n  inputs:\n    versionSpec: '$(python.version)'\n",
+        )
+        .unwrap();
+        fs::write(
+            project_path.join("Jenkinsfile"),
+            "pipeline {\n  agent { docker { image 'node:16-alpine' } }\n}\n",
+        )
+        .unwrap();
+
+        let ci_pipelines = detect_ci_pipelines(project_path);
+        let providers: Vec<&str> = ci_pipelines
+            .iter()
+            .map(|ci_pipeline| ci_pipeline.provider.as_str())
+            .collect();
+        assert_eq!(
+            providers,
+            vec!["GitHub Actions", "GitLab CI", "Azure Pipelines", "Jenkins"]
+        );
+        assert_eq!(ci_pipelines[0].config_file, ".github/workflows/ci.yml");
+        assert_eq!(ci_pipelines[0].tested_versions["Go"], vec!["1.22"]);
+        assert_eq!(ci_pipelines[1].tested_versions["Python"], vec!["3.9"]);
+        assert_eq!(ci_pipelines[2].tested_versions["Python"], vec!["3.8"]);
+        assert_eq!(ci_pipelines[3].tested_versions["JavaScript"], vec!["16"]);
+    }
+}
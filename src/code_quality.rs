@@ -0,0 +1,800 @@
+//! Linter, formatter and type-checker configuration detection.
+//!
+//! Each tool is found from its own config file or its section in a shared
+//! one (`pyproject.toml`, `setup.cfg`, `package.json`, `Cargo.toml`, Gradle
+//! and Maven build files). Where the config is declarative (JSON, TOML, INI,
+//! simple YAML) a few key settings are read; line lengths are reported under
+//! one `line_length` key so tools can be compared.
+
+use crate::config_files::{
+    read_ini_value, read_toml_file, read_yaml_list, read_yaml_value, strip_json_comments,
+};
+use crate::project_info::{CodeQualityKind, CodeQualityTool};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const ESLINT_FLAT_CONFIG_FILES: [&str; 6] = [
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    "eslint.config.ts",
+    "eslint.config.mts",
+    "eslint.config.cts",
+];
+
+const ESLINT_LEGACY_CONFIG_FILES: [&str; 6] = [
+    ".eslintrc.json",
+    ".eslintrc",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+];
+
+const PRETTIER_CONFIG_FILES: [&str; 10] = [
+    ".prettierrc.json",
+    ".prettierrc",
+    ".prettierrc.json5",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.toml",
+    ".prettierrc.js",
+    ".prettierrc.cjs",
+    ".prettierrc.mjs",
+    "prettier.config.js",
+];
+
+const GOLANGCI_CONFIG_FILES: [&str; 4] = [
+    ".golangci.yml",
+    ".golangci.yaml",
+    ".golangci.toml",
+    ".golangci.json",
+];
+
+/// Every configured linter, formatter and type checker, in a fixed order
+pub fn detect_code_quality_tools(project_path: &Path) -> Vec<CodeQualityTool> {
+    let mut code_quality_tools = Vec::new();
+    let package_json = read_json_file(&project_path.join("package.json"));
+    let pyproject = read_toml_file(&project_path.join("pyproject.toml"));
+    let pyproject_tool = |tool_name: &str| {
+        pyproject
+            .as_ref()
+            .and_then(|pyproject| pyproject.get("tool")?.get(tool_name).cloned())
+    };
+
+    detect_eslint(project_path, package_json.as_ref(), &mut code_quality_tools);
+    detect_prettier(project_path, package_json.as_ref(), &mut code_quality_tools);
+
+    if let Some(biome_file) = ["biome.json", "biome.jsonc"]
+        .into_iter()
+        .find(|biome_file| project_path.join(biome_file).exists())
+    {
+        let mut settings = BTreeMap::new();
+        if let Some(biome_config) = read_json_file(&project_path.join(biome_file)) {
+            insert_json_setting(
+                &mut settings,
+                "line_length",
+                biome_config.pointer("/formatter/lineWidth"),
+            );
+            insert_json_setting(
+                &mut settings,
+                "recommended",
+                biome_config.pointer("/linter/rules/recommended"),
+            );
+        }
+        push_tool(
+            &mut code_quality_tools,
+            "Biome",
+            CodeQualityKind::Linter,
+            biome_file,
+            settings,
+        );
+    }
+
+    if let Some(rustfmt_file) = ["rustfmt.toml", ".rustfmt.toml"]
+        .into_iter()
+        .find(|rustfmt_file| project_path.join(rustfmt_file).exists())
+    {
+        let mut settings = read_toml_file(&project_path.join(rustfmt_file))
+            .map(|rustfmt_config| scalar_settings(&rustfmt_config))
+            .unwrap_or_default();
+        rename_setting(&mut settings, "max_width", "line_length");
+        push_tool(
+            &mut code_quality_tools,
+            "rustfmt",
+            CodeQualityKind::Formatter,
+            rustfmt_file,
+            settings,
+        );
+    }
+
+    if let Some(clippy_file) = ["clippy.toml", ".clippy.toml"]
+        .into_iter()
+        .find(|clippy_file| project_path.join(clippy_file).exists())
+    {
+        let settings = read_toml_file(&project_path.join(clippy_file))
+            .map(|clippy_config| scalar_settings(&clippy_config))
+            .unwrap_or_default();
+        push_tool(
+            &mut code_quality_tools,
+            "Clippy",
+            CodeQualityKind::Linter,
+            clippy_file,
+            settings,
+        );
+    } else if let Some(clippy_lints) = read_toml_file(&project_path.join("Cargo.toml"))
+        .and_then(|cargo_toml| cargo_toml.get("lints")?.get("clippy").cloned())
+    {
+        // `[lints.clippy]` maps lint groups to a level or `{ level, priority }`
+        let settings = clippy_lints
+            .as_table()
+            .into_iter()
+            .flatten()
+            .filter_map(|(lint_name, lint_value)| {
+                let lint_level = lint_value
+                    .as_str()
+                    .or_else(|| lint_value.get("level")?.as_str())?;
+                Some((lint_name.clone(), lint_level.to_string()))
+            })
+            .collect();
+        push_tool(
+            &mut code_quality_tools,
+            "Clippy",
+            CodeQualityKind::Linter,
+            "Cargo.toml",
+            settings,
+        );
+    }
+
+    let ruff_config = ["ruff.toml", ".ruff.toml"]
+        .into_iter()
+        .find_map(|ruff_file| Some((ruff_file, read_toml_file(&project_path.join(ruff_file))?)))
+        .or_else(|| Some(("pyproject.toml", pyproject_tool("ruff")?)));
+    if let Some((ruff_file, ruff_config)) = ruff_config {
+        let mut settings = BTreeMap::new();
+        insert_toml_setting(&mut settings, "line_length", ruff_config.get("line-length"));
+        insert_toml_setting(
+            &mut settings,
+            "target_version",
+            ruff_config.get("target-version"),
+        );
+        // Rule selection moved under `[lint]` in Ruff 0.2
+        let lint_config = ruff_config.get("lint").unwrap_or(&ruff_config);
+        insert_toml_setting(
+            &mut settings,
+            "select",
+            lint_config
+                .get("select")
+                .or_else(|| ruff_config.get("select")),
+        );
+        insert_toml_setting(
+            &mut settings,
+            "ignore",
+            lint_config
+                .get("ignore")
+                .or_else(|| ruff_config.get("ignore")),
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "Ruff",
+            CodeQualityKind::Linter,
+            ruff_file,
+            settings,
+        );
+    }
+
+    if let Some(black_config) = pyproject_tool("black") {
+        let mut settings = BTreeMap::new();
+        insert_toml_setting(
+            &mut settings,
+            "line_length",
+            black_config.get("line-length"),
+        );
+        insert_toml_setting(
+            &mut settings,
+            "target_version",
+            black_config.get("target-version"),
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "Black",
+            CodeQualityKind::Formatter,
+            "pyproject.toml",
+            settings,
+        );
+    }
+
+    if let Some(isort_config) = pyproject_tool("isort") {
+        let mut settings = BTreeMap::new();
+        insert_toml_setting(&mut settings, "profile", isort_config.get("profile"));
+        insert_toml_setting(
+            &mut settings,
+            "line_length",
+            isort_config.get("line_length"),
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "isort",
+            CodeQualityKind::Formatter,
+            "pyproject.toml",
+            settings,
+        );
+    } else if let Some((isort_file, isort_section)) = find_ini_section(
+        project_path,
+        &[
+            (".isort.cfg", "settings"),
+            ("setup.cfg", "isort"),
+            ("tox.ini", "isort"),
+        ],
+    ) {
+        let settings = read_ini_settings(
+            project_path,
+            isort_file,
+            isort_section,
+            &[("profile", "profile"), ("line_length", "line_length")],
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "isort",
+            CodeQualityKind::Formatter,
+            isort_file,
+            settings,
+        );
+    }
+
+    if let Some(mypy_config) = pyproject_tool("mypy") {
+        let mut settings = BTreeMap::new();
+        insert_toml_setting(&mut settings, "strict", mypy_config.get("strict"));
+        insert_toml_setting(
+            &mut settings,
+            "python_version",
+            mypy_config.get("python_version"),
+        );
+        insert_toml_setting(
+            &mut settings,
+            "disallow_untyped_defs",
+            mypy_config.get("disallow_untyped_defs"),
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "mypy",
+            CodeQualityKind::TypeChecker,
+            "pyproject.toml",
+            settings,
+        );
+    } else if let Some((mypy_file, mypy_section)) = find_ini_section(
+        project_path,
+        &[
+            ("mypy.ini", "mypy"),
+            (".mypy.ini", "mypy"),
+            ("setup.cfg", "mypy"),
+        ],
+    ) {
+        let settings = read_ini_settings(
+            project_path,
+            mypy_file,
+            mypy_section,
+            &[
+                ("strict", "strict"),
+                ("python_version", "python_version"),
+                ("disallow_untyped_defs", "disallow_untyped_defs"),
+            ],
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "mypy",
+            CodeQualityKind::TypeChecker,
+            mypy_file,
+            settings,
+        );
+    }
+
+    if let Some(pyright_config) = read_json_file(&project_path.join("pyrightconfig.json")) {
+        let mut settings = BTreeMap::new();
+        insert_json_setting(
+            &mut settings,
+            "strictness",
+            pyright_config.get("typeCheckingMode"),
+        );
+        insert_json_setting(
+            &mut settings,
+            "python_version",
+            pyright_config.get("pythonVersion"),
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "Pyright",
+            CodeQualityKind::TypeChecker,
+            "pyrightconfig.json",
+            settings,
+        );
+    } else if let Some(pyright_config) = pyproject_tool("pyright") {
+        let mut settings = BTreeMap::new();
+        insert_toml_setting(
+            &mut settings,
+            "strictness",
+            pyright_config.get("typeCheckingMode"),
+        );
+        insert_toml_setting(
+            &mut settings,
+            "python_version",
+            pyright_config.get("pythonVersion"),
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "Pyright",
+            CodeQualityKind::TypeChecker,
+            "pyproject.toml",
+            settings,
+        );
+    }
+
+    if let Some(golangci_file) = GOLANGCI_CONFIG_FILES
+        .into_iter()
+        .find(|golangci_file| project_path.join(golangci_file).exists())
+    {
+        let mut settings = BTreeMap::new();
+        if golangci_file.ends_with(".yml") || golangci_file.ends_with(".yaml") {
+            let golangci_content =
+                fs::read_to_string(project_path.join(golangci_file)).unwrap_or_default();
+            let enabled_linters = read_yaml_list(&golangci_content, &["linters", "enable"]);
+            if !enabled_linters.is_empty() {
+                settings.insert("enable".to_string(), enabled_linters.join(", "));
+            }
+        }
+        push_tool(
+            &mut code_quality_tools,
+            "golangci-lint",
+            CodeQualityKind::Linter,
+            golangci_file,
+            settings,
+        );
+    }
+
+    detect_jvm_code_quality_tools(project_path, &mut code_quality_tools);
+
+    if let Ok(rubocop_content) = fs::read_to_string(project_path.join(".rubocop.yml")) {
+        let mut settings = BTreeMap::new();
+        if let Some(max_line_length) =
+            read_yaml_value(&rubocop_content, &["Layout/LineLength", "Max"])
+        {
+            settings.insert("line_length".to_string(), max_line_length);
+        }
+        if let Some(target_version) =
+            read_yaml_value(&rubocop_content, &["AllCops", "TargetRubyVersion"])
+        {
+            settings.insert("target_version".to_string(), target_version);
+        }
+        push_tool(
+            &mut code_quality_tools,
+            "RuboCop",
+            CodeQualityKind::Linter,
+            ".rubocop.yml",
+            settings,
+        );
+    }
+
+    if project_path.join(".editorconfig").exists() {
+        let settings = read_ini_settings(
+            project_path,
+            ".editorconfig",
+            "*",
+            &[
+                ("indent_style", "indent_style"),
+                ("indent_size", "indent_size"),
+                ("max_line_length", "line_length"),
+            ],
+        );
+        push_tool(
+            &mut code_quality_tools,
+            "EditorConfig",
+            CodeQualityKind::EditorConfig,
+            ".editorconfig",
+            settings,
+        );
+    }
+
+    code_quality_tools
+}
+
+/// ESLint 9's flat `eslint.config.*` or the legacy `.eslintrc*` / `eslintConfig`
+fn detect_eslint(
+    project_path: &Path,
+    package_json: Option<&serde_json::Value>,
+    code_quality_tools: &mut Vec<CodeQualityTool>,
+) {
+    if let Some(flat_config_file) = ESLINT_FLAT_CONFIG_FILES
+        .into_iter()
+        .find(|config_file| project_path.join(config_file).exists())
+    {
+        let settings = BTreeMap::from([("config".to_string(), "flat".to_string())]);
+        push_tool(
+            code_quality_tools,
+            "ESLint",
+            CodeQualityKind::Linter,
+            flat_config_file,
+            settings,
+        );
+        return;
+    }
+
+    let legacy_config = ESLINT_LEGACY_CONFIG_FILES
+        .into_iter()
+        .find(|config_file| project_path.join(config_file).exists())
+        .map(|config_file| (config_file, read_json_file(&project_path.join(config_file))))
+        .or_else(|| {
+            Some((
+                "package.json",
+                Some(package_json?.get("eslintConfig")?.clone()),
+            ))
+        });
+    if let Some((legacy_config_file, legacy_config_json)) = legacy_config {
+        let mut settings = BTreeMap::from([("config".to_string(), "legacy".to_string())]);
+        insert_json_setting(
+            &mut settings,
+            "extends",
+            legacy_config_json
+                .as_ref()
+                .and_then(|config_json| config_json.get("extends")),
+        );
+        push_tool(
+            code_quality_tools,
+            "ESLint",
+            CodeQualityKind::Linter,
+            legacy_config_file,
+            settings,
+        );
+    }
+}
+
+fn detect_prettier(
+    project_path: &Path,
+    package_json: Option<&serde_json::Value>,
+    code_quality_tools: &mut Vec<CodeQualityTool>,
+) {
+    let prettier_config = PRETTIER_CONFIG_FILES
+        .into_iter()
+        .find(|config_file| project_path.join(config_file).exists())
+        .map(|config_file| (config_file, read_json_file(&project_path.join(config_file))))
+        .or_else(|| Some(("package.json", Some(package_json?.get("prettier")?.clone()))));
+    if let Some((prettier_file, prettier_json)) = prettier_config {
+        let mut settings = BTreeMap::new();
+        if let Some(ref prettier_json) = prettier_json {
+            insert_json_setting(
+                &mut settings,
+                "line_length",
+                prettier_json.get("printWidth"),
+            );
+            insert_json_setting(&mut settings, "tab_width", prettier_json.get("tabWidth"));
+            insert_json_setting(&mut settings, "semi", prettier_json.get("semi"));
+            insert_json_setting(
+                &mut settings,
+                "single_quote",
+                prettier_json.get("singleQuote"),
+            );
+        }
+        push_tool(
+            code_quality_tools,
+            "Prettier",
+            CodeQualityKind::Formatter,
+            prettier_file,
+            settings,
+        );
+    }
+}
+
+/// Checkstyle and Spotless from their config files or build plugins
+fn detect_jvm_code_quality_tools(
+    project_path: &Path,
+    code_quality_tools: &mut Vec<CodeQualityTool>,
+) {
+    let build_files: Vec<(&str, String)> = ["build.gradle.kts", "build.gradle", "pom.xml"]
+        .into_iter()
+        .filter_map(|build_file| {
+            Some((
+                build_file,
+                fs::read_to_string(project_path.join(build_file)).ok()?,
+            ))
+        })
+        .collect();
+
+    let checkstyle_file = ["checkstyle.xml", "config/checkstyle/checkstyle.xml"]
+        .into_iter()
+        .find(|checkstyle_file| project_path.join(checkstyle_file).exists())
+        .or_else(|| {
+            build_files
+                .iter()
+                .find(|(_, build_content)| build_content.contains("checkstyle"))
+                .map(|(build_file, _)| *build_file)
+        });
+    if let Some(checkstyle_file) = checkstyle_file {
+        let mut settings = BTreeMap::new();
+        let line_length_regex = regex::Regex::new(
+            r#"<module\s+name="LineLength"\s*>\s*(?:<[^>]*>\s*)*?<property\s+name="max"\s+value="(\d+)""#,
+        )
+        .unwrap();
+        if let Some(captures) = fs::read_to_string(project_path.join(checkstyle_file))
+            .ok()
+            .and_then(|checkstyle_content| {
+                line_length_regex
+                    .captures(&checkstyle_content)
+                    .map(|c| c[1].to_string())
+            })
+        {
+            settings.insert("line_length".to_string(), captures);
+        }
+        push_tool(
+            code_quality_tools,
+            "Checkstyle",
+            CodeQualityKind::Linter,
+            checkstyle_file,
+            settings,
+        );
+    }
+
+    if let Some((spotless_file, spotless_content)) = build_files
+        .iter()
+        .find(|(_, build_content)| build_content.contains("spotless"))
+    {
+        let mut settings = BTreeMap::new();
+        let formatter_names: Vec<&str> = [
+            "googleJavaFormat",
+            "palantirJavaFormat",
+            "eclipse",
+            "ktlint",
+            "ktfmt",
+            "prettier",
+            "scalafmt",
+        ]
+        .into_iter()
+        .filter(|formatter_name| spotless_content.contains(formatter_name))
+        .collect();
+        if !formatter_names.is_empty() {
+            settings.insert("formatters".to_string(), formatter_names.join(", "));
+        }
+        push_tool(
+            code_quality_tools,
+            "Spotless",
+            CodeQualityKind::Formatter,
+            spotless_file,
+            settings,
+        );
+    }
+}
+
+fn push_tool(
+    code_quality_tools: &mut Vec<CodeQualityTool>,
+    tool_name: &str,
+    tool_kind: CodeQualityKind,
+    config_file: &str,
+    settings: BTreeMap<String, String>,
+) {
+    code_quality_tools.push(CodeQualityTool {
+        name: tool_name.to_string(),
+        kind: tool_kind,
+        config_file: config_file.to_string(),
+        settings,
+    });
+}
+
+fn read_json_file(json_path: &Path) -> Option<serde_json::Value> {
+    let json_content = fs::read_to_string(json_path).ok()?;
+    serde_json::from_str(&strip_json_comments(&json_content)).ok()
+}
+
+/// Top-level strings, numbers and booleans of a TOML config
+fn scalar_settings(toml_config: &toml::Value) -> BTreeMap<String, String> {
+    toml_config
+        .as_table()
+        .into_iter()
+        .flatten()
+        .filter_map(|(setting_key, setting_value)| {
+            Some((setting_key.clone(), toml_setting_string(setting_value)?))
+        })
+        .collect()
+}
+
+fn rename_setting(settings: &mut BTreeMap<String, String>, old_key: &str, new_key: &str) {
+    if let Some(setting_value) = settings.remove(old_key) {
+        settings.insert(new_key.to_string(), setting_value);
+    }
+}
+
+fn insert_toml_setting(
+    settings: &mut BTreeMap<String, String>,
+    setting_key: &str,
+    setting_value: Option<&toml::Value>,
+) {
+    if let Some(setting_string) = setting_value.and_then(toml_setting_string) {
+        settings.insert(setting_key.to_string(), setting_string);
+    }
+}
+
+fn insert_json_setting(
+    settings: &mut BTreeMap<String, String>,
+    setting_key: &str,
+    setting_value: Option<&serde_json::Value>,
+) {
+    let setting_string = match setting_value {
+        Some(serde_json::Value::String(value)) => value.clone(),
+        Some(serde_json::Value::Number(value)) => value.to_string(),
+        Some(serde_json::Value::Bool(value)) => value.to_string(),
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .filter_map(|value| value.as_str())
+            .collect::<Vec<&str>>()
+            .join(", "),
+        _ => return,
+    };
+    settings.insert(setting_key.to_string(), setting_string);
+}
+
+/// Scalars as written; arrays of strings joined with `, `
+fn toml_setting_string(setting_value: &toml::Value) -> Option<String> {
+    match setting_value {
+        toml::Value::String(value) => Some(value.clone()),
+        toml::Value::Integer(value) => Some(value.to_string()),
+        toml::Value::Float(value) => Some(value.to_string()),
+        toml::Value::Boolean(value) => Some(value.to_string()),
+        toml::Value::Array(values) => Some(
+            values
+                .iter()
+                .filter_map(|value| value.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+        ),
+        _ => None,
+    }
+}
+
+/// The first `(file, section)` whose INI file has that section
+fn find_ini_section<'a>(
+    project_path: &Path,
+    candidates: &[(&'a str, &'a str)],
+) -> Option<(&'a str, &'a str)> {
+    candidates.iter().copied().find(|(ini_file, ini_section)| {
+        fs::read_to_string(project_path.join(ini_file)).is_ok_and(|ini_content| {
+            ini_content
+                .lines()
+                .any(|ini_line| ini_line.trim() == format!("[{}]", ini_section))
+        })
+    })
+}
+
+/// `(ini key, setting key)` pairs read from one INI section
+fn read_ini_settings(
+    project_path: &Path,
+    ini_file: &str,
+    ini_section: &str,
+    setting_keys: &[(&str, &str)],
+) -> BTreeMap<String, String> {
+    let ini_content = fs::read_to_string(project_path.join(ini_file)).unwrap_or_default();
+    setting_keys
+        .iter()
+        .filter_map(|(ini_key, setting_key)| {
+            Some((
+                setting_key.to_string(),
+                read_ini_value(&ini_content, ini_section, ini_key)?,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tool_settings<'a>(
+        code_quality_tools: &'a [CodeQualityTool],
+        tool_name: &str,
+    ) -> &'a BTreeMap<String, String> {
+        &code_quality_tools
+            .iter()
+            .find(|code_quality_tool| code_quality_tool.name == tool_name)
+            .unwrap()
+            .settings
+    }
+
+    #[test]
+    fn test_detect_javascript_and_python_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("eslint.config.mjs"),
+            "export default [];\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"prettier": {"printWidth": 100, "singleQuote": true}}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"[tool.ruff]
+line-length = 88
+target-version = "py311"
+
+[tool.ruff.lint]
+select = ["E", "F", "I"]
+
+[tool.mypy]
+strict = true
+"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(".editorconfig"),
+            "root = true\n\n[*]\nindent_style = space\nindent_size = 4\n\n[*.md]\nmax_line_length = off\n",
+        )
+        .unwrap();
+
+        let code_quality_tools = detect_code_quality_tools(temp_dir.path());
+        let tool_names: Vec<&str> = code_quality_tools
+            .iter()
+            .map(|tool| tool.name.as_str())
+            .collect();
+        assert_eq!(
+            tool_names,
+            vec!["ESLint", "Prettier", "Ruff", "mypy", "EditorConfig"]
+        );
+        assert_eq!(
+            tool_settings(&code_quality_tools, "ESLint")["config"],
+            "flat"
+        );
+        assert_eq!(
+            tool_settings(&code_quality_tools, "Prettier")["line_length"],
+            "100"
+        );
+        assert_eq!(code_quality_tools[1].config_file, "package.json");
+        let ruff_settings = tool_settings(&code_quality_tools, "Ruff");
+        assert_eq!(ruff_settings["line_length"], "88");
+        assert_eq!(ruff_settings["select"], "E, F, I");
+        assert_eq!(tool_settings(&code_quality_tools, "mypy")["strict"], "true");
+        assert_eq!(code_quality_tools[3].kind, CodeQualityKind::TypeChecker);
+        let editorconfig_settings = tool_settings(&code_quality_tools, "EditorConfig");
+        assert_eq!(editorconfig_settings["indent_size"], "4");
+        assert!(!editorconfig_settings.contains_key("line_length"));
+    }
+
+    #[test]
+    fn test_detect_rust_go_and_ruby_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("rustfmt.toml"),
+            "max_width = 120\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"app\"\n\n[lints.clippy]\npedantic = { level = \"warn\", priority = -1 }\nunwrap_used = \"deny\"\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(".golangci.yml"),
+            "run:\n  timeout: 5m\nlinters:\n  enable:\n    - govet\n    - errcheck # unchecked errors\n  disable:\n    - lll\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(".rubocop.yml"),
+            "AllCops:\n  TargetRubyVersion: 3.2\n\nLayout/LineLength:\n  Max: 120\n",
+        )
+        .unwrap();
+
+        let code_quality_tools = detect_code_quality_tools(temp_dir.path());
+        let rustfmt_settings = tool_settings(&code_quality_tools, "rustfmt");
+        assert_eq!(rustfmt_settings["line_length"], "120");
+        assert_eq!(rustfmt_settings["edition"], "2021");
+        let clippy_settings = tool_settings(&code_quality_tools, "Clippy");
+        assert_eq!(clippy_settings["pedantic"], "warn");
+        assert_eq!(clippy_settings["unwrap_used"], "deny");
+        assert_eq!(
+            tool_settings(&code_quality_tools, "golangci-lint")["enable"],
+            "govet, errcheck"
+        );
+        let rubocop_settings = tool_settings(&code_quality_tools, "RuboCop");
+        assert_eq!(rubocop_settings["line_length"], "120");
+        assert_eq!(rubocop_settings["target_version"], "3.2");
+    }
+}
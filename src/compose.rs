@@ -0,0 +1,268 @@
+//! Docker Compose service detection.
+//!
+//! Services are read from the top-level `services:` map of each Compose file
+//! with a line-based YAML reader: a service is a key one level under
+//! `services:` and its `image:` and `build:` are keys one level under that.
+//! Well-known images are classified as databases, caches, queues or search
+//! engines, and the version in their tag gets an End of Life status.
+
+use crate::config_files::{strip_yaml_comment, yaml_indentation};
+use crate::container_images::parse_image_reference;
+use crate::languages;
+use crate::project_info::{BackingServiceKind, ComposeService, LanguageStatus};
+use std::fs;
+use std::path::Path;
+
+/// Compose files without a suffix; `docker-compose.<suffix>.yml` files are found too
+const COMPOSE_FILES: [&str; 4] = [
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yml",
+    "docker-compose.yaml",
+];
+
+/// Image names (last path segment) and the backing service they run
+const BACKING_SERVICE_IMAGES: [(&str, &str, BackingServiceKind); 33] = [
+    ("postgres", "PostgreSQL", BackingServiceKind::Database),
+    ("postgresql", "PostgreSQL", BackingServiceKind::Database),
+    ("postgis", "PostgreSQL", BackingServiceKind::Database),
+    ("timescaledb", "TimescaleDB", BackingServiceKind::Database),
+    ("mysql", "MySQL", BackingServiceKind::Database),
+    ("mysql-server", "MySQL", BackingServiceKind::Database),
+    ("mariadb", "MariaDB", BackingServiceKind::Database),
+    ("mongo", "MongoDB", BackingServiceKind::Database),
+    ("mongodb", "MongoDB", BackingServiceKind::Database),
+    ("cassandra", "Cassandra", BackingServiceKind::Database),
+    ("couchdb", "CouchDB", BackingServiceKind::Database),
+    ("cockroach", "CockroachDB", BackingServiceKind::Database),
+    (
+        "clickhouse-server",
+        "ClickHouse",
+        BackingServiceKind::Database,
+    ),
+    ("neo4j", "Neo4j", BackingServiceKind::Database),
+    ("redis", "Redis", BackingServiceKind::Cache),
+    ("redis-stack", "Redis", BackingServiceKind::Cache),
+    ("redis-stack-server", "Redis", BackingServiceKind::Cache),
+    ("valkey", "Valkey", BackingServiceKind::Cache),
+    ("keydb", "KeyDB", BackingServiceKind::Cache),
+    ("dragonfly", "Dragonfly", BackingServiceKind::Cache),
+    ("memcached", "Memcached", BackingServiceKind::Cache),
+    ("rabbitmq", "RabbitMQ", BackingServiceKind::Queue),
+    ("kafka", "Kafka", BackingServiceKind::Queue),
+    ("cp-kafka", "Kafka", BackingServiceKind::Queue),
+    ("redpanda", "Redpanda", BackingServiceKind::Queue),
+    ("nats", "NATS", BackingServiceKind::Queue),
+    ("pulsar", "Pulsar", BackingServiceKind::Queue),
+    ("activemq", "ActiveMQ", BackingServiceKind::Queue),
+    ("elasticsearch", "Elasticsearch", BackingServiceKind::Search),
+    ("opensearch", "OpenSearch", BackingServiceKind::Search),
+    ("solr", "Solr", BackingServiceKind::Search),
+    ("meilisearch", "Meilisearch", BackingServiceKind::Search),
+    ("typesense", "Typesense", BackingServiceKind::Search),
+];
+
+pub fn detect_compose_services(project_path: &Path) -> Vec<ComposeService> {
+    let mut compose_files: Vec<String> = COMPOSE_FILES
+        .iter()
+        .filter(|compose_file| project_path.join(compose_file).is_file())
+        .map(|compose_file| compose_file.to_string())
+        .collect();
+    // Overrides such as `docker-compose.override.yml` and `docker-compose.prod.yaml`
+    if let Ok(directory_entries) = fs::read_dir(project_path) {
+        let mut override_files: Vec<String> = directory_entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|file_name| {
+                (file_name.starts_with("docker-compose.") || file_name.starts_with("compose."))
+                    && (file_name.ends_with(".yml") || file_name.ends_with(".yaml"))
+                    && !COMPOSE_FILES.contains(&file_name.as_str())
+            })
+            .collect();
+        override_files.sort();
+        compose_files.extend(override_files);
+    }
+
+    let mut compose_services = Vec::new();
+    for compose_file in compose_files {
+        let Ok(compose_content) = fs::read_to_string(project_path.join(&compose_file)) else {
+            continue;
+        };
+        for (service_name, image) in read_compose_services(&compose_content) {
+            compose_services.push(compose_service(service_name, image, &compose_file));
+        }
+    }
+
+    compose_services
+}
+
+fn compose_service(service_name: String, image: Option<String>, source: &str) -> ComposeService {
+    let image_reference = image.as_deref().and_then(parse_image_reference);
+    let backing_service = image_reference.as_ref().and_then(|image_reference| {
+        BACKING_SERVICE_IMAGES
+            .iter()
+            .find(|(image_name, _, _)| *image_name == image_reference.image_name())
+    });
+    let version = image_reference
+        .as_ref()
+        .and_then(|image_reference| image_reference.tag_version());
+    let status = match (backing_service, version.as_deref()) {
+        (Some((_, technology, _)), Some(version)) => {
+            languages::get_service_status(technology, version)
+        }
+        _ => LanguageStatus::Unknown,
+    };
+
+    ComposeService {
+        name: service_name,
+        image,
+        technology: backing_service.map(|(_, technology, _)| technology.to_string()),
+        kind: backing_service.map(|(_, _, kind)| *kind),
+        version,
+        status,
+        source: source.to_string(),
+    }
+}
+
+/// Service names and their images; services with neither `image:` nor `build:`
+/// (partial overrides) are skipped
+fn read_compose_services(compose_content: &str) -> Vec<(String, Option<String>)> {
+    let mut compose_services: Vec<(String, Option<String>, bool)> = Vec::new();
+    let mut in_services = false;
+    let mut service_indent: Option<usize> = None;
+    let mut property_indent: Option<usize> = None;
+
+    for content_line in compose_content.lines() {
+        let trimmed_line = strip_yaml_comment(content_line).trim();
+        if trimmed_line.is_empty() {
+            continue;
+        }
+        let line_indent = yaml_indentation(content_line);
+
+        if line_indent == 0 {
+            in_services = trimmed_line.trim_end_matches(':') == "services";
+            service_indent = None;
+            continue;
+        }
+        if !in_services {
+            continue;
+        }
+
+        if line_indent == *service_indent.get_or_insert(line_indent) {
+            if let Some(service_name) = trimmed_line.strip_suffix(':') {
+                compose_services.push((
+                    service_name.trim_matches(['"', '\'']).to_string(),
+                    None,
+                    false,
+                ));
+                property_indent = None;
+            }
+            continue;
+        }
+
+        let Some(current_service) = compose_services.last_mut() else {
+            continue;
+        };
+        if line_indent != *property_indent.get_or_insert(line_indent) {
+            continue;
+        }
+        let (property_key, property_value) =
+            trimmed_line.split_once(':').unwrap_or((trimmed_line, ""));
+        match property_key.trim() {
+            "image" => {
+                let image = property_value.trim().trim_matches(['"', '\'']);
+                current_service.1 = Some(substitute_defaults(image));
+            }
+            "build" => current_service.2 = true,
+            _ => {}
+        }
+    }
+
+    compose_services
+        .into_iter()
+        .filter(|(_, image, has_build)| image.is_some() || *has_build)
+        .map(|(service_name, image, _)| (service_name, image))
+        .collect()
+}
+
+/// `postgres:${POSTGRES_VERSION:-13}` becomes `postgres:13`; variables without a
+/// default are left in place
+fn substitute_defaults(image: &str) -> String {
+    let default_regex = regex::Regex::new(r"\$\{[A-Za-z_][A-Za-z0-9_]*:?-([^}]*)\}").unwrap();
+    default_regex.replace_all(image, "$1").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_compose_services() {
+        let compose_content = r#"
+version: "3.9"
+services:
+  web:
+    build: .
+    ports:
+      - "8000:8000"
+    depends_on:
+      - db
+  db:
+    image: "postgres:${POSTGRES_VERSION:-13}-alpine" # matches production
+    environment:
+      POSTGRES_PASSWORD: example
+  worker:
+    environment:
+      image: not-an-image
+volumes:
+  data:
+    driver: local
+"#;
+        assert_eq!(
+            read_compose_services(compose_content),
+            vec![
+                ("web".to_string(), None),
+                ("db".to_string(), Some("postgres:13-alpine".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_compose_services() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("compose.yaml"),
+            "services:\n  cache:\n    image: redis:6.2\n  search:\n    image: docker.elastic.co/elasticsearch/elasticsearch:8.11.1\n  queue:\n    image: rabbitmq:3-management\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("docker-compose.override.yml"),
+            "services:\n  mongo:\n    image: mongo:latest\n",
+        )
+        .unwrap();
+
+        let compose_services = detect_compose_services(temp_dir.path());
+        assert_eq!(compose_services.len(), 4);
+
+        let redis_service = &compose_services[0];
+        assert_eq!(redis_service.technology, Some("Redis".to_string()));
+        assert_eq!(redis_service.kind, Some(BackingServiceKind::Cache));
+        assert_eq!(redis_service.version, Some("6.2".to_string()));
+        assert!(matches!(
+            redis_service.status,
+            LanguageStatus::EndOfLife { .. }
+        ));
+
+        assert_eq!(compose_services[1].kind, Some(BackingServiceKind::Search));
+        assert_eq!(compose_services[1].status, LanguageStatus::Supported);
+        assert_eq!(compose_services[2].kind, Some(BackingServiceKind::Queue));
+        assert_eq!(compose_services[2].version, Some("3".to_string()));
+
+        let mongo_service = &compose_services[3];
+        assert_eq!(mongo_service.source, "docker-compose.override.yml");
+        assert_eq!(mongo_service.kind, Some(BackingServiceKind::Database));
+        assert!(mongo_service.version.is_none());
+        assert_eq!(mongo_service.status, LanguageStatus::Unknown);
+    }
+}
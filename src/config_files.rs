@@ -0,0 +1,257 @@
+//! Config file readers shared by the detectors.
+//!
+//! TOML is parsed with the `toml` crate and JSONC is stripped down to JSON
+//! for `serde_json`. INI and YAML are read line by line instead: the
+//! detectors only need sections, block-style keys, scalars and lists, and
+//! every YAML reader in the crate strips comments and measures indentation
+//! the same way.
+
+use std::fs;
+use std::path::Path;
+
+pub fn read_toml_file(toml_path: &Path) -> Option<toml::Value> {
+    toml::from_str(&fs::read_to_string(toml_path).ok()?).ok()
+}
+
+/// Strip `//` and `/* */` comments and trailing commas so JSONC parses as JSON
+pub fn strip_json_comments(jsonc_content: &str) -> String {
+    let mut stripped_content = String::with_capacity(jsonc_content.len());
+    let mut characters = jsonc_content.chars().peekable();
+    let mut inside_string = false;
+    // Output position of a comma that only whitespace and comments have followed so far
+    let mut pending_comma: Option<usize> = None;
+
+    while let Some(character) = characters.next() {
+        if inside_string {
+            stripped_content.push(character);
+            match character {
+                '\\' => {
+                    if let Some(escaped_character) = characters.next() {
+                        stripped_content.push(escaped_character);
+                    }
+                }
+                '"' => inside_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (character, characters.peek()) {
+            ('/', Some('/')) => {
+                for comment_character in characters.by_ref() {
+                    if comment_character == '\n' {
+                        stripped_content.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                characters.next();
+                let mut previous_character = ' ';
+                for comment_character in characters.by_ref() {
+                    if previous_character == '*' && comment_character == '/' {
+                        break;
+                    }
+                    previous_character = comment_character;
+                }
+            }
+            _ if character.is_whitespace() => stripped_content.push(character),
+            _ => {
+                if let Some(comma_index) = pending_comma.take() {
+                    if matches!(character, '}' | ']') {
+                        stripped_content.remove(comma_index);
+                    }
+                }
+                match character {
+                    '"' => inside_string = true,
+                    ',' => pending_comma = Some(stripped_content.len()),
+                    _ => {}
+                }
+                stripped_content.push(character);
+            }
+        }
+    }
+
+    stripped_content
+}
+
+/// Value of `key` in `[section]` of an INI file such as `setup.cfg`
+///
+/// Indented continuation lines are joined with newlines, so multi-line
+/// `install_requires` lists come back one requirement per line.
+pub fn read_ini_value(ini_content: &str, section: &str, key: &str) -> Option<String> {
+    let mut in_section = false;
+    let mut value_lines: Option<Vec<String>> = None;
+
+    for content_line in ini_content.lines() {
+        if let Some(ref mut collected_lines) = value_lines {
+            if content_line.starts_with([' ', '\t']) || content_line.trim().is_empty() {
+                if !content_line.trim().is_empty() {
+                    collected_lines.push(content_line.trim().to_string());
+                }
+                continue;
+            }
+            break;
+        }
+
+        let trimmed_line = content_line.trim();
+        if let Some(section_name) = trimmed_line
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            in_section = section_name.trim() == section;
+            continue;
+        }
+
+        if in_section && !content_line.starts_with([' ', '\t']) {
+            if let Some((entry_key, entry_value)) = trimmed_line.split_once(['=', ':']) {
+                if entry_key.trim() == key {
+                    let first_value = entry_value.trim();
+                    value_lines = Some(if first_value.is_empty() {
+                        Vec::new()
+                    } else {
+                        vec![first_value.to_string()]
+                    });
+                }
+            }
+        }
+    }
+
+    value_lines
+        .map(|collected_lines| collected_lines.join("\n"))
+        .filter(|value| !value.is_empty())
+}
+
+/// A YAML line without its comment; a full-line comment becomes empty so
+/// line numbers stay aligned
+pub fn strip_yaml_comment(yaml_line: &str) -> &str {
+    if yaml_line.trim_start().starts_with('#') {
+        return "";
+    }
+    match yaml_line.find(" #") {
+        Some(comment_start) => &yaml_line[..comment_start],
+        None => yaml_line,
+    }
+}
+
+pub fn yaml_indentation(yaml_line: &str) -> usize {
+    yaml_line.len() - yaml_line.trim_start().len()
+}
+
+/// A scalar with its comment, whitespace and quotes removed; `None` when empty
+fn yaml_scalar(yaml_value: &str) -> Option<String> {
+    let yaml_value = strip_yaml_comment(yaml_value)
+        .trim()
+        .trim_matches(['"', '\'']);
+    (!yaml_value.is_empty()).then(|| yaml_value.to_string())
+}
+
+/// Value of an unindented `key: value` line in a simple YAML file
+pub fn read_top_level_yaml_value(yaml_content: &str, key: &str) -> Option<String> {
+    yaml_content
+        .lines()
+        .find_map(|yaml_line| yaml_scalar(yaml_line.strip_prefix(key)?.strip_prefix(':')?))
+}
+
+/// A scalar at a nested key path in block-style YAML
+pub fn read_yaml_value(yaml_content: &str, key_path: &[&str]) -> Option<String> {
+    let (value_line, _) = find_yaml_key(yaml_content, key_path)?;
+    let (_, yaml_value) = value_line.split_once(':')?;
+    yaml_scalar(yaml_value)
+}
+
+/// The `- item` entries under a nested key path in block-style YAML
+pub fn read_yaml_list(yaml_content: &str, key_path: &[&str]) -> Vec<String> {
+    let Some((_, list_line_index)) = find_yaml_key(yaml_content, key_path) else {
+        return Vec::new();
+    };
+    yaml_content
+        .lines()
+        .skip(list_line_index + 1)
+        .map(|yaml_line| strip_yaml_comment(yaml_line).trim())
+        .filter(|yaml_line| !yaml_line.is_empty())
+        .map_while(|yaml_line| yaml_line.strip_prefix("- "))
+        .map(|list_item| list_item.trim().to_string())
+        .collect()
+}
+
+/// The line holding the last key of `key_path`, each key nested under the previous one
+fn find_yaml_key<'a>(yaml_content: &'a str, key_path: &[&str]) -> Option<(&'a str, usize)> {
+    let mut path_index = 0;
+    let mut parent_indent: Option<usize> = None;
+
+    for (line_index, yaml_line) in yaml_content.lines().enumerate() {
+        if strip_yaml_comment(yaml_line).trim().is_empty() {
+            continue;
+        }
+        let line_indent = yaml_indentation(yaml_line);
+        // Leaving the parent's block means the path isn't there
+        if parent_indent.is_some_and(|parent_indent| line_indent <= parent_indent) {
+            return None;
+        }
+        let Some((line_key, _)) = yaml_line.trim_start().split_once(':') else {
+            continue;
+        };
+        if line_key.trim().trim_matches(['"', '\'']) == key_path[path_index] {
+            if path_index + 1 == key_path.len() {
+                return Some((yaml_line, line_index));
+            }
+            path_index += 1;
+            parent_indent = Some(line_indent);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_json_comments() {
+        let jsonc_content = r#"{
+            // compiler settings
+            "compilerOptions": { "strict": true, /* inline */ },
+            "exclude": ["a, }", "b",],
+        }"#;
+        let parsed_json: serde_json::Value =
+            serde_json::from_str(&strip_json_comments(jsonc_content)).unwrap();
+        assert_eq!(parsed_json["compilerOptions"]["strict"], true);
+        assert_eq!(parsed_json["exclude"][0], "a, }");
+        assert_eq!(parsed_json["exclude"][1], "b");
+    }
+
+    #[test]
+    fn test_read_yaml() {
+        let yaml_content = r#"
+# linters for CI
+name: "service" # the app
+linters:
+  # extra checks
+  enable:
+    - gofmt # formatting
+    - govet
+  disable: []
+AllCops:
+  TargetRubyVersion: 3.2
+"#;
+        assert_eq!(strip_yaml_comment("  # extra checks"), "");
+        assert_eq!(
+            strip_yaml_comment("    - gofmt # formatting"),
+            "    - gofmt"
+        );
+        assert_eq!(
+            read_top_level_yaml_value(yaml_content, "name"),
+            Some("service".to_string())
+        );
+        assert_eq!(
+            read_yaml_list(yaml_content, &["linters", "enable"]),
+            vec!["gofmt", "govet"]
+        );
+        assert_eq!(
+            read_yaml_value(yaml_content, &["AllCops", "TargetRubyVersion"]),
+            Some("3.2".to_string())
+        );
+        assert_eq!(read_yaml_value(yaml_content, &["AllCops", "Max"]), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Container image references.
+//!
+//! Official language images encode the runtime version in their tag
+//! (`python:3.8-slim`, `node:14-alpine`, `golang:1.19`), so an image
+//! reference alone tells us which language version a container runs.
+//! Maven and Gradle images lead with the build tool's version instead and
+//! name the JDK in a suffix (`3.9.6-eclipse-temurin-21`, `8.5-jdk17`).
+
+/// Image repositories (last path segment) and the language they run
+const LANGUAGE_IMAGES: [(&str, &str); 20] = [
+    ("node", "JavaScript"),
+    ("python", "Python"),
+    ("pypy", "Python"),
+    ("golang", "Go"),
+    ("go", "Go"),
+    ("rust", "Rust"),
+    ("ruby", "Ruby"),
+    ("openjdk", "Java"),
+    ("eclipse-temurin", "Java"),
+    ("amazoncorretto", "Java"),
+    ("ibm-semeru-runtimes", "Java"),
+    ("sapmachine", "Java"),
+    ("zulu-openjdk", "Java"),
+    ("php", "PHP"),
+    ("elixir", "Elixir"),
+    ("dart", "Dart"),
+    ("swift", "Swift"),
+    ("julia", "Julia"),
+    ("clojure", "Clojure"),
+    ("haskell", "Haskell"),
+];
+
+/// Build tool images whose tag names the JDK after the tool's own version
+const JDK_BUILD_TOOL_IMAGES: [&str; 2] = ["maven", "gradle"];
+
+/// A parsed `[registry/][namespace/]repository[:tag][@digest]` reference
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageReference {
+    /// Repository path without the registry, e.g. `library/python` or `cimg/node`
+    pub repository: String,
+    pub tag: Option<String>,
+}
+
+impl ImageReference {
+    /// The last path segment, e.g. `node` for `cimg/node`
+    pub fn image_name(&self) -> &str {
+        self.repository
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.repository)
+    }
+
+    /// The JDK major version in a build tool tag: `21` for `3.9.6-eclipse-temurin-21`,
+    /// `17` for `8.5-jdk17`
+    pub fn tag_jdk_version(&self) -> Option<String> {
+        let jdk_regex = regex::Regex::new(
+            r"(?:openjdk|jdk|temurin|corretto|graalvm|zulu|semeru|sapmachine)-?(\d+)",
+        )
+        .unwrap();
+        jdk_regex
+            .captures(self.tag.as_deref()?)
+            .map(|jdk_captures| jdk_captures[1].to_string())
+    }
+
+    /// The leading version in the tag: `3.8` for `3.8-slim`, `11` for `11-jdk-jammy`
+    pub fn tag_version(&self) -> Option<String> {
+        let tag = self.tag.as_deref()?.trim_start_matches('v');
+        let version_end = tag
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(tag.len());
+        let tag_version = tag[..version_end].trim_end_matches('.');
+        (!tag_version.is_empty()).then(|| tag_version.to_string())
+    }
+}
+
+pub fn parse_image_reference(image: &str) -> Option<ImageReference> {
+    let image = image.trim().trim_matches(['"', '\'']);
+    let image = image.split('@').next().unwrap_or(image);
+    // Build args and variables can't be resolved here
+    if image.is_empty() || image.contains('$') || image == "scratch" {
+        return None;
+    }
+
+    let mut path_segments: Vec<&str> = image.split('/').collect();
+    // A first segment with a dot, a port or `localhost` is a registry host
+    if path_segments.len() > 1
+        && (path_segments[0].contains(['.', ':']) || path_segments[0] == "localhost")
+    {
+        path_segments.remove(0);
+    }
+    let last_segment = path_segments.pop()?;
+    let (repository_name, tag) = match last_segment.split_once(':') {
+        Some((repository_name, tag)) => (repository_name, Some(tag.to_string())),
+        None => (last_segment, None),
+    };
+    path_segments.push(repository_name);
+
+    Some(ImageReference {
+        repository: path_segments.join("/").to_lowercase(),
+        tag,
+    })
+}
+
+/// The language and version an official (or CircleCI `cimg/`) language image runs
+pub fn image_language_version(image: &str) -> Option<(&'static str, String)> {
+    let image_reference = parse_image_reference(image)?;
+    if JDK_BUILD_TOOL_IMAGES.contains(&image_reference.image_name()) {
+        return Some(("Java", image_reference.tag_jdk_version()?));
+    }
+    let (_, language) = LANGUAGE_IMAGES
+        .iter()
+        .find(|(image_name, _)| *image_name == image_reference.image_name())?;
+    Some((language, image_reference.tag_version()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_language_version() {
+        assert_eq!(
+            image_language_version("python:3.8-slim"),
+            Some(("Python", "3.8".to_string()))
+        );
+        assert_eq!(
+            image_language_version("docker.io/library/node:14-alpine"),
+            Some(("JavaScript", "14".to_string()))
+        );
+        assert_eq!(
+            image_language_version("cimg/go:1.21.5"),
+            Some(("Go", "1.21.5".to_string()))
+        );
+        assert_eq!(
+            image_language_version("eclipse-temurin:11-jre@sha256:abc"),
+            Some(("Java", "11".to_string()))
+        );
+        assert_eq!(
+            image_language_version("maven:3.9.6-eclipse-temurin-21"),
+            Some(("Java", "21".to_string()))
+        );
+        assert_eq!(
+            image_language_version("gradle:8.5-jdk17"),
+            Some(("Java", "17".to_string()))
+        );
+        assert_eq!(
+            image_language_version("maven:3.9-amazoncorretto-17-debian"),
+            Some(("Java", "17".to_string()))
+        );
+        assert_eq!(image_language_version("gradle:8.5"), None);
+        assert_eq!(image_language_version("node:lts-alpine"), None);
+        assert_eq!(image_language_version("postgres:16"), None);
+        assert_eq!(image_language_version("${BASE_IMAGE}"), None);
+
+        let image_reference = parse_image_reference("localhost:5000/team/api").unwrap();
+        assert_eq!(image_reference.repository, "team/api");
+        assert!(image_reference.tag.is_none());
+    }
+}
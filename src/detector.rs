@@ -1,32 +1,108 @@
+use crate::build_tools;
+use crate::ci;
+use crate::code_quality;
+use crate::compose;
+use crate::config_files::{
+    read_ini_value, read_toml_file, read_top_level_yaml_value, strip_json_comments,
+};
+use crate::dockerfile;
+use crate::edn::{self, EdnValue};
 use crate::frameworks;
+use crate::go_mod;
+use crate::imports::{self, SourceImports};
 use crate::languages;
-use crate::project_info::ProjectInfo;
+use crate::notebooks::{self, Notebook};
+use crate::project_info::{
+    CiPipeline, DependencyReport, Dockerfile, ProjectInfo, RustToolchain, ToolVersion,
+    VersionConsistency, VersionSource,
+};
+use crate::requirements::{self, Requirement};
+use crate::test_frameworks;
+use crate::tool_versions;
+use crate::version_consistency;
+use crate::ExamineOptions;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub fn detect_project_info(project_path: &Path) -> Result<ProjectInfo, String> {
+    detect_project_info_with_options(project_path, &ExamineOptions::default())
+}
+
+pub fn detect_project_info_with_options(
+    project_path: &Path,
+    options: &ExamineOptions,
+) -> Result<ProjectInfo, String> {
     if !project_path.exists() {
         return Err(format!("Path does not exist: {}", project_path.display()));
     }
 
-    let detected_language = detect_language(project_path)?;
+    let project_notebooks = notebooks::read_notebooks(project_path);
+    let detected_language = detect_language(project_path, &project_notebooks)?;
     let mut project_info = ProjectInfo::new(
         detected_language.clone(),
         project_path.display().to_string(),
     );
 
-    if let Some(language_version) = detect_language_version(project_path, &detected_language) {
+    let pinned_tool_versions = tool_versions::read_tool_versions(project_path);
+    let detected_version =
+        detect_language_version(project_path, &detected_language, &pinned_tool_versions)
+            .or_else(|| {
+                tool_versions::find_language_version(&pinned_tool_versions, &detected_language)
+                    .map(|pin| (pin.version.clone(), Some(pin.source.clone())))
+            })
+            .or_else(|| {
+                // The kernel version a notebook was last run with
+                project_notebooks
+                    .iter()
+                    .filter(|notebook| notebook.language == Some(detected_language.as_str()))
+                    .find_map(|notebook| {
+                        let kernel_version = notebook.language_version.clone()?;
+                        let notebook_path = notebook.relative_path.display().to_string();
+                        Some((kernel_version, Some(notebook_path)))
+                    })
+            });
+
+    if let Some((language_version, version_source)) = detected_version {
         let language_status = languages::get_language_status(&detected_language, &language_version);
+        let version_conflicts = tool_versions::find_conflicts(
+            &pinned_tool_versions,
+            &detected_language,
+            &language_version,
+            version_source.as_deref(),
+        );
         project_info = project_info
             .with_language_version(language_version)
-            .with_language_status(language_status);
+            .with_language_status(language_status)
+            .with_version_conflicts(version_conflicts);
+        if let Some(source) = version_source {
+            project_info = project_info.with_language_version_source(source);
+        }
     }
 
-    if let Some((framework_name, framework_version)) =
-        detect_framework(project_path, &detected_language)
-    {
+    if !pinned_tool_versions.is_empty() {
+        project_info = project_info.with_tool_versions(pinned_tool_versions);
+    }
+
+    if let Some(notebook_summary) = notebooks::summarize_notebooks(&project_notebooks) {
+        project_info = project_info.with_notebooks(notebook_summary);
+    }
+
+    let source_imports = options
+        .scan_imports
+        .then(|| imports::scan_source_imports(project_path, &detected_language))
+        .flatten();
+    if let Some((framework_name, framework_version)) = detect_framework(
+        project_path,
+        &detected_language,
+        &project_notebooks,
+        source_imports.as_ref(),
+    ) {
         let framework_details = frameworks::get_framework_details(&framework_name);
-        project_info = project_info.with_framework(framework_name, framework_version);
+        let framework_category = frameworks::get_framework_category(&framework_name);
+        project_info = project_info
+            .with_framework(framework_name, framework_version)
+            .with_framework_category(framework_category);
         if let Some(details) = framework_details {
             project_info = project_info.with_framework_details(details);
         }
@@ -36,18 +112,109 @@ pub fn detect_project_info(project_path: &Path) -> Result<ProjectInfo, String> {
         project_info = project_info.with_project_name(project_name);
     }
 
+    if let Some((runtime_name, runtime_version)) = detect_runtime(
+        project_path,
+        &detected_language,
+        &project_info.tool_versions,
+    ) {
+        project_info = project_info.with_runtime(runtime_name, runtime_version);
+    }
+
+    if detected_language == "Rust" {
+        if let Some(rust_toolchain) = detect_rust_toolchain(project_path) {
+            project_info = project_info.with_rust_toolchain(rust_toolchain);
+        }
+    }
+
+    let detected_build_tools = build_tools::detect_build_tools(project_path);
+    if !detected_build_tools.is_empty() {
+        project_info = project_info.with_build_tools(detected_build_tools);
+    }
+
+    let code_quality_tools = code_quality::detect_code_quality_tools(project_path);
+    if !code_quality_tools.is_empty() {
+        project_info = project_info.with_code_quality(code_quality_tools);
+    }
+
+    if let Some(test_setup) =
+        test_frameworks::detect_test_setup(project_path, &collect_all_dependencies(project_path))
+    {
+        project_info = project_info.with_testing(test_setup);
+    }
+
+    let ci_pipelines = ci::detect_ci_pipelines(project_path);
+    if !ci_pipelines.is_empty() {
+        project_info = project_info.with_ci_pipelines(ci_pipelines);
+    }
+
+    let detected_dockerfiles = dockerfile::detect_dockerfiles(project_path);
+    if !detected_dockerfiles.is_empty() {
+        project_info = project_info.with_dockerfiles(detected_dockerfiles);
+    }
+
+    let compose_services = compose::detect_compose_services(project_path);
+    if !compose_services.is_empty() {
+        project_info = project_info.with_compose_services(compose_services);
+    }
+
+    let version_consistency_reports = detect_version_consistency(
+        project_path,
+        &project_info.tool_versions,
+        &project_info.dockerfiles,
+        &project_info.ci_pipelines,
+    );
+    if !version_consistency_reports.is_empty() {
+        project_info = project_info.with_version_consistency(version_consistency_reports);
+    }
+
+    if let Some(ref source_imports) = source_imports {
+        if let Some(dependency_report) =
+            detect_dependency_report(project_path, &detected_language, source_imports)
+        {
+            project_info = project_info.with_dependency_report(dependency_report);
+        }
+    }
+
+    let workspace_members: Vec<ProjectInfo> =
+        detect_workspace_members(project_path, &detected_language)
+            .iter()
+            .filter_map(|member_path| detect_project_info_with_options(member_path, options).ok())
+            .collect();
+    if !workspace_members.is_empty() {
+        project_info = project_info.with_members(workspace_members);
+    }
+
+    let cross_versions = detect_cross_versions(project_path, &detected_language);
+    if !cross_versions.is_empty() {
+        project_info = project_info.with_cross_versions(cross_versions);
+    }
+
     Ok(project_info)
 }
 
-fn detect_language(project_path: &Path) -> Result<String, String> {
+fn detect_language(project_path: &Path, project_notebooks: &[Notebook]) -> Result<String, String> {
     let language_detections = vec![
         ("Cargo.toml", "Rust"),
         ("package.json", "JavaScript"),
+        ("deno.json", "JavaScript"),
+        ("deno.jsonc", "JavaScript"),
+        ("bunfig.toml", "JavaScript"),
+        ("bun.lockb", "JavaScript"),
         ("go.mod", "Go"),
+        ("go.work", "Go"),
         ("pyproject.toml", "Python"),
         ("requirements.txt", "Python"),
+        ("requirements/*.txt", "Python"),
+        ("setup.py", "Python"),
+        ("setup.cfg", "Python"),
+        ("Pipfile", "Python"),
+        ("environment.yml", "Python"),
+        ("environment.yaml", "Python"),
         ("pom.xml", "Java"),
         ("build.gradle", "Java"),
+        ("build.sbt", "Scala"),
+        ("build.sc", "Scala"),
+        ("build.mill", "Scala"),
         ("composer.json", "PHP"),
         ("Gemfile", "Ruby"),
         ("Package.swift", "Swift"),
@@ -55,38 +222,69 @@ fn detect_language(project_path: &Path) -> Result<String, String> {
         ("mix.exs", "Elixir"),
         ("stack.yaml", "Haskell"),
         ("project.clj", "Clojure"),
+        ("deps.edn", "Clojure"),
+        ("shadow-cljs.edn", "Clojure"),
+        ("bb.edn", "Clojure"),
+        ("build.zig.zon", "Zig"),
+        ("build.zig", "Zig"),
+        ("*.nimble", "Nim"),
+        ("shard.yml", "Crystal"),
+        ("dune-project", "OCaml"),
+        ("*.opam", "OCaml"),
+        ("*.rockspec", "Lua"),
+        ("DESCRIPTION", "R"),
+        ("renv.lock", "R"),
+        ("Project.toml", "Julia"),
+        ("JuliaProject.toml", "Julia"),
         ("CMakeLists.txt", "C++"),
         ("Makefile", "C++"),
     ];
 
     for (project_file, detected_language) in language_detections {
-        if project_path.join(project_file).exists() {
+        if project_file_exists(project_path, project_file) {
             return Ok(detected_language.to_string());
         }
     }
 
     if let Ok(directory_entries) = fs::read_dir(project_path) {
-        let mut file_extension_counts = std::collections::HashMap::new();
+        let mut file_extension_counts = HashMap::new();
 
         for directory_entry in directory_entries.flatten() {
-            if let Some(file_extension) =
-                directory_entry.path().extension().and_then(|e| e.to_str())
-            {
+            let entry_path = directory_entry.path();
+            if let Some(file_extension) = entry_path.extension().and_then(|e| e.to_str()) {
                 let detected_language = match file_extension {
                     "rs" => "Rust",
                     "js" | "ts" | "jsx" | "tsx" => "JavaScript",
                     "go" => "Go",
                     "py" => "Python",
                     "java" => "Java",
+                    "scala" => "Scala",
                     "php" => "PHP",
                     "rb" => "Ruby",
                     "swift" => "Swift",
                     "dart" => "Dart",
                     "ex" | "exs" => "Elixir",
                     "hs" => "Haskell",
-                    "clj" | "cljs" => "Clojure",
+                    "clj" | "cljs" | "cljc" => "Clojure",
                     "c" | "cpp" | "cc" | "cxx" => "C++",
                     "cs" => "C#",
+                    "zig" => "Zig",
+                    "nim" => "Nim",
+                    "cr" => "Crystal",
+                    "ml" | "mli" => "OCaml",
+                    "lua" => "Lua",
+                    "r" | "R" => "R",
+                    "jl" => "Julia",
+                    "ipynb" => match project_notebooks
+                        .iter()
+                        .find(|notebook| {
+                            notebook.relative_path.as_os_str() == directory_entry.file_name()
+                        })
+                        .and_then(|notebook| notebook.language)
+                    {
+                        Some(kernel_language) => kernel_language,
+                        None => continue,
+                    },
                     _ => continue,
                 };
                 *file_extension_counts.entry(detected_language).or_insert(0) += 1;
@@ -100,34 +298,202 @@ fn detect_language(project_path: &Path) -> Result<String, String> {
         }
     }
 
+    // Notebook-only repos often keep everything under `notebooks/`
+    if let Some(kernel_language) = notebooks::summarize_notebooks(project_notebooks)
+        .and_then(|notebook_summary| notebook_summary.kernel_language)
+    {
+        return Ok(kernel_language);
+    }
+
     Err("Could not detect project language".to_string())
 }
 
-fn detect_language_version(project_path: &Path, detected_language: &str) -> Option<String> {
-    match detected_language {
+/// The detected language version and, where known, the file it was pinned in
+fn detect_language_version(
+    project_path: &Path,
+    detected_language: &str,
+    pinned_tool_versions: &[ToolVersion],
+) -> Option<(String, Option<String>)> {
+    let language_version = match detected_language {
+        "JavaScript" => {
+            return detect_node_version_with_source(project_path, pinned_tool_versions)
+                .map(|(node_version, version_source)| (node_version, Some(version_source)))
+        }
+        "Python" => {
+            return detect_python_version_with_source(project_path)
+                .map(|(python_version, version_source)| (python_version, Some(version_source)))
+        }
         "Rust" => detect_rust_version(project_path),
-        "JavaScript" => detect_node_version(project_path),
         "Go" => detect_go_version(project_path),
-        "Python" => detect_python_version(project_path),
         "Java" => detect_java_version(project_path),
+        "Scala" => detect_scala_version(project_path),
+        "Clojure" => detect_clojure_version(project_path),
+        "Zig" => detect_zig_version(project_path),
+        "Nim" => detect_nim_version(project_path),
+        "Crystal" => detect_crystal_version(project_path),
+        "OCaml" => detect_ocaml_version(project_path),
+        "Lua" => detect_lua_version(project_path),
+        "R" => detect_r_version(project_path),
+        "Julia" => detect_julia_version(project_path),
         _ => None,
-    }
+    };
+    language_version.map(|version| (version, None))
 }
 
+/// Declared frameworks are checked against `source_imports` when a scan ran;
+/// a framework the source never imports is passed over for the next one,
+/// unless its own config file confirms it
 fn detect_framework(
     project_path: &Path,
     detected_language: &str,
+    project_notebooks: &[Notebook],
+    source_imports: Option<&SourceImports>,
 ) -> Option<(String, Option<String>)> {
-    match detected_language {
-        "Rust" => detect_rust_framework(project_path),
-        "JavaScript" => detect_javascript_framework(project_path),
-        "Go" => detect_go_framework(project_path),
-        "Python" => detect_python_framework(project_path),
+    let is_imported = |package_name: &str| {
+        source_imports.map_or(true, |source_imports| {
+            source_imports.imports_package(package_name)
+        })
+    };
+    let configured_framework = detect_framework_from_config_files(project_path, detected_language);
+    let manifest_framework = match detected_language {
+        "Rust" => detect_rust_framework(project_path, &is_imported),
+        "JavaScript" => {
+            detect_javascript_framework(project_path, configured_framework, &is_imported)
+        }
+        "Go" => detect_go_framework(project_path, &is_imported),
+        "Python" => detect_python_framework(project_path, project_notebooks, &is_imported),
         "Java" => detect_java_framework(project_path),
+        "Scala" => detect_scala_framework(project_path),
+        "Clojure" => detect_clojure_framework(project_path),
+        _ => None,
+    };
+    manifest_framework
+        .or_else(|| configured_framework.map(|framework_name| (framework_name.to_string(), None)))
+        .or_else(|| {
+            detect_framework_from_imports(source_imports?, detected_language)
+                .map(|framework_name| (framework_name.to_string(), None))
+        })
+}
+
+/// The first framework in the language's priority table that the source imports
+///
+/// Only explicit imports count here: a bare `name::path` root may be a local module.
+fn detect_framework_from_imports(
+    source_imports: &SourceImports,
+    detected_language: &str,
+) -> Option<&'static str> {
+    let framework_packages: &[(&str, &str)] = match detected_language {
+        "Rust" => &RUST_FRAMEWORK_CRATES,
+        "JavaScript" => &JAVASCRIPT_FRAMEWORK_PACKAGES,
+        "Go" => &GO_FRAMEWORK_MODULES,
+        "Python" => &PYTHON_FRAMEWORK_PACKAGES,
+        _ => return None,
+    };
+    framework_packages
+        .iter()
+        .find(|(package_name, _)| {
+            source_imports
+                .imported_packages
+                .iter()
+                .any(|imported_package| {
+                    imports::package_provides(detected_language, package_name, imported_package)
+                })
+        })
+        .map(|(_, framework_name)| *framework_name)
+}
+
+/// Framework evidence from the framework's own config files
+///
+/// Covers packages whose dependencies are hoisted to a monorepo root, and
+/// projects whose manifest doesn't name the framework at all.
+fn detect_framework_from_config_files(
+    project_path: &Path,
+    detected_language: &str,
+) -> Option<&'static str> {
+    let framework_config_files = [
+        ("JavaScript", "next.config.*", "Next.js"),
+        ("JavaScript", "nuxt.config.*", "Nuxt"),
+        ("JavaScript", "remix.config.*", "Remix"),
+        ("JavaScript", "astro.config.*", "Astro"),
+        ("JavaScript", "gatsby-config.*", "Gatsby"),
+        ("JavaScript", "angular.json", "Angular"),
+        ("JavaScript", "svelte.config.*", "Svelte"),
+        ("Python", "manage.py", "Django"),
+        ("Ruby", "config/application.rb", "Rails"),
+        ("Rust", "Rocket.toml", "Rocket"),
+    ];
+
+    for (config_language, config_file, framework_name) in framework_config_files {
+        if config_language != detected_language || !project_file_exists(project_path, config_file) {
+            continue;
+        }
+        // svelte.config.js is also used by plain Svelte + Vite apps
+        if framework_name == "Svelte"
+            && file_with_prefix_mentions(project_path, "svelte.config.", "@sveltejs/kit")
+        {
+            return Some("SvelteKit");
+        }
+        return Some(framework_name);
+    }
+
+    // Django keeps settings.py in the project package, one level down
+    if detected_language == "Python" && has_django_settings(project_path) {
+        return Some("Django");
+    }
+
+    None
+}
+
+/// Whether any file named `<prefix>*` in the project directory contains `needle`
+fn file_with_prefix_mentions(project_path: &Path, file_prefix: &str, needle: &str) -> bool {
+    fs::read_dir(project_path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|directory_entry| {
+            directory_entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(file_prefix)
+        })
+        .any(|directory_entry| {
+            fs::read_to_string(directory_entry.path())
+                .is_ok_and(|file_content| file_content.contains(needle))
+        })
+}
+
+fn has_django_settings(project_path: &Path) -> bool {
+    fs::read_dir(project_path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|directory_entry| directory_entry.path().join("settings.py"))
+        .any(|settings_path| {
+            fs::read_to_string(settings_path).is_ok_and(|settings_content| {
+                settings_content.contains("INSTALLED_APPS") && settings_content.contains("django")
+            })
+        })
+}
+
+fn detect_runtime(
+    project_path: &Path,
+    detected_language: &str,
+    pinned_tool_versions: &[ToolVersion],
+) -> Option<(String, Option<String>)> {
+    match detected_language {
+        "JavaScript" => detect_javascript_runtime(project_path, pinned_tool_versions),
+        "Clojure" => detect_clojure_runtime(project_path),
         _ => None,
     }
 }
 
+fn detect_cross_versions(project_path: &Path, detected_language: &str) -> Vec<String> {
+    match detected_language {
+        "Scala" => detect_scala_cross_versions(project_path),
+        _ => Vec::new(),
+    }
+}
+
 fn detect_project_name(project_path: &Path, detected_language: &str) -> Option<String> {
     match detected_language {
         "Rust" => {
@@ -153,32 +519,117 @@ fn detect_project_name(project_path: &Path, detected_language: &str) -> Option<S
                         .map(|name_string| name_string.to_string());
                 }
             }
+            if let Some(package_name) = read_deno_config(project_path)
+                .as_ref()
+                .and_then(|deno_config| deno_config.get("name"))
+                .and_then(|name_value| name_value.as_str())
+            {
+                return Some(package_name.to_string());
+            }
         }
         "Go" => {
-            let go_mod_path = project_path.join("go.mod");
-            if let Ok(go_mod_content) = fs::read_to_string(&go_mod_path) {
-                for content_line in go_mod_content.lines() {
-                    if content_line.starts_with("module ") {
-                        let module_name = content_line.strip_prefix("module ").unwrap_or("").trim();
-                        if let Some(last_path_segment) = module_name.split('/').last() {
-                            return Some(last_path_segment.to_string());
-                        }
-                    }
+            if let Some(module_path) = go_mod::read_go_mod(&project_path.join("go.mod"))
+                .and_then(|go_module| go_module.module_path)
+            {
+                if let Some(last_path_segment) = module_path.split('/').next_back() {
+                    return Some(last_path_segment.to_string());
                 }
             }
         }
         "Python" => {
-            let pyproject_path = project_path.join("pyproject.toml");
-            if let Ok(pyproject_content) = fs::read_to_string(&pyproject_path) {
-                if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&pyproject_content) {
-                    return parsed_toml
-                        .get("project")
-                        .and_then(|project_section| project_section.get("name"))
-                        .and_then(|name_value| name_value.as_str())
-                        .map(|name_string| name_string.to_string());
+            if let Some(project_name) = detect_python_project_name(project_path) {
+                return Some(project_name);
+            }
+        }
+        "Scala" => {
+            let scala_build = read_scala_build(project_path);
+            let name_setting = regex::Regex::new(r"\bname\s*:=\s*([^\s,)]+)").unwrap();
+            if let Some(captures) = name_setting.captures(&scala_build.build_content) {
+                if let Some(project_name) = scala_build.resolve(&captures[1]) {
+                    return Some(project_name);
+                }
+            }
+        }
+        "Zig" => {
+            if let Ok(zon_content) = fs::read_to_string(project_path.join("build.zig.zon")) {
+                if let Some(package_name) = read_zon_field(&zon_content, "name") {
+                    return Some(package_name);
+                }
+            }
+        }
+        "Nim" => {
+            if let Some(package_name) = find_file_with_extension(project_path, "nimble")
+                .as_deref()
+                .and_then(|nimble_path| nimble_path.file_stem())
+                .and_then(|file_stem| file_stem.to_str())
+            {
+                return Some(package_name.to_string());
+            }
+        }
+        "Crystal" => {
+            if let Ok(shard_content) = fs::read_to_string(project_path.join("shard.yml")) {
+                if let Some(shard_name) = read_top_level_yaml_value(&shard_content, "name") {
+                    return Some(shard_name);
+                }
+            }
+        }
+        "OCaml" => {
+            if let Some(dune_name) = read_dune_project(project_path)
+                .iter()
+                .find_map(|stanza| dune_stanza_argument(stanza, "name"))
+            {
+                return Some(dune_name);
+            }
+            if let Some(package_name) = find_file_with_extension(project_path, "opam")
+                .as_deref()
+                .and_then(|opam_path| opam_path.file_stem())
+                .and_then(|file_stem| file_stem.to_str())
+            {
+                return Some(package_name.to_string());
+            }
+        }
+        "Lua" => {
+            if let Some(rockspec_content) = find_file_with_extension(project_path, "rockspec")
+                .and_then(|rockspec_path| fs::read_to_string(rockspec_path).ok())
+            {
+                let package_pattern =
+                    regex::Regex::new(r#"(?m)^\s*package\s*=\s*["']([^"']+)["']"#).unwrap();
+                if let Some(captures) = package_pattern.captures(&rockspec_content) {
+                    return Some(captures[1].to_string());
+                }
+            }
+        }
+        "R" => {
+            if let Ok(description_content) = fs::read_to_string(project_path.join("DESCRIPTION")) {
+                if let Some(package_name) = read_dcf_field(&description_content, "Package") {
+                    return Some(package_name);
                 }
             }
         }
+        "Julia" => {
+            for project_file in ["JuliaProject.toml", "Project.toml"] {
+                if let Ok(project_content) = fs::read_to_string(project_path.join(project_file)) {
+                    if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&project_content) {
+                        if let Some(package_name) = parsed_toml
+                            .get("name")
+                            .and_then(|name_value| name_value.as_str())
+                        {
+                            return Some(package_name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        "Clojure" => {
+            if let Some(project_name) = read_leiningen_project(project_path)
+                .as_ref()
+                .and_then(|defproject_form| defproject_form.get(1))
+                .and_then(|name_value| name_value.as_symbol())
+                .and_then(|qualified_name| qualified_name.split('/').next_back())
+            {
+                return Some(project_name.to_string());
+            }
+        }
         _ => {}
     }
 
@@ -202,17 +653,27 @@ fn detect_rust_version(project_path: &Path) -> Option<String> {
         }
     }
 
-    let toolchain_toml_path = project_path.join("rust-toolchain.toml");
-    if let Ok(toolchain_content) = fs::read_to_string(&toolchain_toml_path) {
-        if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&toolchain_content) {
-            if let Some(toolchain_channel) = parsed_toml
+    read_rust_toolchain_channel(project_path)
+}
+
+/// The `[toolchain]` table of `rust-toolchain.toml`, or of a TOML `rust-toolchain` file
+fn read_rust_toolchain_section(project_path: &Path) -> Option<toml::Value> {
+    ["rust-toolchain.toml", "rust-toolchain"]
+        .into_iter()
+        .find_map(|toolchain_file| {
+            read_toml_file(&project_path.join(toolchain_file))?
                 .get("toolchain")
-                .and_then(|toolchain_section| toolchain_section.get("channel"))
-                .and_then(|channel_value| channel_value.as_str())
-            {
-                return Some(toolchain_channel.to_string());
-            }
-        }
+                .cloned()
+        })
+}
+
+/// The pinned channel, from the TOML toolchain files or a legacy one-line `rust-toolchain`
+fn read_rust_toolchain_channel(project_path: &Path) -> Option<String> {
+    if let Some(toolchain_section) = read_rust_toolchain_section(project_path) {
+        return toolchain_section
+            .get("channel")
+            .and_then(|channel_value| channel_value.as_str())
+            .map(|toolchain_channel| toolchain_channel.to_string());
     }
 
     let toolchain_file_path = project_path.join("rust-toolchain");
@@ -223,414 +684,3402 @@ fn detect_rust_version(project_path: &Path) -> Option<String> {
     None
 }
 
-fn detect_node_version(project_path: &Path) -> Option<String> {
-    let nvmrc_path = project_path.join(".nvmrc");
-    if let Ok(nvmrc_content) = fs::read_to_string(&nvmrc_path) {
-        return Some(nvmrc_content.trim().to_string());
+/// The `rust-version` minimum and the toolchain file pin
+fn collect_rust_version_sources(project_path: &Path) -> Vec<(String, String)> {
+    let mut rust_versions = Vec::new();
+
+    if let Some(rust_version) = read_toml_file(&project_path.join("Cargo.toml"))
+        .as_ref()
+        .and_then(|cargo_toml| cargo_toml.get("package"))
+        .and_then(|package_section| package_section.get("rust-version"))
+        .and_then(|version_value| version_value.as_str())
+    {
+        rust_versions.push((
+            format!(">={}", rust_version),
+            "Cargo.toml rust-version".to_string(),
+        ));
     }
 
-    let package_json_path = project_path.join("package.json");
-    if let Ok(package_content) = fs::read_to_string(&package_json_path) {
-        if let Ok(parsed_json) = serde_json::from_str::<serde_json::Value>(&package_content) {
-            if let Some(node_version) = parsed_json
-                .get("engines")
-                .and_then(|engines_section| engines_section.get("node"))
-                .and_then(|node_value| node_value.as_str())
-            {
-                return Some(node_version.to_string());
-            }
-        }
+    if let Some(toolchain_channel) = read_rust_toolchain_channel(project_path) {
+        let toolchain_file = if project_path.join("rust-toolchain.toml").exists() {
+            "rust-toolchain.toml"
+        } else {
+            "rust-toolchain"
+        };
+        rust_versions.push((toolchain_channel, toolchain_file.to_string()));
     }
 
-    None
+    rust_versions
 }
 
-fn detect_go_version(project_path: &Path) -> Option<String> {
-    let go_mod_path = project_path.join("go.mod");
-    if let Ok(go_mod_content) = fs::read_to_string(&go_mod_path) {
-        for content_line in go_mod_content.lines() {
-            if content_line.starts_with("go ") {
-                let go_version = content_line.strip_prefix("go ").unwrap_or("").trim();
-                return Some(go_version.to_string());
-            }
+fn detect_rust_toolchain(project_path: &Path) -> Option<RustToolchain> {
+    let cargo_toml = read_toml_file(&project_path.join("Cargo.toml"));
+    let workspace_edition = cargo_toml
+        .as_ref()
+        .and_then(|cargo_toml| cargo_toml.get("workspace"))
+        .and_then(|workspace_section| workspace_section.get("package"))
+        .and_then(|workspace_package| workspace_package.get("edition"))
+        .and_then(|edition_value| edition_value.as_str());
+    let package_section = cargo_toml
+        .as_ref()
+        .and_then(|cargo_toml| cargo_toml.get("package"));
+    let edition = match package_section.and_then(|package_section| package_section.get("edition")) {
+        Some(toml::Value::String(package_edition)) => Some(package_edition.as_str()),
+        // `edition.workspace = true` inherits `[workspace.package]`
+        Some(_) => workspace_edition,
+        // Cargo's default when a package leaves `edition` out
+        None if package_section.is_some() => Some("2015"),
+        None => workspace_edition,
+    };
+
+    let toolchain_section = read_rust_toolchain_section(project_path);
+    let toolchain_strings = |key: &str| -> Vec<String> {
+        toolchain_section
+            .as_ref()
+            .and_then(|toolchain_section| toolchain_section.get(key))
+            .and_then(|list_value| list_value.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|list_item| list_item.as_str())
+            .map(|list_item| list_item.to_string())
+            .collect()
+    };
+
+    let cargo_config = [".cargo/config.toml", ".cargo/config"]
+        .into_iter()
+        .find_map(|config_file| read_toml_file(&project_path.join(config_file)));
+    let build_targets: Vec<String> = match cargo_config
+        .as_ref()
+        .and_then(|cargo_config| cargo_config.get("build"))
+        .and_then(|build_section| build_section.get("target"))
+    {
+        Some(toml::Value::String(build_target)) => vec![build_target.clone()],
+        Some(toml::Value::Array(build_target_list)) => build_target_list
+            .iter()
+            .filter_map(|build_target| build_target.as_str())
+            .map(|build_target| build_target.to_string())
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let rust_toolchain = RustToolchain {
+        edition: edition.map(|edition| edition.to_string()),
+        channel: read_rust_toolchain_channel(project_path),
+        profile: toolchain_section
+            .as_ref()
+            .and_then(|toolchain_section| toolchain_section.get("profile"))
+            .and_then(|profile_value| profile_value.as_str())
+            .map(|profile| profile.to_string()),
+        components: toolchain_strings("components"),
+        targets: toolchain_strings("targets"),
+        build_targets,
+    };
+
+    let is_empty = rust_toolchain.edition.is_none()
+        && rust_toolchain.channel.is_none()
+        && rust_toolchain.build_targets.is_empty();
+    (!is_empty).then_some(rust_toolchain)
+}
+
+fn detect_node_version(
+    project_path: &Path,
+    pinned_tool_versions: &[ToolVersion],
+) -> Option<String> {
+    detect_node_version_with_source(project_path, pinned_tool_versions)
+        .map(|(node_version, _)| node_version)
+}
+
+/// Node version pin plus the file (or `package.json` field) it was read from
+fn detect_node_version_with_source(
+    project_path: &Path,
+    pinned_tool_versions: &[ToolVersion],
+) -> Option<(String, String)> {
+    collect_node_version_sources(project_path, pinned_tool_versions)
+        .into_iter()
+        .next()
+}
+
+/// Every Node version pin and requirement, in precedence order
+fn collect_node_version_sources(
+    project_path: &Path,
+    pinned_tool_versions: &[ToolVersion],
+) -> Vec<(String, String)> {
+    let mut node_versions = Vec::new();
+
+    for version_file in [".nvmrc", ".node-version"] {
+        if let Some(node_version) = read_version_file(&project_path.join(version_file)) {
+            node_versions.push((node_version, version_file.to_string()));
         }
     }
-    None
-}
 
-fn detect_python_version(project_path: &Path) -> Option<String> {
-    let python_version_path = project_path.join(".python-version");
-    if let Ok(version_content) = fs::read_to_string(&python_version_path) {
-        return Some(version_content.trim().to_string());
+    let parsed_package_json = fs::read_to_string(project_path.join("package.json"))
+        .ok()
+        .and_then(|package_content| {
+            serde_json::from_str::<serde_json::Value>(&package_content).ok()
+        });
+    let package_json_string = |section: &str, key: &str| {
+        parsed_package_json
+            .as_ref()
+            .and_then(|package_json| package_json.get(section))
+            .and_then(|section_value| section_value.get(key))
+            .and_then(|node_value| node_value.as_str())
+            .map(|node_version| node_version.to_string())
+    };
+
+    if let Some(node_version) = package_json_string("volta", "node") {
+        node_versions.push((node_version, "package.json volta.node".to_string()));
     }
 
-    let pyproject_path = project_path.join("pyproject.toml");
-    if let Ok(pyproject_content) = fs::read_to_string(&pyproject_path) {
-        if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&pyproject_content) {
-            if let Some(python_version) = parsed_toml
-                .get("project")
-                .and_then(|project_section| project_section.get("requires-python"))
-                .and_then(|version_value| version_value.as_str())
-            {
-                return Some(python_version.to_string());
-            }
-        }
+    if let Some(node_pin) = tool_versions::find_language_version(pinned_tool_versions, "JavaScript")
+    {
+        node_versions.push((node_pin.version.clone(), node_pin.source.clone()));
     }
 
-    None
-}
+    if let Some(node_version) = package_json_string("engines", "node") {
+        node_versions.push((node_version, "package.json engines.node".to_string()));
+    }
 
-fn detect_java_version(_project_path: &Path) -> Option<String> {
-    None
+    // `.nvmrc` style aliases such as `lts/hydrogen` become a plain major version
+    node_versions
+        .into_iter()
+        .map(|(raw_version, version_source)| {
+            let node_version = languages::resolve_node_alias(&raw_version).unwrap_or(raw_version);
+            (node_version, version_source)
+        })
+        .collect()
 }
 
-fn detect_rust_framework(project_path: &Path) -> Option<(String, Option<String>)> {
-    let cargo_toml_path = project_path.join("Cargo.toml");
-    if let Ok(cargo_content) = fs::read_to_string(&cargo_toml_path) {
-        if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&cargo_content) {
-            if let Some(dependencies_table) = parsed_toml
-                .get("dependencies")
-                .and_then(|deps| deps.as_table())
-            {
-                if dependencies_table.contains_key("axum") {
-                    let framework_version = get_dependency_version(dependencies_table, "axum");
-                    return Some(("Axum".to_string(), framework_version));
-                }
-                if dependencies_table.contains_key("actix-web") {
-                    let framework_version = get_dependency_version(dependencies_table, "actix-web");
-                    return Some(("Actix Web".to_string(), framework_version));
-                }
-                if dependencies_table.contains_key("warp") {
-                    let framework_version = get_dependency_version(dependencies_table, "warp");
-                    return Some(("Warp".to_string(), framework_version));
-                }
-                if dependencies_table.contains_key("rocket") {
-                    let framework_version = get_dependency_version(dependencies_table, "rocket");
-                    return Some(("Rocket".to_string(), framework_version));
-                }
-                if dependencies_table.contains_key("clap") {
-                    let framework_version = get_dependency_version(dependencies_table, "clap");
-                    return Some(("Clap (CLI)".to_string(), framework_version));
-                }
-                if dependencies_table.contains_key("bevy") {
-                    let framework_version = get_dependency_version(dependencies_table, "bevy");
-                    return Some(("Bevy".to_string(), framework_version));
-                }
-            }
-        }
+fn detect_javascript_runtime(
+    project_path: &Path,
+    pinned_tool_versions: &[ToolVersion],
+) -> Option<(String, Option<String>)> {
+    let parsed_package_json = fs::read_to_string(project_path.join("package.json"))
+        .ok()
+        .and_then(|package_content| {
+            serde_json::from_str::<serde_json::Value>(&package_content).ok()
+        });
+
+    // Pinned version of a runtime tool like `deno` or `bun` from `.tool-versions`/mise
+    let runtime_tool_version = |tool_name: &str| {
+        tool_versions::find_tool_version(pinned_tool_versions, tool_name)
+            .map(|runtime_pin| runtime_pin.version.clone())
+    };
+
+    if ["deno.json", "deno.jsonc", "deno.lock"]
+        .iter()
+        .any(|deno_file| project_path.join(deno_file).exists())
+    {
+        let deno_version = read_version_file(&project_path.join(".dvmrc"))
+            .or_else(|| runtime_tool_version("deno"));
+        return Some(("Deno".to_string(), deno_version));
     }
-    None
-}
 
-fn detect_javascript_framework(project_path: &Path) -> Option<(String, Option<String>)> {
-    let package_json_path = project_path.join("package.json");
-    if let Ok(package_content) = fs::read_to_string(&package_json_path) {
-        if let Ok(parsed_json) = serde_json::from_str::<serde_json::Value>(&package_content) {
-            let production_dependencies = parsed_json
-                .get("dependencies")
-                .and_then(|deps| deps.as_object());
-            let development_dependencies = parsed_json
-                .get("devDependencies")
-                .and_then(|deps| deps.as_object());
+    let package_manager = parsed_package_json
+        .as_ref()
+        .and_then(|package_json| package_json.get("packageManager"))
+        .and_then(|manager_value| manager_value.as_str());
+    let declares_bun_types = parsed_package_json
+        .as_ref()
+        .and_then(|package_json| package_json.get("devDependencies"))
+        .and_then(|deps| deps.as_object())
+        .is_some_and(|deps| deps.contains_key("@types/bun") || deps.contains_key("bun-types"));
 
-            for dependency_object in [production_dependencies, development_dependencies]
-                .iter()
-                .filter_map(|deps| *deps)
-            {
-                if dependency_object.contains_key("react") {
-                    let framework_version = dependency_object
-                        .get("react")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("React".to_string(), framework_version));
-                }
-                if dependency_object.contains_key("vue") {
-                    let framework_version = dependency_object
-                        .get("vue")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("Vue".to_string(), framework_version));
-                }
-                if dependency_object.contains_key("@angular/core") {
-                    let framework_version = dependency_object
-                        .get("@angular/core")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("Angular".to_string(), framework_version));
-                }
-                if dependency_object.contains_key("svelte") {
-                    let framework_version = dependency_object
-                        .get("svelte")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("Svelte".to_string(), framework_version));
-                }
-                if dependency_object.contains_key("express") {
-                    let framework_version = dependency_object
-                        .get("express")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("Express".to_string(), framework_version));
-                }
-                if dependency_object.contains_key("next") {
-                    let framework_version = dependency_object
-                        .get("next")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("Next.js".to_string(), framework_version));
-                }
-                if dependency_object.contains_key("nuxt") {
-                    let framework_version = dependency_object
-                        .get("nuxt")
-                        .and_then(|version| version.as_str())
-                        .map(|s| s.to_string());
-                    return Some(("Nuxt".to_string(), framework_version));
-                }
-            }
-        }
+    if ["bunfig.toml", "bun.lockb", "bun.lock"]
+        .iter()
+        .any(|bun_file| project_path.join(bun_file).exists())
+        || package_manager.is_some_and(|manager| manager.starts_with("bun@"))
+        || declares_bun_types
+    {
+        let bun_version = read_version_file(&project_path.join(".bun-version"))
+            .or_else(|| runtime_tool_version("bun"))
+            .or_else(|| {
+                package_manager
+                    .and_then(|manager| manager.strip_prefix("bun@"))
+                    .map(|version| version.to_string())
+            })
+            .or_else(|| {
+                parsed_package_json
+                    .as_ref()
+                    .and_then(|package_json| package_json.get("engines"))
+                    .and_then(|engines_section| engines_section.get("bun"))
+                    .and_then(|bun_value| bun_value.as_str())
+                    .map(|version| version.to_string())
+            });
+        return Some(("Bun".to_string(), bun_version));
     }
-    None
-}
 
-fn detect_go_framework(project_path: &Path) -> Option<(String, Option<String>)> {
-    let go_mod_path = project_path.join("go.mod");
-    if let Ok(go_mod_content) = fs::read_to_string(&go_mod_path) {
-        if go_mod_content.contains("github.com/gin-gonic/gin") {
-            return Some(("Gin".to_string(), None));
-        }
-        if go_mod_content.contains("github.com/gorilla/mux") {
-            return Some(("Gorilla Mux".to_string(), None));
-        }
-        if go_mod_content.contains("github.com/labstack/echo") {
-            return Some(("Echo".to_string(), None));
-        }
-        if go_mod_content.contains("github.com/gofiber/fiber") {
-            return Some(("Fiber".to_string(), None));
-        }
+    if parsed_package_json.is_some() {
+        return Some((
+            "Node".to_string(),
+            detect_node_version(project_path, pinned_tool_versions),
+        ));
     }
+
     None
 }
 
-fn detect_python_framework(project_path: &Path) -> Option<(String, Option<String>)> {
-    let requirements_path = project_path.join("requirements.txt");
-    if let Ok(requirements_content) = fs::read_to_string(&requirements_path) {
-        for requirement_line in requirements_content.lines() {
-            let normalized_line = requirement_line.trim().to_lowercase();
-            if normalized_line.starts_with("django") {
-                return Some((
-                    "Django".to_string(),
-                    extract_version_from_requirement(&normalized_line),
-                ));
-            }
-            if normalized_line.starts_with("flask") {
-                return Some((
-                    "Flask".to_string(),
-                    extract_version_from_requirement(&normalized_line),
-                ));
-            }
-            if normalized_line.starts_with("fastapi") {
-                return Some((
-                    "FastAPI".to_string(),
-                    extract_version_from_requirement(&normalized_line),
-                ));
-            }
-        }
+fn detect_go_version(project_path: &Path) -> Option<String> {
+    if let Some(go_module) = go_mod::read_go_mod(&project_path.join("go.mod")) {
+        return go_module.build_go_version();
     }
+    go_mod::read_go_work(project_path).and_then(|go_workspace| go_workspace.build_go_version())
+}
 
-    let pyproject_path = project_path.join("pyproject.toml");
-    if let Ok(pyproject_content) = fs::read_to_string(&pyproject_path) {
-        if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&pyproject_content) {
-            if let Some(dependency_array) = parsed_toml
-                .get("project")
-                .and_then(|project_section| project_section.get("dependencies"))
-                .and_then(|deps| deps.as_array())
-            {
-                for dependency_value in dependency_array {
-                    if let Some(dependency_string) = dependency_value.as_str() {
-                        let normalized_dependency = dependency_string.to_lowercase();
-                        if normalized_dependency.starts_with("django") {
-                            return Some((
-                                "Django".to_string(),
-                                extract_version_from_requirement(&normalized_dependency),
-                            ));
-                        }
-                        if normalized_dependency.starts_with("flask") {
-                            return Some((
-                                "Flask".to_string(),
-                                extract_version_from_requirement(&normalized_dependency),
-                            ));
-                        }
-                        if normalized_dependency.starts_with("fastapi") {
-                            return Some((
-                                "FastAPI".to_string(),
-                                extract_version_from_requirement(&normalized_dependency),
-                            ));
-                        }
-                    }
-                }
-            }
+/// The `go` minimums and `toolchain` pins of `go.mod` and `go.work`
+fn collect_go_version_sources(project_path: &Path) -> Vec<(String, String)> {
+    let go_directives = [
+        (
+            "go.mod",
+            go_mod::read_go_mod(&project_path.join("go.mod"))
+                .map(|go_module| (go_module.go_version, go_module.toolchain)),
+        ),
+        (
+            "go.work",
+            go_mod::read_go_work(project_path)
+                .map(|go_workspace| (go_workspace.go_version, go_workspace.toolchain)),
+        ),
+    ];
+    let mut go_versions = Vec::new();
+
+    for (go_file, directives) in go_directives {
+        let Some((go_version, toolchain_name)) = directives else {
+            continue;
+        };
+        if let Some(go_version) = go_version {
+            go_versions.push((format!(">={}", go_version), format!("{} go", go_file)));
+        }
+        if let Some(toolchain_version) = toolchain_name
+            .as_deref()
+            .and_then(go_mod::toolchain_go_version)
+        {
+            go_versions.push((toolchain_version, format!("{} toolchain", go_file)));
         }
     }
 
-    None
+    go_versions
 }
 
-fn detect_java_framework(_project_path: &Path) -> Option<(String, Option<String>)> {
-    None
+/// Python version requirement plus the file it was read from
+fn detect_python_version_with_source(project_path: &Path) -> Option<(String, String)> {
+    collect_python_version_sources(project_path)
+        .into_iter()
+        .next()
 }
 
-fn get_dependency_version(
-    dependencies_table: &toml::map::Map<String, toml::Value>,
-    dependency_name: &str,
-) -> Option<String> {
-    dependencies_table
-        .get(dependency_name)
-        .and_then(|dependency_value| match dependency_value {
-            toml::Value::String(version_string) => Some(version_string.clone()),
-            toml::Value::Table(dependency_table) => dependency_table
-                .get("version")
+/// Every Python version pin and requirement, in precedence order
+fn collect_python_version_sources(project_path: &Path) -> Vec<(String, String)> {
+    let mut python_versions = Vec::new();
+
+    if let Some(python_version) = read_version_file(&project_path.join(".python-version")) {
+        python_versions.push((python_version, ".python-version".to_string()));
+    }
+
+    if let Some(pyproject) = read_pyproject(project_path) {
+        if let Some(python_version) = pyproject
+            .get("project")
+            .and_then(|project_section| project_section.get("requires-python"))
+            .and_then(|version_value| version_value.as_str())
+        {
+            python_versions.push((python_version.to_string(), "pyproject.toml".to_string()));
+        }
+
+        // [tool.poetry.dependencies] python = "^3.11"
+        if let Some(python_version) = read_poetry_section(&pyproject)
+            .and_then(|poetry_section| poetry_section.get("dependencies"))
+            .and_then(|dependencies_table| dependencies_table.get("python"))
+            .and_then(|version_value| version_value.as_str())
+        {
+            python_versions.push((python_version.to_string(), "pyproject.toml".to_string()));
+        }
+    }
+
+    if let Some(pipfile) = read_toml_file(&project_path.join("Pipfile")) {
+        if let Some(python_version) = pipfile.get("requires").and_then(|requires_section| {
+            requires_section
+                .get("python_full_version")
+                .or_else(|| requires_section.get("python_version"))
                 .and_then(|version_value| version_value.as_str())
-                .map(|version_string| version_string.to_string()),
-            _ => None,
+        }) {
+            python_versions.push((python_version.to_string(), "Pipfile".to_string()));
+        }
+    }
+
+    if let Ok(setup_cfg_content) = fs::read_to_string(project_path.join("setup.cfg")) {
+        if let Some(python_version) =
+            read_ini_value(&setup_cfg_content, "options", "python_requires")
+        {
+            python_versions.push((python_version, "setup.cfg".to_string()));
+        }
+    }
+
+    if let Ok(setup_py_content) = fs::read_to_string(project_path.join("setup.py")) {
+        if let Some(python_version) = read_setup_py_string(&setup_py_content, "python_requires") {
+            python_versions.push((python_version, "setup.py".to_string()));
+        }
+    }
+
+    if let Some(conda_environment) = read_conda_environment(project_path) {
+        // - python=3.11 or - python>=3.9
+        if let Some(python_version) =
+            conda_environment
+                .conda_dependencies
+                .iter()
+                .find_map(|conda_spec| {
+                    let (package_name, version_spec) = split_conda_spec(conda_spec);
+                    (package_name == "python").then_some(version_spec).flatten()
+                })
+        {
+            python_versions.push((python_version, conda_environment.file_name.to_string()));
+        }
+    }
+
+    python_versions
+}
+
+fn detect_python_project_name(project_path: &Path) -> Option<String> {
+    if let Some(pyproject) = read_pyproject(project_path) {
+        if let Some(project_name) = pyproject
+            .get("project")
+            .and_then(|project_section| project_section.get("name"))
+            .or_else(|| read_poetry_section(&pyproject).and_then(|poetry| poetry.get("name")))
+            .and_then(|name_value| name_value.as_str())
+        {
+            return Some(project_name.to_string());
+        }
+    }
+
+    if let Ok(setup_cfg_content) = fs::read_to_string(project_path.join("setup.cfg")) {
+        if let Some(project_name) = read_ini_value(&setup_cfg_content, "metadata", "name") {
+            return Some(project_name);
+        }
+    }
+
+    if let Ok(setup_py_content) = fs::read_to_string(project_path.join("setup.py")) {
+        if let Some(project_name) = read_setup_py_string(&setup_py_content, "name") {
+            return Some(project_name);
+        }
+    }
+
+    read_conda_environment(project_path).and_then(|conda_environment| conda_environment.name)
+}
+
+fn detect_java_version(_project_path: &Path) -> Option<String> {
+    None
+}
+
+fn detect_scala_version(project_path: &Path) -> Option<String> {
+    let scala_build = read_scala_build(project_path);
+    // sbt: `ThisBuild / scalaVersion := "3.3.1"`, Mill: `def scalaVersion = "3.3.1"`
+    let scala_version_setting =
+        regex::Regex::new(r"\bscalaVersion\s*(?::=|=)\s*([^\s,)]+)").unwrap();
+    let scala_version = scala_version_setting
+        .captures_iter(&scala_build.build_content)
+        .find_map(|captures| scala_build.resolve(&captures[1]));
+    scala_version.or_else(|| detect_scala_cross_versions(project_path).pop())
+}
+
+fn detect_scala_cross_versions(project_path: &Path) -> Vec<String> {
+    let scala_build = read_scala_build(project_path);
+    // sbt: `crossScalaVersions := Seq(scala212, "2.13.12")`, Mill: `Cross[Module]("2.13.12", "3.3.1")`
+    let cross_version_settings = [
+        r"\bcrossScalaVersions\s*(?::=|=)\s*(?:Seq|List)\(([^)]*)\)",
+        r"\bCross\[[^\]]*\]\(([^)]*)\)",
+    ];
+
+    let mut cross_versions = Vec::new();
+    for setting_pattern in cross_version_settings {
+        let setting_regex = regex::Regex::new(setting_pattern).unwrap();
+        for captures in setting_regex.captures_iter(&scala_build.build_content) {
+            for version_expression in captures[1].split(',') {
+                if let Some(scala_version) = scala_build.resolve(version_expression.trim()) {
+                    if !cross_versions.contains(&scala_version) {
+                        cross_versions.push(scala_version);
+                    }
+                }
+            }
+        }
+    }
+    cross_versions
+}
+
+fn detect_scala_framework(project_path: &Path) -> Option<(String, Option<String>)> {
+    let scala_dependencies = read_scala_build(project_path).dependencies();
+
+    // Play's own artifacts are matched exactly: `play-json` and `play-ws-standalone`
+    // are libraries that Akka HTTP and http4s services use too
+    let play_artifacts = [
+        "play",
+        "play-server",
+        "play-guice",
+        "play-akka-http-server",
+        "play-pekko-http-server",
+        "sbt-plugin",
+    ];
+    let mut framework_artifacts: Vec<(&str, &str, &str)> = Vec::new();
+    for play_group in ["com.typesafe.play", "org.playframework"] {
+        framework_artifacts.extend(
+            play_artifacts
+                .iter()
+                .map(|play_artifact| ("Play", play_group, *play_artifact)),
+        );
+    }
+    // A trailing `-` matches every artifact of the family, e.g. `http4s-ember-server`
+    framework_artifacts.extend([
+        ("Akka HTTP", "com.typesafe.akka", "akka-http"),
+        ("Pekko HTTP", "org.apache.pekko", "pekko-http"),
+        ("http4s", "org.http4s", "http4s-"),
+        ("ZIO", "dev.zio", "zio"),
+        ("Spark", "org.apache.spark", "spark-"),
+    ]);
+
+    for (framework_name, group_id, artifact_pattern) in framework_artifacts {
+        if let Some((_, _, framework_version)) =
+            scala_dependencies.iter().find(|(group, artifact, _)| {
+                group == group_id
+                    && if artifact_pattern.ends_with('-') {
+                        artifact.starts_with(artifact_pattern)
+                    } else {
+                        artifact == artifact_pattern
+                    }
+            })
+        {
+            return Some((framework_name.to_string(), framework_version.clone()));
+        }
+    }
+
+    None
+}
+
+fn detect_zig_version(project_path: &Path) -> Option<String> {
+    let zon_content = fs::read_to_string(project_path.join("build.zig.zon")).ok()?;
+    read_zon_field(&zon_content, "minimum_zig_version")
+}
+
+fn detect_nim_version(project_path: &Path) -> Option<String> {
+    let nimble_path = find_file_with_extension(project_path, "nimble")?;
+    let nimble_content = fs::read_to_string(nimble_path).ok()?;
+    // requires "nim >= 1.6.0", "jester"
+    let nim_requirement = regex::Regex::new(r#""nim\s*([<>=~^]*\s*[0-9][^",]*)"#).ok()?;
+    nim_requirement
+        .captures(&nimble_content)
+        .map(|captures| captures[1].trim().to_string())
+}
+
+fn detect_crystal_version(project_path: &Path) -> Option<String> {
+    let shard_content = fs::read_to_string(project_path.join("shard.yml")).ok()?;
+    read_top_level_yaml_value(&shard_content, "crystal")
+}
+
+fn detect_ocaml_version(project_path: &Path) -> Option<String> {
+    // dune-project: (package (depends (ocaml (>= 4.14))))
+    for stanza in read_dune_project(project_path) {
+        if let Some(ocaml_constraint) = find_dune_ocaml_constraint(&stanza) {
+            return Some(ocaml_constraint);
+        }
+    }
+
+    // *.opam: depends: [ "ocaml" {>= "4.14.0"} ]
+    let opam_path = find_file_with_extension(project_path, "opam")?;
+    let opam_content = fs::read_to_string(opam_path).ok()?;
+    let ocaml_dependency = regex::Regex::new(r#""ocaml"\s*\{([^}]*)\}"#).ok()?;
+    ocaml_dependency
+        .captures(&opam_content)
+        .map(|captures| captures[1].replace('"', "").trim().to_string())
+}
+
+fn detect_lua_version(project_path: &Path) -> Option<String> {
+    let rockspec_path = find_file_with_extension(project_path, "rockspec")?;
+    let rockspec_content = fs::read_to_string(rockspec_path).ok()?;
+    // dependencies = { "lua >= 5.1, < 5.5" }
+    let lua_dependency = regex::Regex::new(r#"["']lua\s*([<>=~]+[^"']*)["']"#).ok()?;
+    lua_dependency
+        .captures(&rockspec_content)
+        .map(|captures| captures[1].trim().to_string())
+}
+
+fn detect_r_version(project_path: &Path) -> Option<String> {
+    if let Ok(renv_content) = fs::read_to_string(project_path.join("renv.lock")) {
+        if let Ok(parsed_json) = serde_json::from_str::<serde_json::Value>(&renv_content) {
+            if let Some(r_version) = parsed_json
+                .get("R")
+                .and_then(|r_section| r_section.get("Version"))
+                .and_then(|version_value| version_value.as_str())
+            {
+                return Some(r_version.to_string());
+            }
+        }
+    }
+
+    // Depends: R (>= 4.1.0), methods
+    let description_content = fs::read_to_string(project_path.join("DESCRIPTION")).ok()?;
+    let depends_field = read_dcf_field(&description_content, "Depends")?;
+    let r_dependency = regex::Regex::new(r"\bR\s*\(([^)]*)\)").ok()?;
+    r_dependency
+        .captures(&depends_field)
+        .map(|captures| captures[1].trim().to_string())
+}
+
+fn detect_julia_version(project_path: &Path) -> Option<String> {
+    if let Ok(manifest_content) = fs::read_to_string(project_path.join("Manifest.toml")) {
+        if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&manifest_content) {
+            if let Some(julia_version) = parsed_toml
+                .get("julia_version")
+                .and_then(|version_value| version_value.as_str())
+            {
+                return Some(julia_version.to_string());
+            }
+        }
+    }
+
+    for project_file in ["JuliaProject.toml", "Project.toml"] {
+        if let Ok(project_content) = fs::read_to_string(project_path.join(project_file)) {
+            if let Ok(parsed_toml) = toml::from_str::<toml::Value>(&project_content) {
+                if let Some(julia_compat) = parsed_toml
+                    .get("compat")
+                    .and_then(|compat_section| compat_section.get("julia"))
+                    .and_then(|compat_value| compat_value.as_str())
+                {
+                    return Some(julia_compat.to_string());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Application frameworks come before the web servers and libraries they build on
+const RUST_FRAMEWORK_CRATES: [(&str, &str); 16] = [
+    ("loco-rs", "Loco"),
+    ("tauri", "Tauri"),
+    ("leptos", "Leptos"),
+    ("dioxus", "Dioxus"),
+    ("yew", "Yew"),
+    ("embassy-executor", "Embassy"),
+    ("axum", "Axum"),
+    ("actix-web", "Actix Web"),
+    ("warp", "Warp"),
+    ("rocket", "Rocket"),
+    ("poem", "Poem"),
+    ("salvo", "Salvo"),
+    ("tonic", "Tonic"),
+    ("ratatui", "Ratatui"),
+    ("clap", "Clap (CLI)"),
+    ("bevy", "Bevy"),
+];
+
+fn detect_rust_framework(
+    project_path: &Path,
+    is_imported: &dyn Fn(&str) -> bool,
+) -> Option<(String, Option<String>)> {
+    let cargo_toml = read_toml_file(&project_path.join("Cargo.toml"))?;
+    for dependency_group in collect_rust_dependencies(&cargo_toml) {
+        for (crate_name, framework_name) in RUST_FRAMEWORK_CRATES {
+            // Source imports a renamed dependency by its key, not its crate name
+            if let Some((_, _, crate_version)) =
+                dependency_group
+                    .iter()
+                    .find(|(dependency_key, dependency_name, _)| {
+                        dependency_name == crate_name && is_imported(dependency_key)
+                    })
+            {
+                return Some((framework_name.to_string(), crate_version.clone()));
+            }
+        }
+    }
+    None
+}
+
+/// `(key, crate, version)` triples from a manifest, grouped as runtime, build and
+/// dev dependencies
+///
+/// Target-specific tables join their group, the crate of a renamed dependency
+/// is its `package` name while code imports it by key, and `workspace = true`
+/// entries are resolved against `[workspace.dependencies]`.
+fn collect_rust_dependencies(
+    cargo_toml: &toml::Value,
+) -> [Vec<(String, String, Option<String>)>; 3] {
+    let workspace_dependencies = cargo_toml
+        .get("workspace")
+        .and_then(|workspace_section| workspace_section.get("dependencies"))
+        .and_then(|deps| deps.as_table());
+    let target_sections: Vec<&toml::Value> = cargo_toml
+        .get("target")
+        .and_then(|target_section| target_section.as_table())
+        .into_iter()
+        .flat_map(|target_table| target_table.values())
+        .collect();
+
+    ["dependencies", "build-dependencies", "dev-dependencies"].map(|table_name| {
+        let mut dependency_tables: Vec<&toml::Value> = Vec::new();
+        dependency_tables.extend(cargo_toml.get(table_name));
+        dependency_tables.extend(
+            target_sections
+                .iter()
+                .filter_map(|target_section| target_section.get(table_name)),
+        );
+        // A virtual manifest only declares shared workspace dependencies
+        if table_name == "dependencies" && cargo_toml.get("package").is_none() {
+            dependency_tables.extend(
+                cargo_toml
+                    .get("workspace")
+                    .and_then(|workspace_section| workspace_section.get("dependencies")),
+            );
+        }
+
+        let mut rust_dependencies = Vec::new();
+        for dependencies_table in dependency_tables
+            .iter()
+            .filter_map(|table| table.as_table())
+        {
+            for (dependency_key, dependency_value) in dependencies_table {
+                let inherits_workspace = dependency_value
+                    .get("workspace")
+                    .and_then(|workspace_value| workspace_value.as_bool())
+                    == Some(true);
+                let declaring_table = match workspace_dependencies {
+                    Some(workspace_dependencies) if inherits_workspace => workspace_dependencies,
+                    _ => dependencies_table,
+                };
+                let crate_name = declaring_table
+                    .get(dependency_key)
+                    .and_then(|declared_value| declared_value.get("package"))
+                    .and_then(|package_value| package_value.as_str())
+                    .unwrap_or(dependency_key);
+                let crate_version = get_dependency_version(declaring_table, dependency_key);
+                rust_dependencies.push((
+                    dependency_key.clone(),
+                    crate_name.to_string(),
+                    crate_version,
+                ));
+            }
+        }
+        rust_dependencies
+    })
+}
+
+/// App platforms and meta-frameworks come before the UI libraries and servers they wrap
+const JAVASCRIPT_FRAMEWORK_PACKAGES: [(&str, &str); 32] = [
+    ("electron", "Electron"),
+    ("expo", "Expo"),
+    ("react-native", "React Native"),
+    ("@ionic/core", "Ionic"),
+    ("@ionic/react", "Ionic"),
+    ("@ionic/angular", "Ionic"),
+    ("@ionic/vue", "Ionic"),
+    ("@capacitor/core", "Capacitor"),
+    ("next", "Next.js"),
+    ("@remix-run/react", "Remix"),
+    ("@remix-run/node", "Remix"),
+    ("gatsby", "Gatsby"),
+    ("astro", "Astro"),
+    ("@sveltejs/kit", "SvelteKit"),
+    ("nuxt", "Nuxt"),
+    ("@nestjs/core", "NestJS"),
+    ("@adonisjs/core", "AdonisJS"),
+    ("react", "React"),
+    ("preact", "Preact"),
+    ("vue", "Vue"),
+    ("@angular/core", "Angular"),
+    ("svelte", "Svelte"),
+    ("solid-js", "Solid"),
+    ("@builder.io/qwik", "Qwik"),
+    ("lit", "Lit"),
+    ("ember-source", "Ember"),
+    ("express", "Express"),
+    ("fastify", "Fastify"),
+    ("koa", "Koa"),
+    ("@hapi/hapi", "Hapi"),
+    ("hono", "Hono"),
+    ("elysia", "Elysia"),
+];
+
+/// Meta-frameworks such as Next.js are rarely imported by name (pages import
+/// React), so `configured_framework` from a config file stands in for an import
+fn detect_javascript_framework(
+    project_path: &Path,
+    configured_framework: Option<&str>,
+    is_imported: &dyn Fn(&str) -> bool,
+) -> Option<(String, Option<String>)> {
+    let package_json_path = project_path.join("package.json");
+    if let Ok(package_content) = fs::read_to_string(&package_json_path) {
+        if let Ok(parsed_json) = serde_json::from_str::<serde_json::Value>(&package_content) {
+            let production_dependencies = parsed_json
+                .get("dependencies")
+                .and_then(|deps| deps.as_object());
+            let development_dependencies = parsed_json
+                .get("devDependencies")
+                .and_then(|deps| deps.as_object());
+
+            // Build-time frameworks such as Electron and SvelteKit usually sit in devDependencies
+            for (package_name, framework_name) in JAVASCRIPT_FRAMEWORK_PACKAGES {
+                for dependency_object in [production_dependencies, development_dependencies]
+                    .iter()
+                    .filter_map(|deps| *deps)
+                {
+                    if dependency_object.contains_key(package_name)
+                        && (configured_framework == Some(framework_name)
+                            || is_imported(package_name))
+                    {
+                        let framework_version = dependency_object
+                            .get(package_name)
+                            .and_then(|version| version.as_str())
+                            .map(|s| s.to_string());
+                        return Some((framework_name.to_string(), framework_version));
+                    }
+                }
+            }
+        }
+    }
+
+    let deno_framework_packages = [
+        ("fresh", "Fresh"),
+        ("@fresh/core", "Fresh"),
+        ("hono", "Hono"),
+        ("@hono/hono", "Hono"),
+        ("oak", "Oak"),
+        ("@oak/oak", "Oak"),
+    ];
+    let deno_dependencies = collect_deno_dependencies(project_path);
+    for (package_name, framework_name) in deno_framework_packages {
+        if let Some((_, framework_version)) = deno_dependencies
+            .iter()
+            .find(|(dependency_name, _)| dependency_name == package_name)
+        {
+            return Some((framework_name.to_string(), framework_version.clone()));
+        }
+    }
+
+    None
+}
+
+/// Full-stack and application frameworks first, then routers, RPC and CLI libraries
+const GO_FRAMEWORK_MODULES: [(&str, &str); 20] = [
+    ("github.com/gobuffalo/buffalo", "Buffalo"),
+    ("github.com/beego/beego", "Beego"),
+    ("github.com/astaxie/beego", "Beego"),
+    ("github.com/revel/revel", "Revel"),
+    ("github.com/go-kratos/kratos", "Kratos"),
+    ("github.com/go-kit/kit", "go-kit"),
+    ("github.com/wailsapp/wails", "Wails"),
+    ("fyne.io/fyne", "Fyne"),
+    ("sigs.k8s.io/controller-runtime", "controller-runtime"),
+    ("github.com/gin-gonic/gin", "Gin"),
+    ("github.com/gorilla/mux", "Gorilla Mux"),
+    ("github.com/labstack/echo", "Echo"),
+    ("github.com/gofiber/fiber", "Fiber"),
+    ("github.com/go-chi/chi", "chi"),
+    ("connectrpc.com/connect", "Connect"),
+    ("github.com/bufbuild/connect-go", "Connect"),
+    ("google.golang.org/grpc", "gRPC-Go"),
+    ("github.com/charmbracelet/bubbletea", "Bubble Tea"),
+    ("github.com/spf13/cobra", "Cobra"),
+    ("github.com/urfave/cli", "urfave/cli"),
+];
+
+fn detect_go_framework(
+    project_path: &Path,
+    is_imported: &dyn Fn(&str) -> bool,
+) -> Option<(String, Option<String>)> {
+    // A `go.work` root has no module of its own; its members declare the frameworks
+    let mut module_directories = vec![project_path.to_path_buf()];
+    if let Some(go_workspace) = go_mod::read_go_work(project_path) {
+        module_directories.extend(
+            go_workspace
+                .uses
+                .iter()
+                .map(|member_directory| project_path.join(member_directory)),
+        );
+    }
+
+    for module_directory in module_directories {
+        let Some(go_module) = go_mod::read_go_mod(&module_directory.join("go.mod")) else {
+            continue;
+        };
+        for (framework_module, framework_name) in GO_FRAMEWORK_MODULES {
+            // Indirect requirements belong to dependencies, not to this module
+            let Some(go_require) = go_module
+                .find_require(framework_module)
+                .filter(|go_require| !go_require.indirect && is_imported(framework_module))
+            else {
+                continue;
+            };
+            let framework_version = go_module.effective_version(go_require);
+            // Kubebuilder and Operator SDK scaffold a PROJECT file around controller-runtime
+            let framework_name = match framework_name {
+                "controller-runtime" if is_kubebuilder_project(&module_directory) => "Kubebuilder",
+                _ => framework_name,
+            };
+            return Some((
+                framework_name.to_string(),
+                Some(framework_version.trim_start_matches('v').to_string()),
+            ));
+        }
+    }
+    None
+}
+
+fn is_kubebuilder_project(module_directory: &Path) -> bool {
+    fs::read_to_string(module_directory.join("PROJECT"))
+        .is_ok_and(|project_content| project_content.contains("kubebuilder.io"))
+}
+
+/// `(package, version)` pairs from the Cargo, npm, Python and Go manifests,
+/// for checks that don't depend on the project's language
+fn collect_all_dependencies(project_path: &Path) -> Vec<(String, Option<String>)> {
+    let mut all_dependencies = Vec::new();
+
+    if let Some(cargo_toml) = read_toml_file(&project_path.join("Cargo.toml")) {
+        all_dependencies.extend(
+            collect_rust_dependencies(&cargo_toml)
+                .concat()
+                .into_iter()
+                .map(|(_, crate_name, crate_version)| (crate_name, crate_version)),
+        );
+    }
+
+    if let Some(package_json) = fs::read_to_string(project_path.join("package.json"))
+        .ok()
+        .and_then(|package_content| {
+            serde_json::from_str::<serde_json::Value>(&package_content).ok()
+        })
+    {
+        for dependency_group in ["dependencies", "devDependencies"] {
+            all_dependencies.extend(
+                package_json
+                    .get(dependency_group)
+                    .and_then(|deps| deps.as_object())
+                    .into_iter()
+                    .flatten()
+                    .map(|(package_name, package_version)| {
+                        (
+                            package_name.clone(),
+                            package_version.as_str().map(|version| version.to_string()),
+                        )
+                    }),
+            );
+        }
+    }
+
+    all_dependencies.extend(
+        collect_python_dependencies(project_path)
+            .into_iter()
+            .chain(collect_python_dependency_groups(project_path))
+            .map(|python_requirement| {
+                let requirement_version = python_requirement.version();
+                (python_requirement.name, requirement_version)
+            }),
+    );
+
+    if let Some(go_module) = go_mod::read_go_mod(&project_path.join("go.mod")) {
+        all_dependencies.extend(go_module.requires.iter().map(|go_require| {
+            (
+                go_require.module_path.clone(),
+                Some(go_module.effective_version(go_require)),
+            )
+        }));
+    }
+
+    all_dependencies
+}
+
+/// Python packages that are run as tools rather than imported
+const PYTHON_TOOL_PACKAGES: [&str; 20] = [
+    "black",
+    "build",
+    "coverage",
+    "flake8",
+    "gunicorn",
+    "hatchling",
+    "ipykernel",
+    "isort",
+    "jupyter",
+    "mypy",
+    "nox",
+    "pip",
+    "pre-commit",
+    "pylint",
+    "pytest",
+    "ruff",
+    "setuptools",
+    "tox",
+    "twine",
+    "uvicorn",
+];
+
+/// Every declared and CI-tested version per language, checked for disagreements
+fn detect_version_consistency(
+    project_path: &Path,
+    pinned_tool_versions: &[ToolVersion],
+    dockerfiles: &[Dockerfile],
+    ci_pipelines: &[CiPipeline],
+) -> Vec<VersionConsistency> {
+    let mut declared_versions: BTreeMap<String, Vec<VersionSource>> = BTreeMap::new();
+    // `.tool-versions` and mise pins are already checked in `version_conflicts`
+    let mut add_declared_version = |language: &str, version: String, source: String| {
+        let is_tool_version_pin = pinned_tool_versions
+            .iter()
+            .any(|tool_version| tool_version.source == source);
+        let language_versions = declared_versions.entry(language.to_string()).or_default();
+        if !is_tool_version_pin
+            && !language_versions
+                .iter()
+                .any(|declared| declared.source == source)
+        {
+            language_versions.push(VersionSource { version, source });
+        }
+    };
+
+    let language_sources = [
+        (
+            "JavaScript",
+            collect_node_version_sources(project_path, pinned_tool_versions),
+        ),
+        ("Python", collect_python_version_sources(project_path)),
+        ("Rust", collect_rust_version_sources(project_path)),
+        ("Go", collect_go_version_sources(project_path)),
+    ];
+    for (language, version_sources) in language_sources {
+        for (version, source) in version_sources {
+            add_declared_version(language, version, source);
+        }
+    }
+    for dockerfile in dockerfiles {
+        for image_runtime in dockerfile
+            .stages
+            .iter()
+            .filter_map(|build_stage| build_stage.runtime.as_ref())
+        {
+            let image_version = VersionSource {
+                version: image_runtime.version.clone(),
+                source: dockerfile.path.clone(),
+            };
+            let language_versions = declared_versions
+                .entry(image_runtime.language.clone())
+                .or_default();
+            // Stages built on an earlier stage repeat its runtime
+            if !language_versions.contains(&image_version) {
+                language_versions.push(image_version);
+            }
+        }
+    }
+
+    let mut tested_versions: BTreeMap<String, Vec<VersionSource>> = BTreeMap::new();
+    for ci_pipeline in ci_pipelines {
+        for (language, pipeline_versions) in &ci_pipeline.tested_versions {
+            tested_versions.entry(language.clone()).or_default().extend(
+                pipeline_versions.iter().map(|version| VersionSource {
+                    version: version.clone(),
+                    source: ci_pipeline.config_file.clone(),
+                }),
+            );
+        }
+    }
+
+    let mut languages: Vec<String> = declared_versions
+        .keys()
+        .chain(tested_versions.keys())
+        .cloned()
+        .collect();
+    languages.sort();
+    languages.dedup();
+    languages
+        .into_iter()
+        .filter_map(|language| {
+            version_consistency::check_version_consistency(
+                &language,
+                declared_versions.remove(&language).unwrap_or_default(),
+                tested_versions.remove(&language).unwrap_or_default(),
+            )
         })
+        .collect()
+}
+
+/// Declared dependencies no source file imports, and imports no manifest declares
+fn detect_dependency_report(
+    project_path: &Path,
+    detected_language: &str,
+    source_imports: &SourceImports,
+) -> Option<DependencyReport> {
+    let (declared_packages, expected_imports) =
+        collect_declared_packages(project_path, detected_language)?;
+    Some(DependencyReport {
+        source_file_count: source_imports.source_file_count,
+        unused_dependencies: expected_imports
+            .into_iter()
+            .filter(|package_name| !source_imports.imports_package(package_name))
+            .collect(),
+        undeclared_imports: source_imports.undeclared_imports(&declared_packages),
+    })
+}
+
+/// Every package the manifest declares, plus the project's own name, and the
+/// subset of those the source is expected to import
+///
+/// Tooling is left out of the second list: `@types/*` and devDependencies in
+/// JavaScript, test runners and formatters in Python.
+fn collect_declared_packages(
+    project_path: &Path,
+    detected_language: &str,
+) -> Option<(Vec<String>, Vec<String>)> {
+    let mut declared_packages: Vec<String> = Vec::new();
+    let mut expected_imports: Vec<String> = Vec::new();
+
+    match detected_language {
+        "Rust" => {
+            let cargo_toml = read_toml_file(&project_path.join("Cargo.toml"))?;
+            // `web = { package = "actix-web" }` is imported as `web`
+            for (dependency_key, _, _) in collect_rust_dependencies(&cargo_toml).concat() {
+                if !expected_imports.contains(&dependency_key) {
+                    expected_imports.push(dependency_key);
+                }
+            }
+            declared_packages.extend(expected_imports.iter().cloned());
+            // Tests, examples and binaries import the crate's own library
+            for own_name_path in [["package", "name"], ["lib", "name"]] {
+                declared_packages.extend(
+                    cargo_toml
+                        .get(own_name_path[0])
+                        .and_then(|section| section.get(own_name_path[1]))
+                        .and_then(|name_value| name_value.as_str())
+                        .map(|own_name| own_name.to_string()),
+                );
+            }
+        }
+        "JavaScript" => {
+            let package_content = fs::read_to_string(project_path.join("package.json")).ok()?;
+            let package_json: serde_json::Value = serde_json::from_str(&package_content).ok()?;
+            for dependency_group in [
+                "dependencies",
+                "devDependencies",
+                "peerDependencies",
+                "optionalDependencies",
+            ] {
+                let group_packages = package_json
+                    .get(dependency_group)
+                    .and_then(|deps| deps.as_object())
+                    .into_iter()
+                    .flat_map(|deps| deps.keys().cloned());
+                for package_name in group_packages {
+                    if dependency_group == "dependencies" && !package_name.starts_with("@types/") {
+                        expected_imports.push(package_name.clone());
+                    }
+                    declared_packages.push(package_name);
+                }
+            }
+            declared_packages.extend(
+                package_json
+                    .get("name")
+                    .and_then(|name_value| name_value.as_str())
+                    .map(|own_name| own_name.to_string()),
+            );
+        }
+        "Python" => {
+            let python_dependencies = collect_python_dependencies(project_path);
+            if python_dependencies.is_empty() {
+                return None;
+            }
+            for python_requirement in python_dependencies {
+                if !PYTHON_TOOL_PACKAGES.contains(&python_requirement.name.as_str())
+                    && !python_requirement.name.starts_with("pytest-")
+                {
+                    expected_imports.push(python_requirement.name.clone());
+                }
+                declared_packages.push(python_requirement.name);
+            }
+            declared_packages.extend(detect_python_project_name(project_path));
+        }
+        "Go" => {
+            let go_module = go_mod::read_go_mod(&project_path.join("go.mod"))?;
+            for go_require in go_module.requires {
+                if !go_require.indirect {
+                    expected_imports.push(go_require.module_path.clone());
+                }
+                declared_packages.push(go_require.module_path);
+            }
+            // Packages inside the module are imported by their full path
+            declared_packages.extend(go_module.module_path);
+        }
+        _ => return None,
+    }
+
+    Some((declared_packages, expected_imports))
+}
+
+/// Member project directories of a multi-project workspace
+fn detect_workspace_members(project_path: &Path, detected_language: &str) -> Vec<PathBuf> {
+    match detected_language {
+        "Go" => go_mod::read_go_work(project_path)
+            .map(|go_workspace| {
+                go_workspace
+                    .uses
+                    .iter()
+                    .filter(|member_directory| !matches!(member_directory.as_str(), "." | "./"))
+                    .map(|member_directory| project_path.join(member_directory))
+                    // A nested `go.work` could point back here
+                    .filter(|member_path| {
+                        member_path.is_dir() && !member_path.join("go.work").exists()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
 }
 
-fn extract_version_from_requirement(requirement: &str) -> Option<String> {
-    // Extract version from requirement strings like "django==4.2.0" or "flask>=2.0.0"
-    let re = regex::Regex::new(r"[><=!]+(.+)").ok()?;
-    if let Some(captures) = re.captures(requirement) {
-        return captures.get(1).map(|m| m.as_str().to_string());
+/// Most specific first: a Streamlit app or LangChain service also depends on the ML stack
+const PYTHON_FRAMEWORK_PACKAGES: [(&str, &str); 19] = [
+    ("django", "Django"),
+    ("flask", "Flask"),
+    ("fastapi", "FastAPI"),
+    ("streamlit", "Streamlit"),
+    ("gradio", "Gradio"),
+    ("dash", "Dash"),
+    ("apache-airflow", "Airflow"),
+    ("dagster", "Dagster"),
+    ("prefect", "Prefect"),
+    ("langchain", "LangChain"),
+    ("langchain-core", "LangChain"),
+    ("transformers", "Transformers"),
+    ("torch", "PyTorch"),
+    ("pytorch", "PyTorch"),
+    ("tensorflow", "TensorFlow"),
+    ("tensorflow-cpu", "TensorFlow"),
+    ("tensorflow-gpu", "TensorFlow"),
+    ("jax", "JAX"),
+    ("scikit-learn", "scikit-learn"),
+];
+
+fn detect_python_framework(
+    project_path: &Path,
+    project_notebooks: &[Notebook],
+    is_imported: &dyn Fn(&str) -> bool,
+) -> Option<(String, Option<String>)> {
+    // Names are PEP 503 normalized, so `django-environ` never reads as Django
+    let python_dependencies = collect_python_dependencies(project_path);
+    for (package_name, framework_name) in PYTHON_FRAMEWORK_PACKAGES {
+        if let Some(python_requirement) = python_dependencies
+            .iter()
+            .find(|python_requirement| python_requirement.name == package_name)
+            .filter(|_| is_imported(package_name))
+        {
+            return Some((framework_name.to_string(), python_requirement.version()));
+        }
+    }
+
+    // Notebooks often import libraries that no manifest declares
+    let notebook_packages: Vec<String> = project_notebooks
+        .iter()
+        .flat_map(|notebook| notebook.imported_modules.iter())
+        .map(|imported_module| {
+            requirements::normalize_name(notebooks::python_import_package(imported_module))
+        })
+        .collect();
+    PYTHON_FRAMEWORK_PACKAGES
+        .iter()
+        .find(|(package_name, _)| notebook_packages.iter().any(|p| p == package_name))
+        .map(|(_, framework_name)| (framework_name.to_string(), None))
+}
+
+/// Requirements from PEP 735 `[dependency-groups]` in pyproject.toml
+///
+/// Groups hold development tooling such as test runners and linters, so they
+/// feed test detection but not framework detection or the dependency report.
+fn collect_python_dependency_groups(project_path: &Path) -> Vec<Requirement> {
+    let Some(pyproject) = read_pyproject(project_path) else {
+        return Vec::new();
+    };
+    // `{include-group = ...}` tables are skipped; the included group is read on its own
+    pyproject
+        .get("dependency-groups")
+        .and_then(|groups| groups.as_table())
+        .into_iter()
+        .flat_map(|groups_table| groups_table.values())
+        .filter_map(|dependency_array| dependency_array.as_array())
+        .flatten()
+        .filter_map(|dependency_value| dependency_value.as_str())
+        .filter_map(requirements::parse_requirement)
+        .collect()
+}
+
+/// Requirements from every Python packaging file, in precedence order
+fn collect_python_dependencies(project_path: &Path) -> Vec<Requirement> {
+    let mut python_dependencies =
+        requirements::read_requirements_files(&requirements::find_requirements_files(project_path));
+
+    let mut requirement_strings = Vec::new();
+    let pyproject = read_pyproject(project_path);
+    if let Some(project_section) = pyproject
+        .as_ref()
+        .and_then(|pyproject| pyproject.get("project"))
+    {
+        let optional_dependency_arrays = project_section
+            .get("optional-dependencies")
+            .and_then(|optional| optional.as_table())
+            .into_iter()
+            .flat_map(|optional_table| optional_table.values());
+        for dependency_array in project_section
+            .get("dependencies")
+            .into_iter()
+            .chain(optional_dependency_arrays)
+            .filter_map(|deps| deps.as_array())
+        {
+            requirement_strings.extend(
+                dependency_array
+                    .iter()
+                    .filter_map(|dependency_value| dependency_value.as_str())
+                    .map(|dependency_string| dependency_string.to_string()),
+            );
+        }
+    }
+
+    if let Ok(setup_cfg_content) = fs::read_to_string(project_path.join("setup.cfg")) {
+        if let Some(install_requires) =
+            read_ini_value(&setup_cfg_content, "options", "install_requires")
+        {
+            requirement_strings.extend(install_requires.lines().map(|line| line.to_string()));
+        }
+    }
+
+    if let Ok(setup_py_content) = fs::read_to_string(project_path.join("setup.py")) {
+        requirement_strings.extend(read_setup_py_list(&setup_py_content, "install_requires"));
+    }
+
+    let conda_environment = read_conda_environment(project_path);
+    if let Some(ref conda_environment) = conda_environment {
+        requirement_strings.extend(conda_environment.pip_dependencies.iter().cloned());
+    }
+
+    python_dependencies.extend(
+        requirement_strings
+            .iter()
+            .filter_map(|requirement_string| requirements::parse_requirement(requirement_string)),
+    );
+
+    // Poetry and Pipenv map package names to constraint strings or tables
+    let poetry_section = pyproject.as_ref().and_then(read_poetry_section);
+    let mut dependency_tables: Vec<&toml::Value> = Vec::new();
+    if let Some(poetry_section) = poetry_section {
+        dependency_tables.extend(poetry_section.get("dependencies"));
+        dependency_tables.extend(poetry_section.get("dev-dependencies"));
+        if let Some(group_table) = poetry_section.get("group").and_then(|g| g.as_table()) {
+            dependency_tables.extend(
+                group_table
+                    .values()
+                    .filter_map(|group_section| group_section.get("dependencies")),
+            );
+        }
+    }
+    let pipfile = read_toml_file(&project_path.join("Pipfile"));
+    if let Some(ref pipfile) = pipfile {
+        dependency_tables.extend(pipfile.get("packages"));
+        dependency_tables.extend(pipfile.get("dev-packages"));
+    }
+    for dependencies_table in dependency_tables
+        .iter()
+        .filter_map(|table| table.as_table())
+    {
+        for package_name in dependencies_table.keys().filter(|name| *name != "python") {
+            let package_version = get_dependency_version(dependencies_table, package_name);
+            python_dependencies.push(Requirement::new(package_name, package_version));
+        }
+    }
+
+    if let Some(ref conda_environment) = conda_environment {
+        for conda_spec in &conda_environment.conda_dependencies {
+            let (package_name, version_spec) = split_conda_spec(conda_spec);
+            if package_name != "python" {
+                python_dependencies.push(Requirement::new(&package_name, version_spec));
+            }
+        }
+    }
+
+    python_dependencies
+}
+
+fn detect_java_framework(_project_path: &Path) -> Option<(String, Option<String>)> {
+    None
+}
+
+fn detect_clojure_version(project_path: &Path) -> Option<String> {
+    // A ClojureScript release number is reported as the runtime version instead
+    let clojure_dependencies = collect_clojure_dependencies(project_path, true);
+    find_clojure_dependency_version(&clojure_dependencies, "org.clojure/clojure")
+}
+
+fn detect_clojure_runtime(project_path: &Path) -> Option<(String, Option<String>)> {
+    let clojure_dependencies = collect_clojure_dependencies(project_path, true);
+    let has_dependency = |dependency_name: &str| {
+        clojure_dependencies
+            .iter()
+            .any(|(name, _)| name == dependency_name)
+    };
+
+    if project_path.join("shadow-cljs.edn").exists()
+        || has_dependency("org.clojure/clojurescript")
+        || has_dependency("thheller/shadow-cljs")
+    {
+        let clojurescript_version =
+            find_clojure_dependency_version(&clojure_dependencies, "org.clojure/clojurescript");
+        return Some(("ClojureScript".to_string(), clojurescript_version));
+    }
+
+    if project_path.join("project.clj").exists() || project_path.join("deps.edn").exists() {
+        return Some(("JVM".to_string(), None));
+    }
+
+    if let Some(bb_config) = read_edn_file(&project_path.join("bb.edn")) {
+        let minimum_bb_version = bb_config
+            .get("min-bb-version")
+            .and_then(|version_value| version_value.as_str())
+            .map(|version_string| version_string.to_string());
+        return Some(("Babashka".to_string(), minimum_bb_version));
+    }
+
+    None
+}
+
+fn detect_clojure_framework(project_path: &Path) -> Option<(String, Option<String>)> {
+    // A `:dev` profile's `ring/ring-mock` doesn't make a library a Ring app
+    let clojure_dependencies = collect_clojure_dependencies(project_path, false);
+
+    // Kit and Luminus are built on Ring, so check the batteries-included stacks first
+    let framework_prefixes = [
+        ("Kit", "io.github.kit-clj/"),
+        ("Luminus", "luminus"),
+        ("Pedestal", "io.pedestal/"),
+        ("re-frame", "re-frame/re-frame"),
+        ("Ring", "ring/"),
+    ];
+
+    for (framework_name, dependency_prefix) in framework_prefixes {
+        if let Some((_, framework_version)) = clojure_dependencies
+            .iter()
+            .find(|(dependency_name, _)| dependency_name.starts_with(dependency_prefix))
+        {
+            return Some((framework_name.to_string(), framework_version.clone()));
+        }
+    }
+
+    None
+}
+
+fn read_edn_file(file_path: &Path) -> Option<EdnValue> {
+    let file_content = fs::read_to_string(file_path).ok()?;
+    edn::parse_first(&file_content)
+}
+
+/// The items of the `(defproject name "version" ...)` form in `project.clj`
+fn read_leiningen_project(project_path: &Path) -> Option<Vec<EdnValue>> {
+    let file_content = fs::read_to_string(project_path.join("project.clj")).ok()?;
+    edn::parse(&file_content)
+        .ok()?
+        .into_iter()
+        .find_map(|top_level_form| match top_level_form {
+            EdnValue::List(items)
+                if items.first().and_then(|head| head.as_symbol()) == Some("defproject") =>
+            {
+                Some(items)
+            }
+            _ => None,
+        })
+}
+
+/// Every dependency coordinate declared across the Clojure project files
+///
+/// `include_development` adds Leiningen `:profiles` dependencies and the
+/// `:extra-deps` of `deps.edn` aliases to the main dependencies.
+fn collect_clojure_dependencies(
+    project_path: &Path,
+    include_development: bool,
+) -> Vec<(String, Option<String>)> {
+    let mut clojure_dependencies = Vec::new();
+
+    for config_file in ["deps.edn", "bb.edn"] {
+        if let Some(deps_config) = read_edn_file(&project_path.join(config_file)) {
+            collect_deps_edn_coordinates(deps_config.get("deps"), &mut clojure_dependencies);
+            if let Some(alias_entries) = deps_config
+                .get("aliases")
+                .and_then(|a| a.as_map())
+                .filter(|_| include_development)
+            {
+                for (_, alias_config) in alias_entries {
+                    collect_deps_edn_coordinates(
+                        alias_config.get("extra-deps"),
+                        &mut clojure_dependencies,
+                    );
+                }
+            }
+        }
+    }
+
+    if let Some(defproject_items) = read_leiningen_project(project_path) {
+        // Skip `defproject`, the name and the version, then read keyword/value pairs
+        for option_pair in defproject_items.get(3..).unwrap_or_default().chunks(2) {
+            if let [EdnValue::Keyword(option_name), option_value] = option_pair {
+                match option_name.as_str() {
+                    "dependencies" => {
+                        collect_vector_coordinates(Some(option_value), &mut clojure_dependencies)
+                    }
+                    "profiles" if include_development => {
+                        for (_, profile_config) in option_value.as_map().unwrap_or_default() {
+                            collect_vector_coordinates(
+                                profile_config.get("dependencies"),
+                                &mut clojure_dependencies,
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if let Some(shadow_config) = read_edn_file(&project_path.join("shadow-cljs.edn")) {
+        collect_vector_coordinates(shadow_config.get("dependencies"), &mut clojure_dependencies);
+    }
+
+    clojure_dependencies
+}
+
+/// Coordinates from a `deps.edn` style map of `lib {:mvn/version "x"}`
+fn collect_deps_edn_coordinates(
+    deps_map: Option<&EdnValue>,
+    clojure_dependencies: &mut Vec<(String, Option<String>)>,
+) {
+    for (library_symbol, coordinate) in deps_map.and_then(|d| d.as_map()).unwrap_or_default() {
+        if let Some(library_name) = library_symbol.as_symbol() {
+            let library_version = ["mvn/version", "git/tag"]
+                .iter()
+                .find_map(|version_key| coordinate.get(version_key))
+                .and_then(|version_value| version_value.as_str())
+                .map(|version_string| version_string.to_string());
+            clojure_dependencies.push((normalize_clojure_library(library_name), library_version));
+        }
+    }
+}
+
+/// Coordinates from a Leiningen/shadow-cljs style vector of `[lib "version"]`
+fn collect_vector_coordinates(
+    dependency_vector: Option<&EdnValue>,
+    clojure_dependencies: &mut Vec<(String, Option<String>)>,
+) {
+    for dependency_entry in dependency_vector
+        .and_then(|v| v.as_seq())
+        .unwrap_or_default()
+    {
+        let coordinate_items = dependency_entry.as_seq().unwrap_or_default();
+        if let Some(library_name) = coordinate_items.first().and_then(|n| n.as_symbol()) {
+            let library_version = coordinate_items
+                .get(1)
+                .and_then(|version_value| version_value.as_str())
+                .map(|version_string| version_string.to_string());
+            clojure_dependencies.push((normalize_clojure_library(library_name), library_version));
+        }
+    }
+}
+
+/// Unqualified library symbols like `ring` mean `ring/ring`
+fn normalize_clojure_library(library_name: &str) -> String {
+    if library_name.contains('/') {
+        library_name.to_string()
+    } else {
+        format!("{}/{}", library_name, library_name)
+    }
+}
+
+fn find_clojure_dependency_version(
+    clojure_dependencies: &[(String, Option<String>)],
+    dependency_name: &str,
+) -> Option<String> {
+    clojure_dependencies
+        .iter()
+        .find(|(name, _)| name == dependency_name)
+        .and_then(|(_, version)| version.clone())
+}
+
+fn read_pyproject(project_path: &Path) -> Option<toml::Value> {
+    read_toml_file(&project_path.join("pyproject.toml"))
+}
+
+/// The `[tool.poetry]` table of a parsed `pyproject.toml`
+fn read_poetry_section(pyproject: &toml::Value) -> Option<&toml::Value> {
+    pyproject.get("tool")?.get("poetry")
+}
+
+/// A literal string keyword argument in `setup.py`, e.g. `python_requires=">=3.8"`
+fn read_setup_py_string(setup_py_content: &str, keyword: &str) -> Option<String> {
+    let keyword_argument = regex::Regex::new(&format!(
+        r#"\b{}\s*=\s*["']([^"']+)["']"#,
+        regex::escape(keyword)
+    ))
+    .ok()?;
+    keyword_argument
+        .captures(setup_py_content)
+        .map(|captures| captures[1].to_string())
+}
+
+/// The string items of a literal list keyword argument in `setup.py`
+fn read_setup_py_list(setup_py_content: &str, keyword: &str) -> Vec<String> {
+    let list_argument = regex::Regex::new(&format!(
+        r"(?s)\b{}\s*=\s*\[(.*?)\]",
+        regex::escape(keyword)
+    ))
+    .unwrap();
+    let quoted_item = regex::Regex::new(r#"["']([^"']+)["']"#).unwrap();
+    list_argument
+        .captures(setup_py_content)
+        .map(|captures| {
+            quoted_item
+                .captures_iter(&captures[1])
+                .map(|item_captures| item_captures[1].to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The parts of a Conda `environment.yml` that matter for detection
+struct CondaEnvironment {
+    file_name: &'static str,
+    name: Option<String>,
+    conda_dependencies: Vec<String>,
+    pip_dependencies: Vec<String>,
+}
+
+fn read_conda_environment(project_path: &Path) -> Option<CondaEnvironment> {
+    let (file_name, environment_content) = ["environment.yml", "environment.yaml"]
+        .into_iter()
+        .find_map(|file_name| {
+            fs::read_to_string(project_path.join(file_name))
+                .ok()
+                .map(|content| (file_name, content))
+        })?;
+
+    let mut conda_environment = CondaEnvironment {
+        file_name,
+        name: read_top_level_yaml_value(&environment_content, "name"),
+        conda_dependencies: Vec::new(),
+        pip_dependencies: Vec::new(),
+    };
+
+    // dependencies:
+    //   - python=3.11
+    //   - pip:
+    //     - django>=4.2
+    let mut in_dependencies = false;
+    let mut pip_list_indent: Option<usize> = None;
+    for content_line in environment_content.lines() {
+        let trimmed_line = content_line.trim();
+        if trimmed_line.is_empty() || trimmed_line.starts_with('#') {
+            continue;
+        }
+        let line_indent = content_line.len() - content_line.trim_start().len();
+
+        if line_indent == 0 {
+            in_dependencies = trimmed_line == "dependencies:";
+            pip_list_indent = None;
+            continue;
+        }
+        if !in_dependencies {
+            continue;
+        }
+
+        let Some(list_item) = trimmed_line.strip_prefix('-').map(|item| item.trim()) else {
+            continue;
+        };
+        let list_item = list_item.trim_matches(|c| c == '"' || c == '\'');
+
+        if let Some(pip_indent) = pip_list_indent {
+            if line_indent > pip_indent {
+                conda_environment
+                    .pip_dependencies
+                    .push(list_item.to_string());
+                continue;
+            }
+            pip_list_indent = None;
+        }
+
+        if list_item == "pip:" {
+            pip_list_indent = Some(line_indent);
+        } else {
+            conda_environment
+                .conda_dependencies
+                .push(list_item.to_string());
+        }
+    }
+
+    Some(conda_environment)
+}
+
+/// Split a Conda match spec like `conda-forge::numpy=1.26` into name and version
+fn split_conda_spec(conda_spec: &str) -> (String, Option<String>) {
+    let conda_spec = conda_spec
+        .rsplit("::")
+        .next()
+        .unwrap_or(conda_spec)
+        .trim()
+        .to_lowercase();
+    let name_length = conda_spec
+        .find(['=', '<', '>', '!', '~', ' '])
+        .unwrap_or(conda_spec.len());
+    let version_constraint = conda_spec[name_length..].trim();
+    // `name=1.2=build` pins a version (and build); other operators read like pip's
+    let version_spec = match version_constraint.strip_prefix('=') {
+        Some(exact_version) if !exact_version.starts_with('=') => {
+            exact_version.split('=').next().unwrap_or("")
+        }
+        _ => version_constraint,
+    }
+    .trim_end_matches(".*")
+    .trim();
+    (
+        conda_spec[..name_length].to_string(),
+        (!version_spec.is_empty()).then(|| version_spec.to_string()),
+    )
+}
+
+/// Parsed `deno.json` or `deno.jsonc`
+fn read_deno_config(project_path: &Path) -> Option<serde_json::Value> {
+    ["deno.json", "deno.jsonc"].iter().find_map(|config_file| {
+        let config_content = fs::read_to_string(project_path.join(config_file)).ok()?;
+        serde_json::from_str(&strip_json_comments(&config_content)).ok()
+    })
+}
+
+/// Dependencies from the Deno import map, inline in `deno.json` or via `importMap`
+fn collect_deno_dependencies(project_path: &Path) -> Vec<(String, Option<String>)> {
+    let Some(deno_config) = read_deno_config(project_path) else {
+        return Vec::new();
+    };
+
+    let external_import_map = deno_config
+        .get("importMap")
+        .and_then(|import_map_value| import_map_value.as_str())
+        .and_then(|import_map_file| fs::read_to_string(project_path.join(import_map_file)).ok())
+        .and_then(|import_map_content| {
+            serde_json::from_str::<serde_json::Value>(&strip_json_comments(&import_map_content))
+                .ok()
+        });
+
+    let mut deno_dependencies = Vec::new();
+    for import_map in [Some(&deno_config), external_import_map.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        if let Some(import_entries) = import_map.get("imports").and_then(|i| i.as_object()) {
+            for import_target in import_entries.values().filter_map(|target| target.as_str()) {
+                if let Some(dependency) = parse_deno_specifier(import_target) {
+                    deno_dependencies.push(dependency);
+                }
+            }
+        }
+    }
+    deno_dependencies
+}
+
+/// Package name and version from `jsr:`, `npm:`, deno.land and esm.sh specifiers
+fn parse_deno_specifier(specifier: &str) -> Option<(String, Option<String>)> {
+    let package_reference = if let Some(registry_reference) = specifier
+        .strip_prefix("jsr:")
+        .or_else(|| specifier.strip_prefix("npm:"))
+    {
+        registry_reference.trim_start_matches('/')
+    } else if let Some(module_path) = specifier
+        .strip_prefix("https://deno.land/x/")
+        .or_else(|| specifier.strip_prefix("https://deno.land/"))
+        .or_else(|| specifier.strip_prefix("https://esm.sh/"))
+    {
+        module_path.trim_start_matches('*')
+    } else {
+        return None;
+    };
+
+    // Scoped names keep their first slash: `@std/http@1.0.0/server`
+    let name_segment_count = if package_reference.starts_with('@') {
+        2
+    } else {
+        1
+    };
+    let package_spec = package_reference
+        .splitn(name_segment_count + 1, '/')
+        .take(name_segment_count)
+        .collect::<Vec<_>>()
+        .join("/");
+
+    match package_spec.rfind('@').filter(|&at_index| at_index > 0) {
+        Some(at_index) => Some((
+            package_spec[..at_index].to_string(),
+            Some(package_spec[at_index + 1..].to_string()),
+        )),
+        None => Some((package_spec, None)),
+    }
+}
+
+/// Trimmed contents of a single-value version file like `.nvmrc`
+fn read_version_file(version_file_path: &Path) -> Option<String> {
+    let version_content = fs::read_to_string(version_file_path).ok()?;
+    let version = version_content.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// Combined sbt/Mill build definition with its simple string constants
+struct ScalaBuild {
+    build_content: String,
+    string_constants: HashMap<String, String>,
+}
+
+impl ScalaBuild {
+    /// Resolve a quoted literal or a reference to a `val`/`def` string constant
+    fn resolve(&self, expression: &str) -> Option<String> {
+        let expression = expression.trim();
+        if let Some(literal) = expression
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            return Some(literal.to_string());
+        }
+        let constant_name = expression.rsplit('.').next().unwrap_or(expression);
+        self.string_constants.get(constant_name).cloned()
+    }
+
+    /// `(group, artifact, version)` from sbt `%`/`%%` and Mill `ivy"g::a:v"` coordinates
+    fn dependencies(&self) -> Vec<(String, String, Option<String>)> {
+        let mut scala_dependencies = Vec::new();
+
+        let sbt_coordinate = regex::Regex::new(
+            r#""([^"]+)"\s*%{1,3}\s*"([^"]+)"(?:\s*%\s*("[^"]*"|[A-Za-z_][\w.]*))?"#,
+        )
+        .unwrap();
+        for captures in sbt_coordinate.captures_iter(&self.build_content) {
+            scala_dependencies.push((
+                captures[1].to_string(),
+                captures[2].to_string(),
+                captures
+                    .get(3)
+                    .and_then(|version_match| self.resolve(version_match.as_str())),
+            ));
+        }
+
+        let mill_coordinate = regex::Regex::new(r#"(?:ivy|mvn)"([^"]+)""#).unwrap();
+        for captures in mill_coordinate.captures_iter(&self.build_content) {
+            let coordinate_parts: Vec<&str> = captures[1]
+                .split(':')
+                .filter(|part| !part.is_empty())
+                .collect();
+            if let [group, artifact, version_parts @ ..] = coordinate_parts.as_slice() {
+                let artifact_version = version_parts.first().map(|version| {
+                    let version = version.trim_start_matches('$');
+                    self.string_constants
+                        .get(version.trim_matches(|c| c == '{' || c == '}'))
+                        .cloned()
+                        .unwrap_or_else(|| version.to_string())
+                });
+                scala_dependencies.push((
+                    group.to_string(),
+                    artifact.to_string(),
+                    artifact_version,
+                ));
+            }
+        }
+
+        scala_dependencies
+    }
+}
+
+/// Read `build.sbt`/`build.sc`/`build.mill` plus the usual `project/` helper files
+fn read_scala_build(project_path: &Path) -> ScalaBuild {
+    let mut build_content = String::new();
+    for build_file in [
+        "build.sbt",
+        "build.sc",
+        "build.mill",
+        "project/plugins.sbt",
+        "project/Dependencies.scala",
+        "project/Versions.scala",
+    ] {
+        if let Ok(file_content) = fs::read_to_string(project_path.join(build_file)) {
+            build_content.push_str(&file_content);
+            build_content.push('\n');
+        }
+    }
+
+    let constant_definition =
+        regex::Regex::new(r#"(?:val|def)\s+([A-Za-z_]\w*)(?:\s*:\s*String)?\s*=\s*"([^"]*)""#)
+            .unwrap();
+    let string_constants = constant_definition
+        .captures_iter(&build_content)
+        .map(|captures| (captures[1].to_string(), captures[2].to_string()))
+        .collect();
+
+    ScalaBuild {
+        build_content,
+        string_constants,
+    }
+}
+
+/// Check for a project file, where `*.ext` (or `dir/*.ext`) matches any file with that
+/// extension and `name.*` matches `name` with any extension
+fn project_file_exists(project_path: &Path, project_file: &str) -> bool {
+    let (search_directory, file_pattern) = match project_file.rsplit_once('/') {
+        Some((directory_name, file_pattern)) => (project_path.join(directory_name), file_pattern),
+        None => (project_path.to_path_buf(), project_file),
+    };
+    if let Some(file_extension) = file_pattern.strip_prefix("*.") {
+        return find_file_with_extension(&search_directory, file_extension).is_some();
+    }
+    if let Some(file_stem) = file_pattern.strip_suffix(".*") {
+        let file_prefix = format!("{}.", file_stem);
+        return fs::read_dir(&search_directory)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(|directory_entry| {
+                directory_entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&file_prefix)
+            });
+    }
+    search_directory.join(file_pattern).exists()
+}
+
+/// First file (by name) in the project directory with the given extension
+fn find_file_with_extension(project_path: &Path, file_extension: &str) -> Option<PathBuf> {
+    let mut matching_files: Vec<PathBuf> = fs::read_dir(project_path)
+        .ok()?
+        .flatten()
+        .map(|directory_entry| directory_entry.path())
+        .filter(|entry_path| {
+            entry_path.is_file()
+                && entry_path.extension().and_then(|e| e.to_str()) == Some(file_extension)
+        })
+        .collect();
+    matching_files.sort();
+    matching_files.into_iter().next()
+}
+
+/// Field of a Debian control style file such as R's `DESCRIPTION`
+fn read_dcf_field(dcf_content: &str, field_name: &str) -> Option<String> {
+    let mut field_value: Option<String> = None;
+    for content_line in dcf_content.lines() {
+        if let Some(ref mut value) = field_value {
+            // Continuation lines are indented
+            if content_line.starts_with([' ', '\t']) {
+                value.push(' ');
+                value.push_str(content_line.trim());
+                continue;
+            }
+            break;
+        }
+        if let Some(value) = content_line
+            .strip_prefix(field_name)
+            .and_then(|rest| rest.strip_prefix(':'))
+        {
+            field_value = Some(value.trim().to_string());
+        }
+    }
+    field_value
+}
+
+/// String field like `.name = "foo"` (or `.name = .foo`) in a `build.zig.zon` file
+fn read_zon_field(zon_content: &str, field_name: &str) -> Option<String> {
+    let field_pattern = regex::Regex::new(&format!(
+        r#"\.{}\s*=\s*(?:"([^"]*)"|\.@?"?([A-Za-z0-9_]+))"#,
+        regex::escape(field_name)
+    ))
+    .ok()?;
+    let captures = field_pattern.captures(zon_content)?;
+    captures
+        .get(1)
+        .or_else(|| captures.get(2))
+        .map(|field_match| field_match.as_str().to_string())
+}
+
+/// Top-level stanzas of `dune-project`, which is an s-expression file
+fn read_dune_project(project_path: &Path) -> Vec<EdnValue> {
+    fs::read_to_string(project_path.join("dune-project"))
+        .ok()
+        .and_then(|dune_content| edn::parse(&dune_content).ok())
+        .unwrap_or_default()
+}
+
+/// The single argument of a `(field value)` stanza
+fn dune_stanza_argument(stanza: &EdnValue, field_name: &str) -> Option<String> {
+    match stanza.as_seq()? {
+        [EdnValue::Symbol(head), EdnValue::Symbol(value) | EdnValue::String(value)]
+            if head == field_name =>
+        {
+            Some(value.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Search a dune stanza for `(ocaml (>= 4.14))` inside any `depends` list
+fn find_dune_ocaml_constraint(stanza: &EdnValue) -> Option<String> {
+    let stanza_items = stanza.as_seq()?;
+    if let [EdnValue::Symbol(head), constraint_items @ ..] = stanza_items {
+        if head == "ocaml" {
+            return constraint_items
+                .iter()
+                .find_map(|constraint| match constraint.as_seq()? {
+                    [EdnValue::Symbol(operator), version] => {
+                        let version_text = match version {
+                            EdnValue::Number(text)
+                            | EdnValue::String(text)
+                            | EdnValue::Symbol(text) => text,
+                            _ => return None,
+                        };
+                        Some(format!("{} {}", operator, version_text))
+                    }
+                    _ => None,
+                });
+        }
+    }
+    stanza_items.iter().find_map(find_dune_ocaml_constraint)
+}
+
+fn get_dependency_version(
+    dependencies_table: &toml::map::Map<String, toml::Value>,
+    dependency_name: &str,
+) -> Option<String> {
+    dependencies_table
+        .get(dependency_name)
+        .and_then(|dependency_value| match dependency_value {
+            toml::Value::String(version_string) => Some(version_string.clone()),
+            toml::Value::Table(dependency_table) => dependency_table
+                .get("version")
+                .and_then(|version_value| version_value.as_str())
+                .map(|version_string| version_string.to_string()),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_info::{FrameworkCategory, LanguageStatus};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_rust_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            r#"[package]
+name = "test-project"
+version = "0.1.0"
+rust-version = "1.70"
+
+[dependencies]
+axum = "0.7"
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Rust");
+        assert_eq!(info.project_name, Some("test-project".to_string()));
+        assert_eq!(info.language_version, Some("1.70".to_string()));
+        assert_eq!(info.framework, Some("Axum".to_string()));
+    }
+
+    #[test]
+    fn test_detect_rust_toolchain() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            r#"[workspace.package]
+edition = "2021"
+
+[package]
+name = "firmware"
+version = "0.1.0"
+edition.workspace = true
+"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("rust-toolchain.toml"),
+            r#"[toolchain]
+channel = "nightly-2023-11-20"
+components = ["rustfmt", "clippy", "rust-src"]
+targets = ["thumbv7em-none-eabihf"]
+profile = "minimal"
+"#,
+        )
+        .unwrap();
+        fs::create_dir(temp_dir.path().join(".cargo")).unwrap();
+        fs::write(
+            temp_dir.path().join(".cargo").join("config.toml"),
+            "[build]\ntarget = \"thumbv7em-none-eabihf\"\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(
+            info.language_version,
+            Some("nightly-2023-11-20".to_string())
+        );
+        assert_eq!(info.language_status, LanguageStatus::Supported);
+
+        let rust_toolchain = info.rust_toolchain.unwrap();
+        assert_eq!(rust_toolchain.edition, Some("2021".to_string()));
+        assert_eq!(rust_toolchain.profile, Some("minimal".to_string()));
+        assert_eq!(
+            rust_toolchain.components,
+            vec!["rustfmt", "clippy", "rust-src"]
+        );
+        assert_eq!(rust_toolchain.targets, vec!["thumbv7em-none-eabihf"]);
+        assert_eq!(rust_toolchain.build_targets, vec!["thumbv7em-none-eabihf"]);
+
+        let legacy_dir = TempDir::new().unwrap();
+        fs::write(
+            legacy_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"old\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        fs::write(legacy_dir.path().join("rust-toolchain"), "1.75.0\n").unwrap();
+        let info = detect_project_info(legacy_dir.path()).unwrap();
+        assert_eq!(info.language_version, Some("1.75.0".to_string()));
+        let rust_toolchain = info.rust_toolchain.unwrap();
+        assert_eq!(rust_toolchain.edition, Some("2015".to_string()));
+        assert_eq!(rust_toolchain.channel, Some("1.75.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_rust_frameworks_across_dependency_tables() {
+        let tauri_dir = TempDir::new().unwrap();
+        fs::write(
+            tauri_dir.path().join("Cargo.toml"),
+            r#"[package]
+name = "desktop"
+version = "0.1.0"
+
+[dependencies]
+serde = "1"
+clap = "4"
+
+[target.'cfg(not(target_os = "android"))'.dependencies]
+tauri = { version = "2.0.0", features = ["tray-icon"] }
+
+[build-dependencies]
+tauri-build = "2.0.0"
+"#,
+        )
+        .unwrap();
+        let info = detect_project_info(tauri_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Tauri".to_string()));
+        assert_eq!(info.framework_version, Some("2.0.0".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::Desktop));
+
+        let workspace_dir = TempDir::new().unwrap();
+        fs::write(
+            workspace_dir.path().join("Cargo.toml"),
+            r#"[workspace.dependencies]
+grpc = { package = "tonic", version = "0.11" }
+
+[package]
+name = "service"
+version = "0.1.0"
+
+[dependencies]
+grpc = { workspace = true }
+
+[dev-dependencies]
+axum = "0.7"
+"#,
+        )
+        .unwrap();
+        let info = detect_project_info(workspace_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Tonic".to_string()));
+        assert_eq!(info.framework_version, Some("0.11".to_string()));
+
+        let dev_only_dir = TempDir::new().unwrap();
+        fs::write(
+            dev_only_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"lib\"\nversion = \"0.1.0\"\n\n[dev-dependencies]\nratatui = \"0.26\"\n",
+        )
+        .unwrap();
+        let info = detect_project_info(dev_only_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Ratatui".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::Cli));
+    }
+
+    #[test]
+    fn test_detect_javascript_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{
+  "name": "my-react-app",
+  "version": "1.0.0",
+  "engines": {
+    "node": "18.0.0"
+  },
+  "dependencies": {
+    "react": "^18.2.0"
+  }
+}"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "JavaScript");
+        assert_eq!(info.project_name, Some("my-react-app".to_string()));
+        assert_eq!(info.language_version, Some("18.0.0".to_string()));
+        assert_eq!(info.framework, Some("React".to_string()));
+        assert_eq!(info.runtime, Some("Node".to_string()));
+        assert_eq!(info.runtime_version, Some("18.0.0".to_string()));
+        assert_eq!(
+            info.language_version_source,
+            Some("package.json engines.node".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_javascript_framework_catalogue() {
+        let javascript_project = |package_json_content: &str| {
+            let temp_dir = TempDir::new().unwrap();
+            fs::write(temp_dir.path().join("package.json"), package_json_content).unwrap();
+            detect_project_info(temp_dir.path()).unwrap()
+        };
+
+        let info =
+            javascript_project(r#"{"dependencies": {"react": "^18.2.0", "next": "14.1.0"}}"#);
+        assert_eq!(info.framework, Some("Next.js".to_string()));
+        assert_eq!(info.framework_version, Some("14.1.0".to_string()));
+
+        let info = javascript_project(
+            r#"{"dependencies": {"react": "^18.2.0"}, "devDependencies": {"electron": "^29.0.0"}}"#,
+        );
+        assert_eq!(info.framework, Some("Electron".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::Desktop));
+
+        let info = javascript_project(
+            r#"{"dependencies": {"expo": "~50.0.0", "react-native": "0.73.4", "react": "18.2.0"}}"#,
+        );
+        assert_eq!(info.framework, Some("Expo".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::Mobile));
+
+        let info = javascript_project(
+            r#"{"devDependencies": {"@sveltejs/kit": "^2.5.0", "svelte": "^4.2.0"}}"#,
+        );
+        assert_eq!(info.framework, Some("SvelteKit".to_string()));
+
+        let info = javascript_project(
+            r#"{"dependencies": {"@nestjs/core": "^10.3.0", "express": "^4.18.0"}}"#,
+        );
+        assert_eq!(info.framework, Some("NestJS".to_string()));
+        assert!(info.framework_details.is_some());
+    }
+
+    #[test]
+    fn test_detect_framework_with_import_scan() {
+        let scan_options = ExamineOptions::new().with_import_scan(true);
+
+        // `axum` is an optional dependency the default build never compiles
+        let optional_dir = TempDir::new().unwrap();
+        fs::create_dir(optional_dir.path().join("src")).unwrap();
+        fs::write(
+            optional_dir.path().join("Cargo.toml"),
+            r#"[package]
+name = "cli"
+version = "0.1.0"
+
+[dependencies]
+axum = { version = "0.7", optional = true }
+clap = "4.5"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            optional_dir.path().join("src").join("main.rs"),
+            "fn main() {\n    let matches = clap::Command::new(\"cli\").get_matches();\n}\n",
+        )
+        .unwrap();
+        let info = detect_project_info(optional_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Axum".to_string()));
+        let info = detect_project_info_with_options(optional_dir.path(), &scan_options).unwrap();
+        assert_eq!(info.framework, Some("Clap (CLI)".to_string()));
+        assert_eq!(info.framework_version, Some("4.5".to_string()));
+
+        // Renamed dependencies are imported by their key
+        let renamed_dir = TempDir::new().unwrap();
+        fs::create_dir(renamed_dir.path().join("src")).unwrap();
+        fs::write(
+            renamed_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"server\"\nversion = \"0.1.0\"\n\n[dependencies]\nweb = { package = \"actix-web\", version = \"4\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            renamed_dir.path().join("src").join("main.rs"),
+            "use web::App;\n",
+        )
+        .unwrap();
+        let info = detect_project_info_with_options(renamed_dir.path(), &scan_options).unwrap();
+        assert_eq!(info.framework, Some("Actix Web".to_string()));
+        let dependency_report = info.dependency_report.unwrap();
+        assert!(dependency_report.unused_dependencies.is_empty());
+        assert!(dependency_report.undeclared_imports.is_empty());
+
+        // Loose scripts with no manifest at all
+        let script_dir = TempDir::new().unwrap();
+        fs::write(
+            script_dir.path().join("app.py"),
+            "from fastapi import FastAPI\n\napp = FastAPI()\n",
+        )
+        .unwrap();
+        let info = detect_project_info(script_dir.path()).unwrap();
+        assert!(info.framework.is_none());
+        let info = detect_project_info_with_options(script_dir.path(), &scan_options).unwrap();
+        assert_eq!(info.framework, Some("FastAPI".to_string()));
+        assert!(info.framework_version.is_none());
+
+        // Next.js pages import React, never `next` itself
+        let next_dir = TempDir::new().unwrap();
+        fs::create_dir(next_dir.path().join("pages")).unwrap();
+        fs::write(
+            next_dir.path().join("package.json"),
+            r#"{"dependencies": {"next": "14.1.0", "react": "18.2.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            next_dir.path().join("next.config.js"),
+            "module.exports = {};\n",
+        )
+        .unwrap();
+        fs::write(
+            next_dir.path().join("pages").join("index.js"),
+            "import React from 'react';\n\nexport default function Home() {}\n",
+        )
+        .unwrap();
+        let info = detect_project_info_with_options(next_dir.path(), &scan_options).unwrap();
+        assert_eq!(info.framework, Some("Next.js".to_string()));
+        assert_eq!(info.framework_version, Some("14.1.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_testing_from_dependency_groups() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("tests")).unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"[project]
+name = "service"
+dependencies = ["fastapi>=0.110"]
+
+[dependency-groups]
+dev = ["pytest>=8.1", "httpx", {include-group = "lint"}]
+lint = ["ruff"]
+"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("app.py"),
+            "from fastapi import FastAPI\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        let test_setup = info.testing.unwrap();
+        assert_eq!(test_setup.frameworks.len(), 1);
+        assert_eq!(test_setup.frameworks[0].name, "pytest");
+        assert_eq!(test_setup.frameworks[0].version, Some("8.1".to_string()));
+        assert_eq!(test_setup.test_directories, vec!["tests"]);
+
+        // Groups are development tooling, never expected in application imports
+        let scan_options = ExamineOptions::new().with_import_scan(true);
+        let info = detect_project_info_with_options(temp_dir.path(), &scan_options).unwrap();
+        assert_eq!(info.framework, Some("FastAPI".to_string()));
+        assert!(info
+            .dependency_report
+            .unwrap()
+            .unused_dependencies
+            .is_empty());
+    }
+
+    #[test]
+    fn test_detect_dependency_report() {
+        let scan_options = ExamineOptions::new().with_import_scan(true);
+
+        let rust_dir = TempDir::new().unwrap();
+        fs::create_dir_all(rust_dir.path().join("src")).unwrap();
+        fs::create_dir_all(rust_dir.path().join("tests")).unwrap();
+        fs::write(
+            rust_dir.path().join("Cargo.toml"),
+            r#"[package]
+name = "web-app"
+version = "0.1.0"
+
+[dependencies]
+axum = "0.7"
+serde = "1"
+json = { package = "serde_json", version = "1" }
+lazy_static = "1"
+
+[dev-dependencies]
+reqwest = "0.12"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            rust_dir.path().join("src").join("main.rs"),
+            "mod routes;\nuse axum::Router;\nuse routes::index;\nuse serde::Serialize;\nuse anyhow::Result;\n",
+        )
+        .unwrap();
+        fs::write(
+            rust_dir.path().join("src").join("routes.rs"),
+            "use std::fmt;\nuse json::Value;\n",
+        )
+        .unwrap();
+        fs::write(
+            rust_dir.path().join("tests").join("api.rs"),
+            "use web_app::routes;\nuse reqwest::Client;\n",
+        )
+        .unwrap();
+        let info = detect_project_info_with_options(rust_dir.path(), &scan_options).unwrap();
+        let dependency_report = info.dependency_report.unwrap();
+        assert_eq!(dependency_report.source_file_count, 3);
+        assert_eq!(dependency_report.unused_dependencies, vec!["lazy_static"]);
+        assert_eq!(dependency_report.undeclared_imports, vec!["anyhow"]);
+        assert!(detect_project_info(rust_dir.path())
+            .unwrap()
+            .dependency_report
+            .is_none());
+
+        let node_dir = TempDir::new().unwrap();
+        fs::write(
+            node_dir.path().join("package.json"),
+            r#"{"name": "api", "dependencies": {"express": "^4.18.0", "lodash": "^4.17.0", "@types/express": "^4"},
+                "devDependencies": {"jest": "^29.0.0", "eslint": "^8.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            node_dir.path().join("index.js"),
+            "const express = require('express');\nconst path = require('path');\nconst dayjs = require('dayjs');\nconst { helper } = require('./helper');\n",
+        )
+        .unwrap();
+        let info = detect_project_info_with_options(node_dir.path(), &scan_options).unwrap();
+        let dependency_report = info.dependency_report.unwrap();
+        assert_eq!(dependency_report.unused_dependencies, vec!["lodash"]);
+        assert_eq!(dependency_report.undeclared_imports, vec!["dayjs"]);
+
+        let python_dir = TempDir::new().unwrap();
+        fs::create_dir_all(python_dir.path().join("service")).unwrap();
+        fs::write(python_dir.path().join("service").join("__init__.py"), "").unwrap();
+        fs::write(
+            python_dir.path().join("requirements.txt"),
+            "fastapi==0.110.0\nscikit-learn\nrequests\npytest\npytest-cov\n",
+        )
+        .unwrap();
+        fs::write(
+            python_dir.path().join("service").join("main.py"),
+            "import os\nfrom fastapi import FastAPI\nfrom sklearn import svm\nimport numpy as np\nfrom service import models\n",
+        )
+        .unwrap();
+        let info = detect_project_info_with_options(python_dir.path(), &scan_options).unwrap();
+        let dependency_report = info.dependency_report.unwrap();
+        assert_eq!(dependency_report.unused_dependencies, vec!["requests"]);
+        assert_eq!(dependency_report.undeclared_imports, vec!["numpy"]);
+    }
+
+    #[test]
+    fn test_detect_framework_from_config_files() {
+        // A monorepo package whose dependencies are hoisted to the root
+        let next_dir = TempDir::new().unwrap();
+        fs::write(
+            next_dir.path().join("package.json"),
+            r#"{"name": "web", "private": true}"#,
+        )
+        .unwrap();
+        fs::write(
+            next_dir.path().join("next.config.mjs"),
+            "export default {};\n",
+        )
+        .unwrap();
+        let info = detect_project_info(next_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Next.js".to_string()));
+        assert!(info.framework_version.is_none());
+
+        let kit_dir = TempDir::new().unwrap();
+        fs::write(kit_dir.path().join("package.json"), "{}").unwrap();
+        fs::write(
+            kit_dir.path().join("svelte.config.js"),
+            "import adapter from '@sveltejs/kit/adapter-auto';\n",
+        )
+        .unwrap();
+        let info = detect_project_info(kit_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("SvelteKit".to_string()));
+
+        let django_dir = TempDir::new().unwrap();
+        let settings_dir = django_dir.path().join("mysite");
+        fs::create_dir(&settings_dir).unwrap();
+        fs::write(django_dir.path().join("requirements.txt"), "psycopg2\n").unwrap();
+        fs::write(
+            settings_dir.join("settings.py"),
+            "INSTALLED_APPS = [\"django.contrib.admin\"]\n",
+        )
+        .unwrap();
+        let info = detect_project_info(django_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Django".to_string()));
+
+        let rails_dir = TempDir::new().unwrap();
+        fs::write(
+            rails_dir.path().join("Gemfile"),
+            "source 'https://rubygems.org'\n",
+        )
+        .unwrap();
+        fs::create_dir(rails_dir.path().join("config")).unwrap();
+        fs::write(
+            rails_dir.path().join("config").join("application.rb"),
+            "module Blog\n  class Application < Rails::Application\n  end\nend\n",
+        )
+        .unwrap();
+        let info = detect_project_info(rails_dir.path()).unwrap();
+        assert_eq!(info.language, "Ruby");
+        assert_eq!(info.framework, Some("Rails".to_string()));
+        assert!(info.framework_details.is_some());
+    }
+
+    #[test]
+    fn test_detect_node_version_sources() {
+        let temp_dir = TempDir::new().unwrap();
+        let node_version_with_source = || {
+            let pinned_tool_versions = tool_versions::read_tool_versions(temp_dir.path());
+            detect_node_version_with_source(temp_dir.path(), &pinned_tool_versions)
+        };
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "api", "engines": {"node": ">=16"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("mise.toml"),
+            "[tools]\nnode = [\"20.11.0\", \"18\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            node_version_with_source(),
+            Some(("20.11.0".to_string(), "mise.toml".to_string()))
+        );
+
+        fs::write(temp_dir.path().join(".tool-versions"), "nodejs 18.19.0\n").unwrap();
+        assert_eq!(
+            node_version_with_source(),
+            Some(("18.19.0".to_string(), ".tool-versions".to_string()))
+        );
+
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "api", "volta": {"node": "20.10.0"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            node_version_with_source(),
+            Some(("20.10.0".to_string(), "package.json volta.node".to_string()))
+        );
+
+        fs::write(temp_dir.path().join(".node-version"), "v21.6.1\n").unwrap();
+        assert_eq!(
+            node_version_with_source(),
+            Some(("v21.6.1".to_string(), ".node-version".to_string()))
+        );
+
+        fs::write(temp_dir.path().join(".nvmrc"), "lts/hydrogen\n").unwrap();
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language_version, Some("18".to_string()));
+        assert_eq!(info.language_version_source, Some(".nvmrc".to_string()));
+        assert_eq!(info.language_status, LanguageStatus::Supported);
+    }
+
+    #[test]
+    fn test_detect_deno_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("deno.jsonc"),
+            r#"{
+  // Fresh project
+  "name": "@acme/storefront",
+  "tasks": { "start": "deno run -A main.ts" },
+  "imports": {
+    "$fresh/": "https://deno.land/x/fresh@1.6.1/",
+    "@std/http": "jsr:@std/http@^0.218.0", /* std */
+    "preact": "https://esm.sh/preact@10.19.2",
+  },
+}"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join(".tool-versions"), "deno 1.41.0\n").unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "JavaScript");
+        assert_eq!(info.project_name, Some("@acme/storefront".to_string()));
+        assert_eq!(info.runtime, Some("Deno".to_string()));
+        assert_eq!(info.runtime_version, Some("1.41.0".to_string()));
+        assert_eq!(info.framework, Some("Fresh".to_string()));
+        assert_eq!(info.framework_version, Some("1.6.1".to_string()));
+    }
+
+    #[test]
+    fn test_detect_bun_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{
+  "name": "bun-api",
+  "packageManager": "bun@1.1.8",
+  "dependencies": { "elysia": "^1.0.0" }
+}"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("bunfig.toml"), "[install]\n").unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.runtime, Some("Bun".to_string()));
+        assert_eq!(info.runtime_version, Some("1.1.8".to_string()));
+        assert_eq!(info.framework, Some("Elysia".to_string()));
+    }
+
+    #[test]
+    fn test_parse_deno_specifier() {
+        assert_eq!(
+            parse_deno_specifier("jsr:@std/http@^0.218.0"),
+            Some(("@std/http".to_string(), Some("^0.218.0".to_string())))
+        );
+        assert_eq!(
+            parse_deno_specifier("npm:hono@4"),
+            Some(("hono".to_string(), Some("4".to_string())))
+        );
+        assert_eq!(
+            parse_deno_specifier("https://deno.land/x/oak@v12.6.1/mod.ts"),
+            Some(("oak".to_string(), Some("v12.6.1".to_string())))
+        );
+        assert_eq!(
+            parse_deno_specifier("jsr:@oak/oak"),
+            Some(("@oak/oak".to_string(), None))
+        );
+        assert_eq!(parse_deno_specifier("./src/utils.ts"), None);
+    }
+
+    #[test]
+    fn test_detect_go_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("go.mod"),
+            r#"module github.com/example/my-go-app
+
+go 1.21
+
+require github.com/gin-gonic/gin v1.9.0
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Go");
+        assert_eq!(info.project_name, Some("my-go-app".to_string()));
+        assert_eq!(info.language_version, Some("1.21".to_string()));
+        assert_eq!(info.framework, Some("Gin".to_string()));
+        assert_eq!(info.framework_version, Some("1.9.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_go_frameworks() {
+        let go_project = |go_mod_content: &str| {
+            let temp_dir = TempDir::new().unwrap();
+            fs::write(temp_dir.path().join("go.mod"), go_mod_content).unwrap();
+            temp_dir
+        };
+
+        let cli_dir = go_project(
+            "module example.com/tool\n\ngo 1.22\n\nrequire (\n\tgithub.com/spf13/cobra v1.8.0\n\tgithub.com/charmbracelet/bubbletea v0.25.0\n\tgoogle.golang.org/grpc v1.62.0 // indirect\n)\n",
+        );
+        let info = detect_project_info(cli_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Bubble Tea".to_string()));
+        assert_eq!(info.framework_version, Some("0.25.0".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::Cli));
+
+        let router_dir = go_project(
+            "module example.com/api\n\ngo 1.22\n\nrequire (\n\tgithub.com/go-chi/chi/v5 v5.0.12\n\tgoogle.golang.org/grpc v1.62.0\n)\n",
+        );
+        let info = detect_project_info(router_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("chi".to_string()));
+        assert!(info.framework_details.is_some());
+
+        let operator_dir = go_project(
+            "module example.com/operator\n\ngo 1.22\n\nrequire sigs.k8s.io/controller-runtime v0.17.2\n",
+        );
+        fs::write(
+            operator_dir.path().join("PROJECT"),
+            "domain: example.com\nlayout:\n- go.kubebuilder.io/v4\n",
+        )
+        .unwrap();
+        let info = detect_project_info(operator_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Kubebuilder".to_string()));
+        assert_eq!(
+            info.framework_category,
+            Some(FrameworkCategory::Infrastructure)
+        );
+    }
+
+    #[test]
+    fn test_detect_go_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("go.work"),
+            "go 1.22.0\n\nuse (\n\t./api\n\t./worker\n)\n",
+        )
+        .unwrap();
+        let api_dir = temp_dir.path().join("api");
+        fs::create_dir(&api_dir).unwrap();
+        fs::write(
+            api_dir.join("go.mod"),
+            r#"module github.com/acme/api
+
+go 1.22.0
+
+toolchain go1.22.3
+
+require (
+	github.com/labstack/echo/v4 v4.11.4
+	golang.org/x/net v0.20.0 // indirect
+)
+"#,
+        )
+        .unwrap();
+        let worker_dir = temp_dir.path().join("worker");
+        fs::create_dir(&worker_dir).unwrap();
+        fs::write(
+            worker_dir.join("go.mod"),
+            "module github.com/acme/worker\n\ngo 1.21\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Go");
+        assert_eq!(info.language_version, Some("1.22.0".to_string()));
+        assert_eq!(info.framework, Some("Echo".to_string()));
+        assert_eq!(info.framework_version, Some("4.11.4".to_string()));
+        assert_eq!(info.members.len(), 2);
+
+        let api_member = &info.members[0];
+        assert_eq!(api_member.project_name, Some("api".to_string()));
+        assert_eq!(api_member.language_version, Some("1.22.3".to_string()));
+        assert_eq!(api_member.framework, Some("Echo".to_string()));
+        assert_eq!(info.members[1].project_name, Some("worker".to_string()));
+        assert!(info.members[1].framework.is_none());
+    }
+
+    #[test]
+    fn test_detect_python_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"[project]
+name = "my-django-app"
+requires-python = ">=3.11"
+dependencies = [
+    "django>=4.2.0"
+]
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.project_name, Some("my-django-app".to_string()));
+        assert_eq!(info.language_version, Some(">=3.11".to_string()));
+        assert_eq!(info.framework, Some("Django".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::Web));
+    }
+
+    #[test]
+    fn test_detect_python_requirements_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let requirements_dir = temp_dir.path().join("requirements");
+        fs::create_dir(&requirements_dir).unwrap();
+        fs::write(
+            requirements_dir.join("base.txt"),
+            "django-environ==0.11.2\nDjango>=4.2,<5.0 ; python_version >= \"3.10\"\n",
+        )
+        .unwrap();
+        fs::write(requirements_dir.join("dev.txt"), "-r base.txt\npytest\n").unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.framework, Some("Django".to_string()));
+        assert_eq!(info.framework_version, Some("4.2".to_string()));
+
+        let environ_only = TempDir::new().unwrap();
+        fs::write(
+            environ_only.path().join("requirements.txt"),
+            "django-environ==0.11.2\nflask_cors\n",
+        )
+        .unwrap();
+        let info = detect_project_info(environ_only.path()).unwrap();
+        assert!(info.framework.is_none());
+    }
+
+    #[test]
+    fn test_detect_python_ml_and_data_frameworks() {
+        let ml_dir = TempDir::new().unwrap();
+        fs::write(
+            ml_dir.path().join("requirements.txt"),
+            "numpy==1.26.4\ntorch==2.2.1\ntransformers>=4.38\n",
+        )
+        .unwrap();
+        let info = detect_project_info(ml_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Transformers".to_string()));
+        assert_eq!(info.framework_version, Some("4.38".to_string()));
+        assert_eq!(
+            info.framework_category,
+            Some(FrameworkCategory::MachineLearning)
+        );
+
+        let pipeline_dir = TempDir::new().unwrap();
+        fs::write(
+            pipeline_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"etl\"\ndependencies = [\"pandas\", \"apache-airflow==2.8.1\"]\n",
+        )
+        .unwrap();
+        let info = detect_project_info(pipeline_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Airflow".to_string()));
+        assert_eq!(
+            info.framework_category,
+            Some(FrameworkCategory::DataPipeline)
+        );
+
+        let dashboard_dir = TempDir::new().unwrap();
+        fs::write(
+            dashboard_dir.path().join("requirements.txt"),
+            "scikit-learn==1.4.0\nstreamlit==1.31.0\n",
+        )
+        .unwrap();
+        let info = detect_project_info(dashboard_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Streamlit".to_string()));
+        assert_eq!(info.framework_category, Some(FrameworkCategory::DataApp));
+    }
+
+    #[test]
+    fn test_detect_notebook_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let notebooks_dir = temp_dir.path().join("notebooks");
+        fs::create_dir(&notebooks_dir).unwrap();
+        fs::write(
+            notebooks_dir.join("01-explore.ipynb"),
+            r#"{"nbformat": 4, "nbformat_minor": 5,
+                "metadata": {"kernelspec": {"name": "python3", "language": "python", "display_name": "Python 3"},
+                             "language_info": {"name": "python", "version": "3.10.12"}},
+                "cells": [{"cell_type": "code", "source": "import pandas as pd\nfrom sklearn.linear_model import LinearRegression\n"}]}"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Analysis\n").unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.language_version, Some("3.10.12".to_string()));
+        assert_eq!(
+            info.language_version_source,
+            Some(
+                Path::new("notebooks")
+                    .join("01-explore.ipynb")
+                    .display()
+                    .to_string()
+            )
+        );
+        assert_eq!(info.framework, Some("scikit-learn".to_string()));
+
+        let notebook_summary = info.notebooks.unwrap();
+        assert_eq!(notebook_summary.notebook_count, 1);
+        assert_eq!(notebook_summary.kernel_name, Some("python3".to_string()));
+        assert_eq!(
+            notebook_summary.imported_libraries,
+            vec!["pandas", "sklearn"]
+        );
+    }
+
+    #[test]
+    fn test_detect_clojure_deps_edn_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("deps.edn"),
+            r#"{:paths ["src"]
+ :deps {org.clojure/clojure {:mvn/version "1.11.1"}
+        io.pedestal/pedestal.service {:mvn/version "0.6.3"}
+        ring/ring-core {:mvn/version "1.10.0"}}}"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Clojure");
+        assert_eq!(info.language_version, Some("1.11.1".to_string()));
+        assert_eq!(info.runtime, Some("JVM".to_string()));
+        assert_eq!(info.framework, Some("Pedestal".to_string()));
+        assert_eq!(info.framework_version, Some("0.6.3".to_string()));
     }
-    None
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::TempDir;
+    #[test]
+    fn test_detect_clojure_leiningen_project() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("project.clj"),
+            r#"(defproject com.example/guestbook "0.1.0-SNAPSHOT"
+  :dependencies [[org.clojure/clojure "1.10.3"]
+                 [luminus-undertow "0.1.14"]
+                 [ring "1.9.6"]]
+  :profiles {:dev {:dependencies [[ring/ring-mock "0.4.0"]]}})"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.language, "Clojure");
+        assert_eq!(info.project_name, Some("guestbook".to_string()));
+        assert_eq!(info.language_version, Some("1.10.3".to_string()));
+        assert_eq!(info.framework, Some("Luminus".to_string()));
+        assert_eq!(info.framework_version, Some("0.1.14".to_string()));
+
+        let library_dir = TempDir::new().unwrap();
+        fs::write(
+            library_dir.path().join("project.clj"),
+            r#"(defproject com.example/middleware "0.1.0"
+  :dependencies [[org.clojure/clojure "1.11.1"]]
+  :profiles {:dev {:dependencies [[ring/ring-mock "0.4.0"]]}})"#,
+        )
+        .unwrap();
+        let info = detect_project_info(library_dir.path()).unwrap();
+        assert!(info.framework.is_none());
+    }
 
     #[test]
-    fn test_detect_rust_project() {
+    fn test_detect_clojurescript_and_babashka_runtimes() {
+        let shadow_dir = TempDir::new().unwrap();
+        fs::write(
+            shadow_dir.path().join("shadow-cljs.edn"),
+            r#"{:source-paths ["src"]
+ :dependencies [[re-frame "1.4.2"] [reagent "1.2.0"]]
+ :builds {:app {:target :browser}}}"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(shadow_dir.path()).unwrap();
+        assert_eq!(info.language, "Clojure");
+        assert_eq!(info.runtime, Some("ClojureScript".to_string()));
+        assert_eq!(info.framework, Some("re-frame".to_string()));
+        assert_eq!(info.framework_version, Some("1.4.2".to_string()));
+
+        let cljs_dir = TempDir::new().unwrap();
+        fs::write(
+            cljs_dir.path().join("deps.edn"),
+            r#"{:deps {org.clojure/clojurescript {:mvn/version "1.11.132"}}}"#,
+        )
+        .unwrap();
+        let info = detect_project_info(cljs_dir.path()).unwrap();
+        assert!(info.language_version.is_none());
+        assert_eq!(info.runtime, Some("ClojureScript".to_string()));
+        assert_eq!(info.runtime_version, Some("1.11.132".to_string()));
+
+        let bb_dir = TempDir::new().unwrap();
+        fs::write(
+            bb_dir.path().join("bb.edn"),
+            r#"{:min-bb-version "1.3.0" :tasks {test (shell "echo hi")}}"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(bb_dir.path()).unwrap();
+        assert_eq!(info.runtime, Some("Babashka".to_string()));
+        assert_eq!(info.runtime_version, Some("1.3.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_poetry_project() {
         let temp_dir = TempDir::new().unwrap();
         fs::write(
-            temp_dir.path().join("Cargo.toml"),
-            r#"[package]
-name = "test-project"
+            temp_dir.path().join("pyproject.toml"),
+            r#"[tool.poetry]
+name = "poetry-api"
 version = "0.1.0"
-rust-version = "1.70"
 
-[dependencies]
-axum = "0.7"
+[tool.poetry.dependencies]
+python = "^3.11"
+fastapi = { version = "^0.110.0", extras = ["all"] }
+
+[tool.poetry.group.dev.dependencies]
+pytest = "^8.0"
 "#,
         )
         .unwrap();
 
         let info = detect_project_info(temp_dir.path()).unwrap();
-        assert_eq!(info.language, "Rust");
-        assert_eq!(info.project_name, Some("test-project".to_string()));
-        assert_eq!(info.language_version, Some("1.70".to_string()));
-        assert_eq!(info.framework, Some("Axum".to_string()));
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.project_name, Some("poetry-api".to_string()));
+        assert_eq!(info.language_version, Some("^3.11".to_string()));
+        assert_eq!(
+            info.language_version_source,
+            Some("pyproject.toml".to_string())
+        );
+        assert_eq!(info.language_status, LanguageStatus::Supported);
+        assert_eq!(info.framework, Some("FastAPI".to_string()));
+        assert_eq!(info.framework_version, Some("0.110.0".to_string()));
     }
 
     #[test]
-    fn test_detect_javascript_project() {
-        let temp_dir = TempDir::new().unwrap();
+    fn test_detect_setuptools_projects() {
+        let cfg_dir = TempDir::new().unwrap();
         fs::write(
-            temp_dir.path().join("package.json"),
-            r#"{
-  "name": "my-react-app",
-  "version": "1.0.0",
-  "engines": {
-    "node": "18.0.0"
-  },
-  "dependencies": {
-    "react": "^18.2.0"
-  }
-}"#,
+            cfg_dir.path().join("setup.cfg"),
+            r#"[metadata]
+name = legacy-service
+
+[options]
+python_requires = >=3.8
+install_requires =
+    requests>=2.31
+    flask==2.3.3
+"#,
         )
         .unwrap();
 
-        let info = detect_project_info(temp_dir.path()).unwrap();
-        assert_eq!(info.language, "JavaScript");
-        assert_eq!(info.project_name, Some("my-react-app".to_string()));
-        assert_eq!(info.language_version, Some("18.0.0".to_string()));
-        assert_eq!(info.framework, Some("React".to_string()));
+        let info = detect_project_info(cfg_dir.path()).unwrap();
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.project_name, Some("legacy-service".to_string()));
+        assert_eq!(info.language_version, Some(">=3.8".to_string()));
+        assert_eq!(info.language_version_source, Some("setup.cfg".to_string()));
+        assert_eq!(info.framework, Some("Flask".to_string()));
+        assert_eq!(info.framework_version, Some("2.3.3".to_string()));
+
+        let py_dir = TempDir::new().unwrap();
+        fs::write(
+            py_dir.path().join("setup.py"),
+            r#"from setuptools import setup
+
+setup(
+    name="old-django-site",
+    python_requires=">=3.7",
+    install_requires=[
+        "Django>=3.2",
+        'psycopg2',
+    ],
+)
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(py_dir.path()).unwrap();
+        assert_eq!(info.project_name, Some("old-django-site".to_string()));
+        assert_eq!(info.language_version, Some(">=3.7".to_string()));
+        assert_eq!(info.framework, Some("Django".to_string()));
+        assert_eq!(info.framework_version, Some("3.2".to_string()));
     }
 
     #[test]
-    fn test_detect_go_project() {
+    fn test_detect_pipfile_and_conda_projects() {
+        let pipenv_dir = TempDir::new().unwrap();
+        fs::write(
+            pipenv_dir.path().join("Pipfile"),
+            r#"[packages]
+flask = "==3.0.2"
+requests = "*"
+
+[requires]
+python_version = "3.10"
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(pipenv_dir.path()).unwrap();
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.language_version, Some("3.10".to_string()));
+        assert_eq!(info.language_version_source, Some("Pipfile".to_string()));
+        assert_eq!(info.framework, Some("Flask".to_string()));
+        assert_eq!(info.framework_version, Some("3.0.2".to_string()));
+
+        let conda_dir = TempDir::new().unwrap();
+        fs::write(
+            conda_dir.path().join("environment.yml"),
+            r#"name: research-env
+channels:
+  - conda-forge
+dependencies:
+  - python=3.11
+  - conda-forge::numpy>=1.26
+  - pip
+  - pip:
+    - fastapi==0.109.0
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(conda_dir.path()).unwrap();
+        assert_eq!(info.language, "Python");
+        assert_eq!(info.project_name, Some("research-env".to_string()));
+        assert_eq!(info.language_version, Some("3.11".to_string()));
+        assert_eq!(
+            info.language_version_source,
+            Some("environment.yml".to_string())
+        );
+        assert_eq!(info.framework, Some("FastAPI".to_string()));
+    }
+
+    #[test]
+    fn test_split_conda_spec() {
+        assert_eq!(
+            split_conda_spec("python=3.11"),
+            ("python".to_string(), Some("3.11".to_string()))
+        );
+        assert_eq!(
+            split_conda_spec("conda-forge::numpy>=1.26"),
+            ("numpy".to_string(), Some(">=1.26".to_string()))
+        );
+        assert_eq!(
+            split_conda_spec("pandas=2.1.*=py311_0"),
+            ("pandas".to_string(), Some("2.1".to_string()))
+        );
+        assert_eq!(split_conda_spec("pip"), ("pip".to_string(), None));
+    }
+
+    #[test]
+    fn test_detect_scala_sbt_project() {
         let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("project")).unwrap();
         fs::write(
-            temp_dir.path().join("go.mod"),
-            r#"module github.com/example/my-go-app
+            temp_dir.path().join("build.sbt"),
+            r#"val scala213 = "2.13.12"
+val scala3 = "3.3.1"
+val http4sVersion = "0.23.23"
 
-go 1.21
+ThisBuild / scalaVersion := scala3
+ThisBuild / crossScalaVersions := Seq(scala213, scala3)
 
-require github.com/gin-gonic/gin v1.9.0
+lazy val root = (project in file("."))
+  .settings(
+    name := "orders-service",
+    libraryDependencies ++= Seq(
+      "org.http4s" %% "http4s-ember-server" % http4sVersion,
+      "org.typelevel" %% "cats-effect" % "3.5.2"
+    )
+  )
 "#,
         )
         .unwrap();
 
         let info = detect_project_info(temp_dir.path()).unwrap();
-        assert_eq!(info.language, "Go");
-        assert_eq!(info.project_name, Some("my-go-app".to_string()));
-        assert_eq!(info.language_version, Some("1.21".to_string()));
-        assert_eq!(info.framework, Some("Gin".to_string()));
+        assert_eq!(info.language, "Scala");
+        assert_eq!(info.project_name, Some("orders-service".to_string()));
+        assert_eq!(info.language_version, Some("3.3.1".to_string()));
+        assert_eq!(info.language_status, LanguageStatus::Supported);
+        assert_eq!(info.cross_versions, vec!["2.13.12", "3.3.1"]);
+        assert_eq!(info.framework, Some("http4s".to_string()));
+        assert_eq!(info.framework_version, Some("0.23.23".to_string()));
+
+        // Play's JSON library doesn't make an http4s service a Play app
+        let build_content = fs::read_to_string(temp_dir.path().join("build.sbt")).unwrap();
+        fs::write(
+            temp_dir.path().join("build.sbt"),
+            build_content.replace(
+                "\"org.typelevel\"",
+                "\"com.typesafe.play\" %% \"play-json\" % \"2.10.4\",\n      \"org.typelevel\"",
+            ),
+        )
+        .unwrap();
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("http4s".to_string()));
+
+        fs::write(
+            temp_dir.path().join("project/plugins.sbt"),
+            r#"addSbtPlugin("com.typesafe.play" % "sbt-plugin" % "2.9.0")"#,
+        )
+        .unwrap();
+        let info = detect_project_info(temp_dir.path()).unwrap();
+        assert_eq!(info.framework, Some("Play".to_string()));
+        assert_eq!(info.framework_version, Some("2.9.0".to_string()));
     }
 
     #[test]
-    fn test_detect_python_project() {
+    fn test_detect_scala_mill_project() {
         let temp_dir = TempDir::new().unwrap();
         fs::write(
-            temp_dir.path().join("pyproject.toml"),
-            r#"[project]
-name = "my-django-app"
-requires-python = ">=3.11"
-dependencies = [
-    "django>=4.2.0"
-]
+            temp_dir.path().join("build.sc"),
+            r#"import mill._, scalalib._
+
+object core extends Cross[CoreModule]("2.12.18", "2.13.12")
+trait CoreModule extends CrossScalaModule {
+  def ivyDeps = Agg(
+    ivy"org.apache.spark::spark-sql:3.5.0",
+    ivy"com.lihaoyi::os-lib:0.9.1"
+  )
+}
 "#,
         )
         .unwrap();
 
         let info = detect_project_info(temp_dir.path()).unwrap();
-        assert_eq!(info.language, "Python");
-        assert_eq!(info.project_name, Some("my-django-app".to_string()));
-        assert_eq!(info.language_version, Some(">=3.11".to_string()));
-        assert_eq!(info.framework, Some("Django".to_string()));
+        assert_eq!(info.language, "Scala");
+        assert_eq!(info.cross_versions, vec!["2.12.18", "2.13.12"]);
+        assert_eq!(info.language_version, Some("2.13.12".to_string()));
+        assert_eq!(info.framework, Some("Spark".to_string()));
+        assert_eq!(info.framework_version, Some("3.5.0".to_string()));
     }
 
     #[test]
-    fn test_detect_language_by_files() {
-        let temp_dir = TempDir::new().unwrap();
-        fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
-        fs::write(temp_dir.path().join("lib.rs"), "pub fn hello() {}").unwrap();
+    fn test_detect_zig_and_nim_projects() {
+        let zig_dir = TempDir::new().unwrap();
+        fs::write(
+            zig_dir.path().join("build.zig.zon"),
+            r#".{
+    .name = "zap-server",
+    .version = "0.1.0",
+    .minimum_zig_version = "0.12.0",
+    .dependencies = .{},
+}"#,
+        )
+        .unwrap();
 
-        let language = detect_language(temp_dir.path()).unwrap();
-        assert_eq!(language, "Rust");
+        let info = detect_project_info(zig_dir.path()).unwrap();
+        assert_eq!(info.language, "Zig");
+        assert_eq!(info.project_name, Some("zap-server".to_string()));
+        assert_eq!(info.language_version, Some("0.12.0".to_string()));
+
+        let nim_dir = TempDir::new().unwrap();
+        fs::write(
+            nim_dir.path().join("webapp.nimble"),
+            r#"version = "0.1.0"
+requires "nim >= 1.6.0", "jester >= 0.5.0"
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(nim_dir.path()).unwrap();
+        assert_eq!(info.language, "Nim");
+        assert_eq!(info.project_name, Some("webapp".to_string()));
+        assert_eq!(info.language_version, Some(">= 1.6.0".to_string()));
     }
 
     #[test]
-    fn test_nonexistent_path() {
-        let result = detect_project_info(Path::new("/definitely/does/not/exist"));
-        assert!(result.is_err());
+    fn test_detect_crystal_and_lua_projects() {
+        let crystal_dir = TempDir::new().unwrap();
+        fs::write(
+            crystal_dir.path().join("shard.yml"),
+            "name: kemal-app\nversion: 0.1.0\ncrystal: '>= 1.9.0'\ndependencies:\n  kemal:\n    github: kemalcr/kemal\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(crystal_dir.path()).unwrap();
+        assert_eq!(info.language, "Crystal");
+        assert_eq!(info.project_name, Some("kemal-app".to_string()));
+        assert_eq!(info.language_version, Some(">= 1.9.0".to_string()));
+
+        let lua_dir = TempDir::new().unwrap();
+        fs::write(
+            lua_dir.path().join("luasocket-3.1.0-1.rockspec"),
+            r#"package = "luasocket"
+version = "3.1.0-1"
+dependencies = {
+   "lua >= 5.1"
+}
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(lua_dir.path()).unwrap();
+        assert_eq!(info.language, "Lua");
+        assert_eq!(info.project_name, Some("luasocket".to_string()));
+        assert_eq!(info.language_version, Some(">= 5.1".to_string()));
+    }
+
+    #[test]
+    fn test_detect_ocaml_project() {
+        let dune_dir = TempDir::new().unwrap();
+        fs::write(
+            dune_dir.path().join("dune-project"),
+            r#"(lang dune 3.0)
+(name mylib)
+; comment
+(package
+ (name mylib)
+ (depends
+  (ocaml (>= 4.14))
+  (alcotest :with-test)))"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(dune_dir.path()).unwrap();
+        assert_eq!(info.language, "OCaml");
+        assert_eq!(info.project_name, Some("mylib".to_string()));
+        assert_eq!(info.language_version, Some(">= 4.14".to_string()));
+
+        let opam_dir = TempDir::new().unwrap();
+        fs::write(
+            opam_dir.path().join("parser.opam"),
+            r#"opam-version: "2.0"
+depends: [
+  "ocaml" {>= "4.08.0"}
+  "dune" {>= "2.0"}
+]"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(opam_dir.path()).unwrap();
+        assert_eq!(info.language, "OCaml");
+        assert_eq!(info.project_name, Some("parser".to_string()));
+        assert_eq!(info.language_version, Some(">= 4.08.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_r_and_julia_projects() {
+        let r_dir = TempDir::new().unwrap();
+        fs::write(
+            r_dir.path().join("DESCRIPTION"),
+            "Package: tidyhelpers\nVersion: 0.1.0\nDepends:\n    R (>= 4.1.0),\n    methods\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(r_dir.path()).unwrap();
+        assert_eq!(info.language, "R");
+        assert_eq!(info.project_name, Some("tidyhelpers".to_string()));
+        assert_eq!(info.language_version, Some(">= 4.1.0".to_string()));
+
+        fs::write(
+            r_dir.path().join("renv.lock"),
+            r#"{"R": {"Version": "4.3.1"}, "Packages": {}}"#,
+        )
+        .unwrap();
+        let info = detect_project_info(r_dir.path()).unwrap();
+        assert_eq!(info.language_version, Some("4.3.1".to_string()));
+
+        let julia_dir = TempDir::new().unwrap();
+        fs::write(
+            julia_dir.path().join("Project.toml"),
+            r#"name = "Flux"
+uuid = "587475ba-b771-5e3f-ad9e-33799f191a9c"
+
+[compat]
+julia = "1.9"
+"#,
+        )
+        .unwrap();
+
+        let info = detect_project_info(julia_dir.path()).unwrap();
+        assert_eq!(info.language, "Julia");
+        assert_eq!(info.project_name, Some("Flux".to_string()));
+        assert_eq!(info.language_version, Some("1.9".to_string()));
+
+        fs::write(
+            julia_dir.path().join("Manifest.toml"),
+            "julia_version = \"1.10.2\"\nmanifest_format = \"2.0\"\n",
+        )
+        .unwrap();
+        let info = detect_project_info(julia_dir.path()).unwrap();
+        assert_eq!(info.language_version, Some("1.10.2".to_string()));
     }
 
     #[test]
-    fn test_extract_version_from_requirement() {
+    fn test_tool_versions_fill_in_and_conflict() {
+        let java_dir = TempDir::new().unwrap();
+        fs::write(java_dir.path().join("pom.xml"), "<project></project>").unwrap();
+        fs::write(
+            java_dir.path().join(".tool-versions"),
+            "java temurin-21.0.2+13\nmaven 3.9.6\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(java_dir.path()).unwrap();
+        assert_eq!(info.language, "Java");
+        assert_eq!(info.language_version, Some("21.0.2+13".to_string()));
         assert_eq!(
-            extract_version_from_requirement("django==4.2.0"),
-            Some("4.2.0".to_string())
+            info.language_version_source,
+            Some(".tool-versions".to_string())
         );
+        assert_eq!(info.language_status, LanguageStatus::Supported);
+        assert_eq!(info.tool_versions.len(), 2);
+        assert!(info.version_conflicts.is_empty());
+
+        let python_dir = TempDir::new().unwrap();
+        fs::write(python_dir.path().join("requirements.txt"), "flask\n").unwrap();
+        fs::write(python_dir.path().join(".python-version"), "3.12.1\n").unwrap();
+        fs::write(python_dir.path().join(".tool-versions"), "python 3.11.7\n").unwrap();
+
+        let info = detect_project_info(python_dir.path()).unwrap();
+        assert_eq!(info.language_version, Some("3.12.1".to_string()));
+        assert_eq!(info.version_conflicts.len(), 1);
+        assert_eq!(info.version_conflicts[0].conflicting_version, "3.11.7");
+        assert_eq!(
+            info.version_conflicts[0].conflicting_source,
+            ".tool-versions"
+        );
+        // Reported once, not again as a version consistency conflict
+        assert!(info.version_consistency.is_empty());
+    }
+
+    #[test]
+    fn test_detect_version_consistency() {
+        let node_dir = TempDir::new().unwrap();
+        fs::write(
+            node_dir.path().join("package.json"),
+            r#"{"name": "api", "engines": {"node": ">=16"}}"#,
+        )
+        .unwrap();
+        fs::write(node_dir.path().join(".nvmrc"), "18\n").unwrap();
+        fs::write(
+            node_dir.path().join("Dockerfile"),
+            "FROM node:20-alpine AS build\nFROM node:20-alpine\n",
+        )
+        .unwrap();
+        fs::create_dir_all(node_dir.path().join(".github/workflows")).unwrap();
+        fs::write(
+            node_dir.path().join(".github/workflows/ci.yml"),
+            "jobs:\n  test:\n    strategy:\n      matrix:\n        node: [16, 18, 20]\n    steps:\n      - uses: actions/setup-node@v4\n        with:\n          node-version: ${{ matrix.node }}\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(node_dir.path()).unwrap();
+        assert_eq!(info.version_consistency.len(), 1);
+        let node_consistency = &info.version_consistency[0];
+        assert_eq!(node_consistency.language, "JavaScript");
+        assert_eq!(node_consistency.declared_versions.len(), 3);
+        assert_eq!(node_consistency.tested_versions.len(), 3);
+        assert_eq!(node_consistency.conflicts.len(), 1);
         assert_eq!(
-            extract_version_from_requirement("flask>=2.0.0"),
-            Some("2.0.0".to_string())
+            node_consistency.conflicts[0].conflicting_source,
+            "Dockerfile"
         );
+
+        let rust_dir = TempDir::new().unwrap();
+        fs::write(
+            rust_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\nrust-version = \"1.75\"\n",
+        )
+        .unwrap();
+        fs::write(
+            rust_dir.path().join("rust-toolchain.toml"),
+            "[toolchain]\nchannel = \"1.72.0\"\n",
+        )
+        .unwrap();
+
+        let info = detect_project_info(rust_dir.path()).unwrap();
+        let rust_consistency = &info.version_consistency[0];
+        assert_eq!(rust_consistency.declared_versions[0].version, ">=1.75");
+        assert_eq!(rust_consistency.conflicts.len(), 1);
         assert_eq!(
-            extract_version_from_requirement("fastapi~=0.68.0"),
-            Some("0.68.0".to_string())
+            rust_consistency.conflicts[0].conflicting_source,
+            "rust-toolchain.toml"
         );
-        assert_eq!(extract_version_from_requirement("requests"), None);
+    }
+
+    #[test]
+    fn test_detect_language_by_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+        fs::write(temp_dir.path().join("lib.rs"), "pub fn hello() {}").unwrap();
+
+        let language = detect_language(temp_dir.path(), &[]).unwrap();
+        assert_eq!(language, "Rust");
+    }
+
+    #[test]
+    fn test_nonexistent_path() {
+        let result = detect_project_info(Path::new("/definitely/does/not/exist"));
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,299 @@
+//! Dockerfile and Containerfile analysis.
+//!
+//! Each `FROM` starts a build stage. Base images are resolved against the
+//! global `ARG` defaults declared before the first `FROM`, and a stage built
+//! on an earlier stage inherits that stage's runtime. The runtime version an
+//! official language image ships is looked up in the End of Life tables, so
+//! an outdated runtime baked into an image is flagged like any other.
+
+use crate::container_images::image_language_version;
+use crate::languages;
+use crate::project_info::{BuildStage, Dockerfile, ImageRuntime};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Directories searched for Dockerfiles, relative to the project root
+const DOCKERFILE_DIRECTORIES: [&str; 3] = ["", "docker", ".devcontainer"];
+
+pub fn detect_dockerfiles(project_path: &Path) -> Vec<Dockerfile> {
+    let mut dockerfiles = Vec::new();
+
+    for dockerfile_directory in DOCKERFILE_DIRECTORIES {
+        let Ok(directory_entries) = fs::read_dir(project_path.join(dockerfile_directory)) else {
+            continue;
+        };
+        let mut dockerfile_names: Vec<String> = directory_entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|file_name| is_dockerfile_name(file_name))
+            .collect();
+        dockerfile_names.sort();
+
+        for dockerfile_name in dockerfile_names {
+            let dockerfile_path = if dockerfile_directory.is_empty() {
+                dockerfile_name
+            } else {
+                format!("{}/{}", dockerfile_directory, dockerfile_name)
+            };
+            let Ok(dockerfile_content) = fs::read_to_string(project_path.join(&dockerfile_path))
+            else {
+                continue;
+            };
+            let stages = parse_dockerfile(&dockerfile_content);
+            if !stages.is_empty() {
+                dockerfiles.push(Dockerfile {
+                    path: dockerfile_path,
+                    stages,
+                });
+            }
+        }
+    }
+
+    dockerfiles
+}
+
+/// `Dockerfile`, `Containerfile`, `Dockerfile.prod` and `api.Dockerfile`
+fn is_dockerfile_name(file_name: &str) -> bool {
+    let file_name = file_name.to_lowercase();
+    if file_name.ends_with(".dockerignore") {
+        return false;
+    }
+    ["dockerfile", "containerfile"].iter().any(|base_name| {
+        file_name == *base_name
+            || file_name.starts_with(&format!("{}.", base_name))
+            || file_name.ends_with(&format!(".{}", base_name))
+    })
+}
+
+pub fn parse_dockerfile(dockerfile_content: &str) -> Vec<BuildStage> {
+    let mut global_args: HashMap<String, String> = HashMap::new();
+    let mut stages: Vec<BuildStage> = Vec::new();
+
+    for (instruction, arguments) in read_instructions(dockerfile_content) {
+        match instruction.as_str() {
+            // Only args declared before the first FROM are in scope for FROM lines
+            "ARG" if stages.is_empty() => {
+                for arg_declaration in arguments.split_whitespace() {
+                    if let Some((arg_name, default_value)) = arg_declaration.split_once('=') {
+                        global_args.insert(
+                            arg_name.to_string(),
+                            default_value.trim_matches(['"', '\'']).to_string(),
+                        );
+                    }
+                }
+            }
+            "FROM" => {
+                let from_arguments: Vec<&str> = arguments
+                    .split_whitespace()
+                    .filter(|from_argument| !from_argument.starts_with("--"))
+                    .collect();
+                let Some(image_argument) = from_arguments.first() else {
+                    continue;
+                };
+                let base_image = substitute_args(image_argument, &global_args);
+                let stage_name = match from_arguments.as_slice() {
+                    [_, as_keyword, stage_name, ..] if as_keyword.eq_ignore_ascii_case("as") => {
+                        Some(stage_name.to_string())
+                    }
+                    _ => None,
+                };
+
+                let runtime = match stages.iter().find(|earlier_stage| {
+                    earlier_stage
+                        .name
+                        .as_deref()
+                        .is_some_and(|earlier_name| earlier_name.eq_ignore_ascii_case(&base_image))
+                }) {
+                    Some(earlier_stage) => earlier_stage.runtime.clone(),
+                    None => image_runtime(&base_image),
+                };
+
+                stages.push(BuildStage {
+                    name: stage_name,
+                    base_image,
+                    runtime,
+                    exposed_ports: Vec::new(),
+                    entrypoint: None,
+                    cmd: None,
+                });
+            }
+            "EXPOSE" => {
+                if let Some(current_stage) = stages.last_mut() {
+                    current_stage
+                        .exposed_ports
+                        .extend(arguments.split_whitespace().map(|port| port.to_string()));
+                }
+            }
+            "ENTRYPOINT" => {
+                if let Some(current_stage) = stages.last_mut() {
+                    current_stage.entrypoint = Some(command_line(&arguments));
+                }
+            }
+            "CMD" => {
+                if let Some(current_stage) = stages.last_mut() {
+                    current_stage.cmd = Some(command_line(&arguments));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    stages
+}
+
+fn image_runtime(base_image: &str) -> Option<ImageRuntime> {
+    let (language, version) = image_language_version(base_image)?;
+    Some(ImageRuntime {
+        language: language.to_string(),
+        status: languages::get_language_status(language, &version),
+        version,
+    })
+}
+
+/// Instruction keywords (uppercased) and their arguments, with `\` continuations
+/// joined and comments dropped
+fn read_instructions(dockerfile_content: &str) -> Vec<(String, String)> {
+    let mut instructions = Vec::new();
+    let mut logical_line = String::new();
+
+    for content_line in dockerfile_content.lines() {
+        let trimmed_line = content_line.trim();
+        if trimmed_line.starts_with('#') || (trimmed_line.is_empty() && logical_line.is_empty()) {
+            continue;
+        }
+        if let Some(continued_line) = trimmed_line.strip_suffix('\\') {
+            logical_line.push_str(continued_line);
+            logical_line.push(' ');
+            continue;
+        }
+        logical_line.push_str(trimmed_line);
+
+        let (instruction, arguments) = logical_line
+            .split_once(char::is_whitespace)
+            .unwrap_or((logical_line.as_str(), ""));
+        instructions.push((instruction.to_uppercase(), arguments.trim().to_string()));
+        logical_line.clear();
+    }
+
+    instructions
+}
+
+/// `${PYTHON_VERSION}`, `$PYTHON_VERSION` and `${PYTHON_VERSION:-3.11}`; unknown
+/// args are left in place
+fn substitute_args(image_argument: &str, global_args: &HashMap<String, String>) -> String {
+    let arg_regex = regex::Regex::new(
+        r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-([^}]*))?\}|\$([A-Za-z_][A-Za-z0-9_]*)",
+    )
+    .unwrap();
+
+    arg_regex
+        .replace_all(image_argument, |captures: &regex::Captures| {
+            let arg_name = captures
+                .get(1)
+                .or_else(|| captures.get(3))
+                .map_or("", |arg_name| arg_name.as_str());
+            global_args
+                .get(arg_name)
+                .filter(|arg_value| !arg_value.is_empty())
+                .map(|arg_value| arg_value.as_str())
+                .or_else(|| captures.get(2).map(|default_value| default_value.as_str()))
+                .unwrap_or(&captures[0])
+                .to_string()
+        })
+        .to_string()
+}
+
+/// The exec form `["python", "app.py"]` becomes `python app.py`; the shell form is kept
+fn command_line(arguments: &str) -> String {
+    serde_json::from_str::<Vec<String>>(arguments)
+        .map(|exec_arguments| exec_arguments.join(" "))
+        .unwrap_or_else(|_| arguments.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_info::LanguageStatus;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_multi_stage_dockerfile() {
+        let dockerfile_content = r#"
+# syntax=docker/dockerfile:1
+ARG GO_VERSION=1.19
+FROM --platform=$BUILDPLATFORM golang:${GO_VERSION}-alpine AS build
+WORKDIR /src
+RUN go build \
+    -o /out/server ./cmd/server
+
+FROM build AS test
+RUN go test ./...
+
+FROM gcr.io/distroless/static-debian12
+COPY --from=build /out/server /server
+EXPOSE 8080 9090/tcp
+ENTRYPOINT ["/server"]
+CMD ["--port", "8080"]
+"#;
+        let stages = parse_dockerfile(dockerfile_content);
+        assert_eq!(stages.len(), 3);
+
+        assert_eq!(stages[0].name, Some("build".to_string()));
+        assert_eq!(stages[0].base_image, "golang:1.19-alpine");
+        let go_runtime = stages[0].runtime.as_ref().unwrap();
+        assert_eq!(go_runtime.language, "Go");
+        assert_eq!(go_runtime.version, "1.19");
+        assert!(matches!(
+            go_runtime.status,
+            LanguageStatus::EndOfLife { .. }
+        ));
+
+        assert_eq!(stages[1].base_image, "build");
+        assert_eq!(stages[1].runtime, stages[0].runtime);
+
+        assert!(stages[2].name.is_none());
+        assert!(stages[2].runtime.is_none());
+        assert_eq!(stages[2].exposed_ports, vec!["8080", "9090/tcp"]);
+        assert_eq!(stages[2].entrypoint, Some("/server".to_string()));
+        assert_eq!(stages[2].cmd, Some("--port 8080".to_string()));
+    }
+
+    #[test]
+    fn test_detect_dockerfiles() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::write(
+            project_path.join("Dockerfile"),
+            "FROM python:3.8-slim\nCMD python app.py\n",
+        )
+        .unwrap();
+        fs::write(project_path.join("Dockerfile.dockerignore"), "target\n").unwrap();
+        fs::create_dir_all(project_path.join("docker")).unwrap();
+        fs::write(
+            project_path.join("docker/web.Dockerfile"),
+            "FROM node:20-alpine\n",
+        )
+        .unwrap();
+
+        let dockerfiles = detect_dockerfiles(project_path);
+        let dockerfile_paths: Vec<&str> = dockerfiles
+            .iter()
+            .map(|dockerfile| dockerfile.path.as_str())
+            .collect();
+        assert_eq!(
+            dockerfile_paths,
+            vec!["Dockerfile", "docker/web.Dockerfile"]
+        );
+
+        let python_stage = dockerfiles[0].final_stage().unwrap();
+        assert_eq!(python_stage.cmd, Some("python app.py".to_string()));
+        let python_runtime = python_stage.runtime.as_ref().unwrap();
+        assert_eq!(python_runtime.version, "3.8");
+        assert!(matches!(
+            python_runtime.status,
+            LanguageStatus::EndOfLife { .. }
+        ));
+    }
+}
@@ -0,0 +1,412 @@
+//! Minimal EDN reader used for Clojure project files.
+//!
+//! Handles the subset of EDN (and the Clojure reader syntax that shows up in
+//! `project.clj`) needed to pull coordinates and settings out of `deps.edn`,
+//! `shadow-cljs.edn`, `bb.edn` and Leiningen project files. Plain
+//! s-expression files like OCaml's `dune-project` read fine too.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A parsed EDN value
+#[derive(Debug, Clone, PartialEq)]
+pub enum EdnValue {
+    Nil,
+    Bool(bool),
+    String(String),
+    Char(char),
+    Number(String),
+    Keyword(String),
+    Symbol(String),
+    List(Vec<EdnValue>),
+    Vector(Vec<EdnValue>),
+    Set(Vec<EdnValue>),
+    Map(Vec<(EdnValue, EdnValue)>),
+    Tagged(String, Box<EdnValue>),
+}
+
+impl EdnValue {
+    /// Look up a keyword key (without the leading colon) in a map
+    pub fn get(&self, keyword: &str) -> Option<&EdnValue> {
+        match self {
+            EdnValue::Map(entries) => entries.iter().find_map(|(key, value)| match key {
+                EdnValue::Keyword(name) if name == keyword => Some(value),
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// String contents, if this is a string
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            EdnValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Symbol name, if this is a symbol
+    pub fn as_symbol(&self) -> Option<&str> {
+        match self {
+            EdnValue::Symbol(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Elements of a list, vector or set
+    pub fn as_seq(&self) -> Option<&[EdnValue]> {
+        match self {
+            EdnValue::List(items) | EdnValue::Vector(items) | EdnValue::Set(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Entries of a map
+    pub fn as_map(&self) -> Option<&[(EdnValue, EdnValue)]> {
+        match self {
+            EdnValue::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+}
+
+/// Parse every top-level form in the input
+pub fn parse(input: &str) -> Result<Vec<EdnValue>, String> {
+    let mut reader = EdnReader {
+        characters: input.chars().peekable(),
+    };
+    let mut forms = Vec::new();
+    while let Some(form) = reader.read_form()? {
+        forms.push(form);
+    }
+    Ok(forms)
+}
+
+/// Parse the first top-level form in the input
+pub fn parse_first(input: &str) -> Option<EdnValue> {
+    parse(input).ok()?.into_iter().next()
+}
+
+struct EdnReader<'a> {
+    characters: Peekable<Chars<'a>>,
+}
+
+impl EdnReader<'_> {
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&next_character) = self.characters.peek() {
+            if next_character.is_whitespace() || next_character == ',' {
+                self.characters.next();
+            } else if next_character == ';' {
+                for comment_character in self.characters.by_ref() {
+                    if comment_character == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Read the next form, returning `None` at end of input
+    fn read_form(&mut self) -> Result<Option<EdnValue>, String> {
+        let mut skipped_form = false;
+        loop {
+            self.skip_whitespace_and_comments();
+            let Some(&next_character) = self.characters.peek() else {
+                return Ok(None);
+            };
+
+            let form = match next_character {
+                '(' => {
+                    self.characters.next();
+                    EdnValue::List(self.read_sequence(')')?)
+                }
+                '[' => {
+                    self.characters.next();
+                    EdnValue::Vector(self.read_sequence(']')?)
+                }
+                '{' => {
+                    self.characters.next();
+                    self.read_map()?
+                }
+                // A discarded form may sit right before the end of a collection
+                ')' | ']' | '}' if skipped_form => return Ok(None),
+                ')' | ']' | '}' => {
+                    return Err(format!("Unexpected closing delimiter '{}'", next_character))
+                }
+                '"' => {
+                    self.characters.next();
+                    EdnValue::String(self.read_string()?)
+                }
+                '\\' => {
+                    self.characters.next();
+                    self.read_character()
+                }
+                '#' => {
+                    self.characters.next();
+                    match self.read_dispatch()? {
+                        Some(form) => form,
+                        None => {
+                            skipped_form = true;
+                            continue;
+                        }
+                    }
+                }
+                // Quoting and deref only appear in project.clj; keep the quoted form
+                '\'' | '`' | '@' => {
+                    self.characters.next();
+                    return self.read_required_form();
+                }
+                '~' => {
+                    self.characters.next();
+                    if self.characters.peek() == Some(&'@') {
+                        self.characters.next();
+                    }
+                    return self.read_required_form();
+                }
+                '^' => {
+                    self.characters.next();
+                    self.read_required_form()?;
+                    skipped_form = true;
+                    continue;
+                }
+                _ => self.read_atom(),
+            };
+            return Ok(Some(form));
+        }
+    }
+
+    fn read_required_form(&mut self) -> Result<Option<EdnValue>, String> {
+        match self.read_form()? {
+            Some(form) => Ok(Some(form)),
+            None => Err("Unexpected end of input".to_string()),
+        }
+    }
+
+    fn read_sequence(&mut self, closing_delimiter: char) -> Result<Vec<EdnValue>, String> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            match self.characters.peek() {
+                Some(&character) if character == closing_delimiter => {
+                    self.characters.next();
+                    return Ok(items);
+                }
+                Some(_) => {
+                    if let Some(form) = self.read_form()? {
+                        items.push(form);
+                    }
+                }
+                None => return Err(format!("Missing closing '{}'", closing_delimiter)),
+            }
+        }
+    }
+
+    fn read_map(&mut self) -> Result<EdnValue, String> {
+        let items = self.read_sequence('}')?;
+        let mut entries = Vec::with_capacity(items.len() / 2);
+        let mut item_iterator = items.into_iter();
+        while let Some(key) = item_iterator.next() {
+            let value = item_iterator.next().unwrap_or(EdnValue::Nil);
+            entries.push((key, value));
+        }
+        Ok(EdnValue::Map(entries))
+    }
+
+    fn read_string(&mut self) -> Result<String, String> {
+        let mut text = String::new();
+        while let Some(character) = self.characters.next() {
+            match character {
+                '"' => return Ok(text),
+                '\\' => match self.characters.next() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('r') => text.push('\r'),
+                    Some(escaped) => text.push(escaped),
+                    None => break,
+                },
+                _ => text.push(character),
+            }
+        }
+        Err("Unterminated string".to_string())
+    }
+
+    fn read_character(&mut self) -> EdnValue {
+        let token = self.read_token();
+        let character = match token.as_str() {
+            "newline" => '\n',
+            "space" => ' ',
+            "tab" => '\t',
+            "return" => '\r',
+            _ => token.chars().next().unwrap_or(' '),
+        };
+        EdnValue::Char(character)
+    }
+
+    /// Handle `#` dispatch forms; `None` means the form was discarded
+    fn read_dispatch(&mut self) -> Result<Option<EdnValue>, String> {
+        match self.characters.peek() {
+            Some('{') => {
+                self.characters.next();
+                Ok(Some(EdnValue::Set(self.read_sequence('}')?)))
+            }
+            Some('_') => {
+                self.characters.next();
+                self.read_required_form()?;
+                Ok(None)
+            }
+            Some('"') => {
+                self.characters.next();
+                Ok(Some(EdnValue::String(self.read_string()?)))
+            }
+            Some('(') => {
+                self.characters.next();
+                Ok(Some(EdnValue::List(self.read_sequence(')')?)))
+            }
+            Some('?') => {
+                self.characters.next();
+                if self.characters.peek() == Some(&'@') {
+                    self.characters.next();
+                }
+                self.read_required_form()
+            }
+            Some('\'') => {
+                self.characters.next();
+                self.read_required_form()
+            }
+            Some('#') => {
+                self.characters.next();
+                Ok(Some(EdnValue::Symbol(format!("##{}", self.read_token()))))
+            }
+            _ => {
+                let tag = self.read_token();
+                let tagged_form = self.read_required_form()?.unwrap_or(EdnValue::Nil);
+                Ok(Some(EdnValue::Tagged(tag, Box::new(tagged_form))))
+            }
+        }
+    }
+
+    fn read_token(&mut self) -> String {
+        let mut token = String::new();
+        while let Some(&character) = self.characters.peek() {
+            if character.is_whitespace()
+                || matches!(
+                    character,
+                    ',' | '(' | ')' | '[' | ']' | '{' | '}' | '"' | ';'
+                )
+            {
+                break;
+            }
+            token.push(character);
+            self.characters.next();
+        }
+        token
+    }
+
+    fn read_atom(&mut self) -> EdnValue {
+        let token = self.read_token();
+        if let Some(keyword) = token.strip_prefix(':') {
+            return EdnValue::Keyword(keyword.trim_start_matches(':').to_string());
+        }
+        match token.as_str() {
+            "nil" => EdnValue::Nil,
+            "true" => EdnValue::Bool(true),
+            "false" => EdnValue::Bool(false),
+            _ if is_number_token(&token) => EdnValue::Number(token),
+            _ => EdnValue::Symbol(token),
+        }
+    }
+}
+
+fn is_number_token(token: &str) -> bool {
+    let unsigned_token = token.strip_prefix(['+', '-']).unwrap_or(token);
+    unsigned_token
+        .chars()
+        .next()
+        .is_some_and(|first_character| first_character.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scalars() {
+        let forms =
+            parse(r#"nil true 42 -1.5 "hi\n" :mvn/version org.clojure/clojure \a"#).unwrap();
+        assert_eq!(
+            forms,
+            vec![
+                EdnValue::Nil,
+                EdnValue::Bool(true),
+                EdnValue::Number("42".to_string()),
+                EdnValue::Number("-1.5".to_string()),
+                EdnValue::String("hi\n".to_string()),
+                EdnValue::Keyword("mvn/version".to_string()),
+                EdnValue::Symbol("org.clojure/clojure".to_string()),
+                EdnValue::Char('a'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_deps_edn() {
+        let deps = parse_first(
+            r#"{:paths ["src"]
+ ;; comment
+ :deps {org.clojure/clojure {:mvn/version "1.11.1"},
+        ring/ring-core {:mvn/version "1.10.0"}}
+ #_:ignored #_{:a 1}
+ :aliases {:test {:extra-deps {lambdaisland/kaocha {:mvn/version "1.87"}}}}}"#,
+        )
+        .unwrap();
+
+        let clojure_coordinate = deps
+            .get("deps")
+            .and_then(|deps_map| deps_map.as_map())
+            .and_then(|entries| entries.first())
+            .unwrap();
+        assert_eq!(
+            clojure_coordinate.0.as_symbol(),
+            Some("org.clojure/clojure")
+        );
+        assert_eq!(
+            clojure_coordinate
+                .1
+                .get("mvn/version")
+                .and_then(|v| v.as_str()),
+            Some("1.11.1")
+        );
+        assert!(deps.get("aliases").and_then(|a| a.get("test")).is_some());
+        assert!(deps.get("ignored").is_none());
+    }
+
+    #[test]
+    fn test_parse_project_clj_reader_syntax() {
+        let project = parse_first(
+            r#"(defproject com.example/app "0.1.0-SNAPSHOT"
+  :description "Example"
+  :dependencies [[org.clojure/clojure "1.11.1"] [ring "1.10.0"]]
+  :main ^:skip-aot app.core
+  :jvm-opts ~(vec ["-Xmx1g"])
+  :regex #"foo.*"
+  :set #{1 2})"#,
+        )
+        .unwrap();
+
+        let items = project.as_seq().unwrap();
+        assert_eq!(items[0].as_symbol(), Some("defproject"));
+        assert_eq!(items[1].as_symbol(), Some("com.example/app"));
+        assert_eq!(items[2].as_str(), Some("0.1.0-SNAPSHOT"));
+        assert!(items.contains(&EdnValue::Symbol("app.core".to_string())));
+        assert!(items.contains(&EdnValue::String("foo.*".to_string())));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("{:a 1").is_err());
+        assert!(parse("\"unterminated").is_err());
+        assert!(parse(")").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::project_info::FrameworkDetails;
+use crate::project_info::{FrameworkCategory, FrameworkDetails};
 
 /// Detailed information about a framework
 pub fn get_framework_details(framework_name: &str) -> Option<FrameworkDetails> {
@@ -51,6 +51,70 @@ pub fn get_framework_details(framework_name: &str) -> Option<FrameworkDetails> {
             is_popular: true,
             description: Some("Command line argument parser".to_string()),
         }),
+        "Tauri" => Some(FrameworkDetails {
+            framework_type: "Desktop Framework".to_string(),
+            alternatives: vec!["Electron".to_string(), "Dioxus".to_string()],
+            is_popular: true,
+            description: Some(
+                "Small, secure desktop and mobile apps with a web frontend".to_string(),
+            ),
+        }),
+        "Leptos" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Dioxus".to_string(), "Yew".to_string()],
+            is_popular: true,
+            description: Some("Fine-grained reactive full-stack web framework".to_string()),
+        }),
+        "Dioxus" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Leptos".to_string(), "Yew".to_string(), "Tauri".to_string()],
+            is_popular: true,
+            description: Some("React-like UI library for web, desktop and mobile".to_string()),
+        }),
+        "Yew" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec!["Leptos".to_string(), "Dioxus".to_string()],
+            is_popular: true,
+            description: Some("Component-based framework for WebAssembly front-ends".to_string()),
+        }),
+        "Tonic" => Some(FrameworkDetails {
+            framework_type: "RPC Framework".to_string(),
+            alternatives: vec!["tarpc".to_string()],
+            is_popular: true,
+            description: Some("gRPC over HTTP/2 built on Tokio and Hyper".to_string()),
+        }),
+        "Poem" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Axum".to_string(), "Salvo".to_string()],
+            is_popular: false,
+            description: Some(
+                "Full-featured web framework with built-in OpenAPI support".to_string(),
+            ),
+        }),
+        "Salvo" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Axum".to_string(), "Poem".to_string()],
+            is_popular: false,
+            description: Some("Simple yet powerful web framework with HTTP/3 support".to_string()),
+        }),
+        "Loco" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Rocket".to_string(), "Axum".to_string()],
+            is_popular: false,
+            description: Some("Rails-inspired productivity framework built on Axum".to_string()),
+        }),
+        "Embassy" => Some(FrameworkDetails {
+            framework_type: "Embedded Framework".to_string(),
+            alternatives: vec!["RTIC".to_string()],
+            is_popular: true,
+            description: Some("Async runtime and HALs for embedded devices".to_string()),
+        }),
+        "Ratatui" => Some(FrameworkDetails {
+            framework_type: "TUI Framework".to_string(),
+            alternatives: vec!["Cursive".to_string()],
+            is_popular: true,
+            description: Some("Build rich terminal user interfaces".to_string()),
+        }),
         "React" => Some(FrameworkDetails {
             framework_type: "Frontend Framework".to_string(),
             alternatives: vec![
@@ -125,6 +189,198 @@ pub fn get_framework_details(framework_name: &str) -> Option<FrameworkDetails> {
                 "Intuitive Vue framework for creating universal applications".to_string(),
             ),
         }),
+        "Remix" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec![
+                "Next.js".to_string(),
+                "SvelteKit".to_string(),
+                "Nuxt".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Full-stack React framework built on web standards".to_string()),
+        }),
+        "Astro" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec![
+                "Next.js".to_string(),
+                "Gatsby".to_string(),
+                "Eleventy".to_string(),
+            ],
+            is_popular: true,
+            description: Some(
+                "Content-focused framework that ships zero JavaScript by default".to_string(),
+            ),
+        }),
+        "SvelteKit" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec![
+                "Next.js".to_string(),
+                "Nuxt".to_string(),
+                "Remix".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Application framework for Svelte".to_string()),
+        }),
+        "Gatsby" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Next.js".to_string(), "Astro".to_string()],
+            is_popular: false,
+            description: Some(
+                "React-based static site generator with a GraphQL data layer".to_string(),
+            ),
+        }),
+        "Solid" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec![
+                "React".to_string(),
+                "Preact".to_string(),
+                "Svelte".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Fine-grained reactive UI library without a virtual DOM".to_string()),
+        }),
+        "Qwik" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec![
+                "Solid".to_string(),
+                "React".to_string(),
+                "Astro".to_string(),
+            ],
+            is_popular: false,
+            description: Some("Resumable framework for instant-loading web apps".to_string()),
+        }),
+        "Preact" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec!["React".to_string(), "Solid".to_string()],
+            is_popular: true,
+            description: Some("Fast 3kB alternative to React with the same API".to_string()),
+        }),
+        "Lit" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec!["Stencil".to_string(), "Svelte".to_string()],
+            is_popular: true,
+            description: Some(
+                "Simple library for building fast, lightweight web components".to_string(),
+            ),
+        }),
+        "Ember" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec!["Angular".to_string(), "Vue".to_string()],
+            is_popular: false,
+            description: Some(
+                "Batteries-included framework for ambitious web applications".to_string(),
+            ),
+        }),
+        "NestJS" => Some(FrameworkDetails {
+            framework_type: "Backend Framework".to_string(),
+            alternatives: vec![
+                "AdonisJS".to_string(),
+                "Express".to_string(),
+                "Fastify".to_string(),
+            ],
+            is_popular: true,
+            description: Some(
+                "Progressive, TypeScript-first framework for server-side applications".to_string(),
+            ),
+        }),
+        "Fastify" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Express".to_string(), "Koa".to_string(), "Hono".to_string()],
+            is_popular: true,
+            description: Some("Fast and low overhead web framework for Node.js".to_string()),
+        }),
+        "Koa" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Express".to_string(), "Fastify".to_string()],
+            is_popular: true,
+            description: Some("Expressive middleware framework from the Express team".to_string()),
+        }),
+        "Hapi" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Express".to_string(), "Fastify".to_string()],
+            is_popular: false,
+            description: Some("Configuration-centric framework for secure APIs".to_string()),
+        }),
+        "AdonisJS" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["NestJS".to_string(), "Laravel".to_string()],
+            is_popular: false,
+            description: Some("TypeScript-first MVC web framework for Node.js".to_string()),
+        }),
+        "Electron" => Some(FrameworkDetails {
+            framework_type: "Desktop Framework".to_string(),
+            alternatives: vec!["Tauri".to_string(), "NW.js".to_string()],
+            is_popular: true,
+            description: Some(
+                "Cross-platform desktop apps with JavaScript, HTML and CSS".to_string(),
+            ),
+        }),
+        "React Native" => Some(FrameworkDetails {
+            framework_type: "Mobile Framework".to_string(),
+            alternatives: vec![
+                "Flutter".to_string(),
+                "Ionic".to_string(),
+                "Expo".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Native mobile apps using React".to_string()),
+        }),
+        "Expo" => Some(FrameworkDetails {
+            framework_type: "Mobile Framework".to_string(),
+            alternatives: vec!["React Native".to_string(), "Ionic".to_string()],
+            is_popular: true,
+            description: Some("Platform and tooling for universal React Native apps".to_string()),
+        }),
+        "Ionic" => Some(FrameworkDetails {
+            framework_type: "Mobile Framework".to_string(),
+            alternatives: vec![
+                "React Native".to_string(),
+                "Flutter".to_string(),
+                "Capacitor".to_string(),
+            ],
+            is_popular: true,
+            description: Some(
+                "UI toolkit for cross-platform mobile apps with web technologies".to_string(),
+            ),
+        }),
+        "Capacitor" => Some(FrameworkDetails {
+            framework_type: "Mobile Framework".to_string(),
+            alternatives: vec!["Cordova".to_string(), "React Native".to_string()],
+            is_popular: true,
+            description: Some("Native runtime for running web apps on iOS and Android".to_string()),
+        }),
+        "Hono" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Elysia".to_string(),
+                "Oak".to_string(),
+                "Express".to_string(),
+            ],
+            is_popular: true,
+            description: Some(
+                "Small web framework built on Web Standards for any JavaScript runtime".to_string(),
+            ),
+        }),
+        "Elysia" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Hono".to_string(), "Express".to_string()],
+            is_popular: false,
+            description: Some("Ergonomic, end-to-end type-safe web framework for Bun".to_string()),
+        }),
+        "Fresh" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Next.js".to_string(), "Astro".to_string()],
+            is_popular: false,
+            description: Some(
+                "Deno web framework with islands-based partial hydration".to_string(),
+            ),
+        }),
+        "Oak" => Some(FrameworkDetails {
+            framework_type: "Backend Framework".to_string(),
+            alternatives: vec!["Hono".to_string(), "Express".to_string()],
+            is_popular: false,
+            description: Some("Middleware framework for Deno's HTTP server".to_string()),
+        }),
         "Gin" => Some(FrameworkDetails {
             framework_type: "Web Framework".to_string(),
             alternatives: vec![
@@ -157,6 +413,104 @@ pub fn get_framework_details(framework_name: &str) -> Option<FrameworkDetails> {
             is_popular: true,
             description: Some("Express inspired web framework built on Fasthttp".to_string()),
         }),
+        "chi" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Gorilla Mux".to_string(),
+                "Gin".to_string(),
+                "Echo".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Lightweight, idiomatic router built on net/http".to_string()),
+        }),
+        "Beego" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Buffalo".to_string(), "Revel".to_string()],
+            is_popular: true,
+            description: Some("MVC framework with ORM, caching and admin tooling".to_string()),
+        }),
+        "Buffalo" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Beego".to_string(), "Revel".to_string()],
+            is_popular: false,
+            description: Some("Rails-like web development eco-system for Go".to_string()),
+        }),
+        "Revel" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec!["Beego".to_string(), "Buffalo".to_string()],
+            is_popular: false,
+            description: Some("High-productivity, full-stack web framework".to_string()),
+        }),
+        "go-kit" => Some(FrameworkDetails {
+            framework_type: "Microservice Framework".to_string(),
+            alternatives: vec!["Kratos".to_string(), "gRPC-Go".to_string()],
+            is_popular: true,
+            description: Some("Toolkit of packages for building microservices".to_string()),
+        }),
+        "Kratos" => Some(FrameworkDetails {
+            framework_type: "Microservice Framework".to_string(),
+            alternatives: vec!["go-kit".to_string(), "gRPC-Go".to_string()],
+            is_popular: true,
+            description: Some("Microservice framework with HTTP and gRPC transports".to_string()),
+        }),
+        "gRPC-Go" => Some(FrameworkDetails {
+            framework_type: "RPC Framework".to_string(),
+            alternatives: vec!["Connect".to_string(), "Twirp".to_string()],
+            is_popular: true,
+            description: Some("Go implementation of gRPC".to_string()),
+        }),
+        "Connect" => Some(FrameworkDetails {
+            framework_type: "RPC Framework".to_string(),
+            alternatives: vec!["gRPC-Go".to_string(), "Twirp".to_string()],
+            is_popular: false,
+            description: Some("gRPC-compatible HTTP APIs that work in browsers".to_string()),
+        }),
+        "Cobra" => Some(FrameworkDetails {
+            framework_type: "CLI Framework".to_string(),
+            alternatives: vec!["urfave/cli".to_string(), "Kong".to_string()],
+            is_popular: true,
+            description: Some("Commander for modern Go CLI applications".to_string()),
+        }),
+        "urfave/cli" => Some(FrameworkDetails {
+            framework_type: "CLI Framework".to_string(),
+            alternatives: vec!["Cobra".to_string(), "Kong".to_string()],
+            is_popular: true,
+            description: Some(
+                "Simple, fast and fun package for building command line apps".to_string(),
+            ),
+        }),
+        "Bubble Tea" => Some(FrameworkDetails {
+            framework_type: "TUI Framework".to_string(),
+            alternatives: vec!["tview".to_string(), "termui".to_string()],
+            is_popular: true,
+            description: Some("Elm-architecture framework for terminal apps".to_string()),
+        }),
+        "Wails" => Some(FrameworkDetails {
+            framework_type: "Desktop Framework".to_string(),
+            alternatives: vec!["Fyne".to_string(), "Tauri".to_string()],
+            is_popular: true,
+            description: Some("Desktop apps with Go and web technologies".to_string()),
+        }),
+        "Fyne" => Some(FrameworkDetails {
+            framework_type: "Desktop Framework".to_string(),
+            alternatives: vec!["Wails".to_string(), "Gio".to_string()],
+            is_popular: true,
+            description: Some("Cross-platform GUI toolkit with native rendering".to_string()),
+        }),
+        "controller-runtime" => Some(FrameworkDetails {
+            framework_type: "Kubernetes Operator Framework".to_string(),
+            alternatives: vec!["Operator SDK".to_string(), "Kubebuilder".to_string()],
+            is_popular: true,
+            description: Some("Libraries for building Kubernetes controllers".to_string()),
+        }),
+        "Kubebuilder" => Some(FrameworkDetails {
+            framework_type: "Kubernetes Operator Framework".to_string(),
+            alternatives: vec!["Operator SDK".to_string(), "Metacontroller".to_string()],
+            is_popular: true,
+            description: Some(
+                "SDK for building Kubernetes APIs with CRDs and controllers".to_string(),
+            ),
+        }),
         "Gorilla Mux" => Some(FrameworkDetails {
             framework_type: "Web Framework".to_string(),
             alternatives: vec!["Gin".to_string(), "Echo".to_string(), "Fiber".to_string()],
@@ -165,6 +519,16 @@ pub fn get_framework_details(framework_name: &str) -> Option<FrameworkDetails> {
                 "Powerful HTTP router and URL matcher for building Go web servers".to_string(),
             ),
         }),
+        "Rails" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec![
+                "Django".to_string(),
+                "Laravel".to_string(),
+                "Hanami".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Convention-over-configuration MVC framework for Ruby".to_string()),
+        }),
         "Django" => Some(FrameworkDetails {
             framework_type: "Web Framework".to_string(),
             alternatives: vec![
@@ -200,10 +564,220 @@ pub fn get_framework_details(framework_name: &str) -> Option<FrameworkDetails> {
                     .to_string(),
             ),
         }),
+        "Play" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Akka HTTP".to_string(),
+                "http4s".to_string(),
+                "ZIO".to_string(),
+            ],
+            is_popular: true,
+            description: Some("High-velocity web framework for Scala and Java".to_string()),
+        }),
+        "Akka HTTP" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Pekko HTTP".to_string(),
+                "http4s".to_string(),
+                "Play".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Streaming HTTP server and client toolkit built on Akka".to_string()),
+        }),
+        "Pekko HTTP" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Akka HTTP".to_string(),
+                "http4s".to_string(),
+                "Play".to_string(),
+            ],
+            is_popular: false,
+            description: Some("Apache-licensed fork of Akka HTTP".to_string()),
+        }),
+        "http4s" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Akka HTTP".to_string(),
+                "ZIO".to_string(),
+                "Play".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Typeful, functional HTTP library for Scala".to_string()),
+        }),
+        "ZIO" => Some(FrameworkDetails {
+            framework_type: "Effect System".to_string(),
+            alternatives: vec!["Cats Effect".to_string(), "Akka".to_string()],
+            is_popular: true,
+            description: Some(
+                "Type-safe, composable asynchronous and concurrent programming for Scala"
+                    .to_string(),
+            ),
+        }),
+        "Spark" => Some(FrameworkDetails {
+            framework_type: "Data Processing".to_string(),
+            alternatives: vec!["Flink".to_string(), "Beam".to_string()],
+            is_popular: true,
+            description: Some("Unified engine for large-scale data processing".to_string()),
+        }),
+        "Ring" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec![
+                "Pedestal".to_string(),
+                "Luminus".to_string(),
+                "Kit".to_string(),
+            ],
+            is_popular: true,
+            description: Some("Clojure HTTP server abstraction and middleware".to_string()),
+        }),
+        "Pedestal" => Some(FrameworkDetails {
+            framework_type: "Web Framework".to_string(),
+            alternatives: vec!["Ring".to_string(), "Luminus".to_string(), "Kit".to_string()],
+            is_popular: false,
+            description: Some("Interceptor-based Clojure services library".to_string()),
+        }),
+        "Luminus" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec![
+                "Kit".to_string(),
+                "Pedestal".to_string(),
+                "Ring".to_string(),
+            ],
+            is_popular: false,
+            description: Some("Clojure micro-framework template built on Ring".to_string()),
+        }),
+        "Kit" => Some(FrameworkDetails {
+            framework_type: "Full-stack Framework".to_string(),
+            alternatives: vec![
+                "Luminus".to_string(),
+                "Pedestal".to_string(),
+                "Ring".to_string(),
+            ],
+            is_popular: false,
+            description: Some("Modular Clojure web framework, successor to Luminus".to_string()),
+        }),
+        "re-frame" => Some(FrameworkDetails {
+            framework_type: "Frontend Framework".to_string(),
+            alternatives: vec!["Reagent".to_string(), "Fulcro".to_string()],
+            is_popular: true,
+            description: Some("ClojureScript framework for Reagent single page apps".to_string()),
+        }),
+        "PyTorch" => Some(FrameworkDetails {
+            framework_type: "Machine Learning Framework".to_string(),
+            alternatives: vec!["TensorFlow".to_string(), "JAX".to_string()],
+            is_popular: true,
+            description: Some(
+                "Tensors and dynamic neural networks with GPU acceleration".to_string(),
+            ),
+        }),
+        "TensorFlow" => Some(FrameworkDetails {
+            framework_type: "Machine Learning Framework".to_string(),
+            alternatives: vec!["PyTorch".to_string(), "JAX".to_string()],
+            is_popular: true,
+            description: Some("End-to-end platform for machine learning".to_string()),
+        }),
+        "JAX" => Some(FrameworkDetails {
+            framework_type: "Machine Learning Framework".to_string(),
+            alternatives: vec!["PyTorch".to_string(), "TensorFlow".to_string()],
+            is_popular: true,
+            description: Some(
+                "Composable transformations of NumPy programs: differentiate, vectorize, JIT"
+                    .to_string(),
+            ),
+        }),
+        "scikit-learn" => Some(FrameworkDetails {
+            framework_type: "Machine Learning Library".to_string(),
+            alternatives: vec!["XGBoost".to_string(), "LightGBM".to_string()],
+            is_popular: true,
+            description: Some(
+                "Classical machine learning algorithms built on NumPy and SciPy".to_string(),
+            ),
+        }),
+        "Transformers" => Some(FrameworkDetails {
+            framework_type: "Machine Learning Library".to_string(),
+            alternatives: vec!["PyTorch".to_string(), "TensorFlow".to_string()],
+            is_popular: true,
+            description: Some(
+                "Hugging Face library of pretrained models for text, vision and audio".to_string(),
+            ),
+        }),
+        "LangChain" => Some(FrameworkDetails {
+            framework_type: "LLM Framework".to_string(),
+            alternatives: vec!["LlamaIndex".to_string(), "Haystack".to_string()],
+            is_popular: true,
+            description: Some(
+                "Framework for building applications powered by language models".to_string(),
+            ),
+        }),
+        "Streamlit" => Some(FrameworkDetails {
+            framework_type: "Data App Framework".to_string(),
+            alternatives: vec!["Gradio".to_string(), "Dash".to_string()],
+            is_popular: true,
+            description: Some("Turn Python scripts into shareable data apps".to_string()),
+        }),
+        "Gradio" => Some(FrameworkDetails {
+            framework_type: "Data App Framework".to_string(),
+            alternatives: vec!["Streamlit".to_string(), "Dash".to_string()],
+            is_popular: true,
+            description: Some("Build web demos for machine learning models".to_string()),
+        }),
+        "Dash" => Some(FrameworkDetails {
+            framework_type: "Data App Framework".to_string(),
+            alternatives: vec!["Streamlit".to_string(), "Gradio".to_string()],
+            is_popular: true,
+            description: Some("Analytical web apps built on Plotly".to_string()),
+        }),
+        "Airflow" => Some(FrameworkDetails {
+            framework_type: "Workflow Orchestrator".to_string(),
+            alternatives: vec!["Dagster".to_string(), "Prefect".to_string()],
+            is_popular: true,
+            description: Some(
+                "Platform to author, schedule and monitor workflows as DAGs".to_string(),
+            ),
+        }),
+        "Dagster" => Some(FrameworkDetails {
+            framework_type: "Workflow Orchestrator".to_string(),
+            alternatives: vec!["Airflow".to_string(), "Prefect".to_string()],
+            is_popular: true,
+            description: Some("Asset-centric orchestrator for data pipelines".to_string()),
+        }),
+        "Prefect" => Some(FrameworkDetails {
+            framework_type: "Workflow Orchestrator".to_string(),
+            alternatives: vec!["Airflow".to_string(), "Dagster".to_string()],
+            is_popular: true,
+            description: Some("Workflow orchestration for resilient data pipelines".to_string()),
+        }),
         _ => None,
     }
 }
 
+/// Get the kind of project a framework is used for
+pub fn get_framework_category(framework_name: &str) -> FrameworkCategory {
+    match framework_name {
+        "PyTorch" | "TensorFlow" | "JAX" | "scikit-learn" | "Transformers" | "LangChain" => {
+            FrameworkCategory::MachineLearning
+        }
+        "Streamlit" | "Gradio" | "Dash" => FrameworkCategory::DataApp,
+        "Airflow" | "Dagster" | "Prefect" | "Spark" => FrameworkCategory::DataPipeline,
+        "React Native" | "Expo" | "Ionic" | "Capacitor" => FrameworkCategory::Mobile,
+        "Tauri" | "Wails" | "Fyne" | "Electron" => FrameworkCategory::Desktop,
+        "Embassy" => FrameworkCategory::Embedded,
+        "Bevy" => FrameworkCategory::Game,
+        "controller-runtime" | "Kubebuilder" => FrameworkCategory::Infrastructure,
+        "Clap (CLI)" | "Ratatui" | "Cobra" | "urfave/cli" | "Bubble Tea" => FrameworkCategory::Cli,
+        "Axum" | "Actix Web" | "Rocket" | "Warp" | "Poem" | "Salvo" | "Loco" | "Leptos"
+        | "Dioxus" | "Yew" | "Tonic" | "React" | "Vue" | "Angular" | "Svelte" | "Solid"
+        | "Qwik" | "Preact" | "Lit" | "Ember" | "Next.js" | "Nuxt" | "Remix" | "Astro"
+        | "SvelteKit" | "Gatsby" | "Express" | "NestJS" | "Fastify" | "Koa" | "Hapi"
+        | "AdonisJS" | "Hono" | "Elysia" | "Fresh" | "Oak" | "Gin" | "Echo" | "Fiber" | "chi"
+        | "Gorilla Mux" | "Beego" | "Buffalo" | "Revel" | "go-kit" | "Kratos" | "gRPC-Go"
+        | "Connect" | "Rails" | "Django" | "Flask" | "FastAPI" | "Play" | "Akka HTTP"
+        | "Pekko HTTP" | "http4s" | "Ring" | "Pedestal" | "Luminus" | "Kit" | "re-frame" => {
+            FrameworkCategory::Web
+        }
+        _ => FrameworkCategory::Other,
+    }
+}
+
 /// Get framework popularity ranking (1-10, where 10 is most popular)
 ///
 /// This function provides a popularity score that could be useful for recommendations
@@ -332,6 +906,30 @@ mod tests {
         assert!(unknown_framework.is_none());
     }
 
+    #[test]
+    fn test_get_framework_category() {
+        assert_eq!(get_framework_category("Django"), FrameworkCategory::Web);
+        assert_eq!(get_framework_category("React"), FrameworkCategory::Web);
+        assert_eq!(
+            get_framework_category("PyTorch"),
+            FrameworkCategory::MachineLearning
+        );
+        assert_eq!(
+            get_framework_category("Streamlit"),
+            FrameworkCategory::DataApp
+        );
+        assert_eq!(
+            get_framework_category("Airflow"),
+            FrameworkCategory::DataPipeline
+        );
+        assert_eq!(get_framework_category("ZIO"), FrameworkCategory::Other);
+        assert_eq!(get_framework_category("Gin"), FrameworkCategory::Web);
+        assert_eq!(
+            get_framework_category("UnknownFramework"),
+            FrameworkCategory::Other
+        );
+    }
+
     #[test]
     fn test_framework_popularity() {
         assert_eq!(get_framework_popularity("React"), 10);
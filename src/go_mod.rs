@@ -0,0 +1,344 @@
+//! Parser for `go.mod` and `go.work` files.
+//!
+//! Both files share one line-oriented syntax: a directive verb followed by
+//! arguments, optionally grouped into `verb ( ... )` blocks, with `//`
+//! comments. A trailing `// indirect` marks a requirement that no package in
+//! the module imports directly.
+
+use std::fs;
+use std::path::Path;
+
+/// A parsed `go.mod` file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GoModule {
+    pub module_path: Option<String>,
+    pub go_version: Option<String>,
+    pub toolchain: Option<String>,
+    pub requires: Vec<GoRequire>,
+    pub replaces: Vec<GoReplace>,
+    pub excludes: Vec<(String, String)>,
+}
+
+/// A `require` entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoRequire {
+    pub module_path: String,
+    pub version: String,
+    pub indirect: bool,
+}
+
+/// A `replace old [version] => new [version]` entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoReplace {
+    pub old_path: String,
+    pub old_version: Option<String>,
+    pub new_path: String,
+    pub new_version: Option<String>,
+}
+
+/// A parsed `go.work` file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GoWorkspace {
+    pub go_version: Option<String>,
+    pub toolchain: Option<String>,
+    /// Module directories, relative to the workspace root
+    pub uses: Vec<String>,
+    pub replaces: Vec<GoReplace>,
+}
+
+/// One directive line: verb, arguments and the trailing comment
+struct GoDirective {
+    verb: String,
+    arguments: Vec<String>,
+    comment: String,
+}
+
+impl GoModule {
+    /// The requirement for a module path, accepting major version suffixes (`/v4`)
+    pub fn find_require(&self, module_path: &str) -> Option<&GoRequire> {
+        self.requires
+            .iter()
+            .find(|go_require| module_path_matches(&go_require.module_path, module_path))
+    }
+
+    /// The version in effect for a requirement once `replace` directives apply
+    pub fn effective_version(&self, go_require: &GoRequire) -> String {
+        self.replaces
+            .iter()
+            .find(|go_replace| {
+                go_replace.old_path == go_require.module_path
+                    && go_replace
+                        .old_version
+                        .as_ref()
+                        .map_or(true, |old_version| *old_version == go_require.version)
+            })
+            // Replacing with a local directory keeps the required version
+            .and_then(|go_replace| go_replace.new_version.clone())
+            .unwrap_or_else(|| go_require.version.clone())
+    }
+
+    /// The Go version the module builds with: `toolchain` if set, otherwise `go`
+    pub fn build_go_version(&self) -> Option<String> {
+        self.toolchain
+            .as_deref()
+            .and_then(toolchain_go_version)
+            .or_else(|| self.go_version.clone())
+    }
+}
+
+impl GoWorkspace {
+    /// The Go version the workspace builds with: `toolchain` if set, otherwise `go`
+    pub fn build_go_version(&self) -> Option<String> {
+        self.toolchain
+            .as_deref()
+            .and_then(toolchain_go_version)
+            .or_else(|| self.go_version.clone())
+    }
+}
+
+/// `github.com/labstack/echo/v4` matches `github.com/labstack/echo`
+pub fn module_path_matches(required_path: &str, module_path: &str) -> bool {
+    match required_path.strip_prefix(module_path) {
+        Some("") => true,
+        Some(version_suffix) => version_suffix
+            .strip_prefix("/v")
+            .is_some_and(|major_version| major_version.chars().all(|c| c.is_ascii_digit())),
+        None => false,
+    }
+}
+
+/// `go1.22.3` becomes `1.22.3`; `default` and `local` don't name a version
+pub fn toolchain_go_version(toolchain_name: &str) -> Option<String> {
+    let toolchain_version = toolchain_name.strip_prefix("go")?;
+    // Custom toolchains look like `go1.22.3+auto` or `go1.22.3-custom`
+    let toolchain_version = toolchain_version
+        .split(['+', '-'])
+        .next()
+        .unwrap_or(toolchain_version);
+    (!toolchain_version.is_empty()).then(|| toolchain_version.to_string())
+}
+
+pub fn read_go_mod(go_mod_path: &Path) -> Option<GoModule> {
+    fs::read_to_string(go_mod_path)
+        .ok()
+        .map(|go_mod_content| parse_go_mod(&go_mod_content))
+}
+
+pub fn parse_go_mod(go_mod_content: &str) -> GoModule {
+    let mut go_module = GoModule::default();
+
+    for directive in parse_directives(go_mod_content) {
+        let first_argument = directive.arguments.first().cloned();
+        match directive.verb.as_str() {
+            "module" => go_module.module_path = first_argument,
+            "go" => go_module.go_version = first_argument,
+            "toolchain" => go_module.toolchain = first_argument,
+            "require" => {
+                if let [module_path, version, ..] = directive.arguments.as_slice() {
+                    go_module.requires.push(GoRequire {
+                        module_path: module_path.clone(),
+                        version: version.clone(),
+                        indirect: directive
+                            .comment
+                            .split(';')
+                            .any(|comment_part| comment_part.trim() == "indirect"),
+                    });
+                }
+            }
+            "replace" => go_module
+                .replaces
+                .extend(parse_replace(&directive.arguments)),
+            "exclude" => {
+                if let [module_path, version, ..] = directive.arguments.as_slice() {
+                    go_module
+                        .excludes
+                        .push((module_path.clone(), version.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    go_module
+}
+
+pub fn read_go_work(project_path: &Path) -> Option<GoWorkspace> {
+    let go_work_content = fs::read_to_string(project_path.join("go.work")).ok()?;
+    let mut go_workspace = GoWorkspace::default();
+
+    for directive in parse_directives(&go_work_content) {
+        let first_argument = directive.arguments.first().cloned();
+        match directive.verb.as_str() {
+            "go" => go_workspace.go_version = first_argument,
+            "toolchain" => go_workspace.toolchain = first_argument,
+            "use" => go_workspace.uses.extend(first_argument),
+            "replace" => go_workspace
+                .replaces
+                .extend(parse_replace(&directive.arguments)),
+            _ => {}
+        }
+    }
+
+    Some(go_workspace)
+}
+
+fn parse_replace(arguments: &[String]) -> Option<GoReplace> {
+    let arrow_index = arguments.iter().position(|argument| argument == "=>")?;
+    let (old_side, new_side) = (&arguments[..arrow_index], &arguments[arrow_index + 1..]);
+    Some(GoReplace {
+        old_path: old_side.first()?.clone(),
+        old_version: old_side.get(1).cloned(),
+        new_path: new_side.first()?.clone(),
+        new_version: new_side.get(1).cloned(),
+    })
+}
+
+/// Flatten single-line and block directives into one list
+fn parse_directives(file_content: &str) -> Vec<GoDirective> {
+    let mut directives = Vec::new();
+    let mut block_verb: Option<String> = None;
+
+    for content_line in file_content.lines() {
+        let (line_code, line_comment) = match content_line.split_once("//") {
+            Some((line_code, line_comment)) => (line_code, line_comment.trim()),
+            None => (content_line, ""),
+        };
+        let mut tokens = tokenize(line_code);
+        if tokens.is_empty() {
+            continue;
+        }
+
+        if let Some(ref verb) = block_verb {
+            if tokens == [")"] {
+                block_verb = None;
+                continue;
+            }
+            directives.push(GoDirective {
+                verb: verb.clone(),
+                arguments: tokens,
+                comment: line_comment.to_string(),
+            });
+            continue;
+        }
+
+        let verb = tokens.remove(0);
+        if tokens == ["("] {
+            block_verb = Some(verb);
+            continue;
+        }
+        directives.push(GoDirective {
+            verb,
+            arguments: tokens,
+            comment: line_comment.to_string(),
+        });
+    }
+
+    directives
+}
+
+/// Split on whitespace, keeping `"quoted strings"` and `` `raw strings` `` whole
+fn tokenize(line_code: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut characters = line_code.chars().peekable();
+
+    while let Some(&next_character) = characters.peek() {
+        if next_character.is_whitespace() {
+            characters.next();
+            continue;
+        }
+        let mut token = String::new();
+        if next_character == '"' || next_character == '`' {
+            characters.next();
+            for quoted_character in characters.by_ref() {
+                if quoted_character == next_character {
+                    break;
+                }
+                token.push(quoted_character);
+            }
+        } else {
+            while let Some(&token_character) = characters.peek() {
+                if token_character.is_whitespace() {
+                    break;
+                }
+                token.push(token_character);
+                characters.next();
+            }
+        }
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_go_mod() {
+        let go_module = parse_go_mod(
+            r#"// Service module
+module github.com/acme/api
+
+go 1.21
+
+toolchain go1.22.3
+
+require github.com/gin-gonic/gin v1.9.1
+
+require (
+	github.com/labstack/echo/v4 v4.11.4
+	golang.org/x/net v0.20.0 // indirect
+	"github.com/spf13/cobra" v1.8.0
+)
+
+replace github.com/gin-gonic/gin v1.9.1 => github.com/acme/gin v1.9.2-acme
+replace (
+	golang.org/x/net => ../net
+)
+
+exclude github.com/spf13/cobra v1.7.0
+"#,
+        );
+
+        assert_eq!(
+            go_module.module_path,
+            Some("github.com/acme/api".to_string())
+        );
+        assert_eq!(go_module.go_version, Some("1.21".to_string()));
+        assert_eq!(go_module.build_go_version(), Some("1.22.3".to_string()));
+        assert_eq!(go_module.requires.len(), 4);
+        assert!(go_module.requires[2].indirect);
+        assert!(!go_module.requires[1].indirect);
+        assert_eq!(go_module.requires[3].module_path, "github.com/spf13/cobra");
+        assert_eq!(go_module.excludes.len(), 1);
+
+        let gin = go_module.find_require("github.com/gin-gonic/gin").unwrap();
+        assert_eq!(go_module.effective_version(gin), "v1.9.2-acme");
+        let echo = go_module.find_require("github.com/labstack/echo").unwrap();
+        assert_eq!(go_module.effective_version(echo), "v4.11.4");
+        let net = go_module.find_require("golang.org/x/net").unwrap();
+        assert_eq!(go_module.effective_version(net), "v0.20.0");
+
+        assert!(go_module.find_require("github.com/labstack").is_none());
+        assert!(!module_path_matches(
+            "github.com/gin-gonic/gin-contrib",
+            "github.com/gin-gonic/gin"
+        ));
+    }
+
+    #[test]
+    fn test_read_go_work() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("go.work"),
+            "go 1.22.0\n\ntoolchain default\n\nuse (\n\t./api\n\t./worker // background jobs\n)\nuse ./tools\n",
+        )
+        .unwrap();
+
+        let go_workspace = read_go_work(temp_dir.path()).unwrap();
+        assert_eq!(go_workspace.uses, vec!["./api", "./worker", "./tools"]);
+        assert_eq!(go_workspace.build_go_version(), Some("1.22.0".to_string()));
+    }
+}
@@ -0,0 +1,908 @@
+//! Source import scanner.
+//!
+//! Reads `use` / `extern crate` lines in Rust, `import` / `require` in
+//! JavaScript and TypeScript, `import` / `from ... import` in Python and
+//! import declarations in Go, and reduces each one to the package that
+//! provides it. Manifests say what a project may use; imports say what it does.
+
+use crate::notebooks;
+use crate::project_files;
+use crate::requirements;
+use std::fs;
+use std::path::Path;
+
+/// How deep to look for source files below the project root
+const MAX_SEARCH_DEPTH: usize = 8;
+
+/// Larger files are generated bundles rather than hand-written source
+const MAX_SOURCE_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Node.js core modules importable without the `node:` prefix, as listed by
+/// `require("module").builtinModules`; subpaths such as `fs/promises` reduce
+/// to their first segment
+const NODE_BUILTIN_MODULES: [&str; 56] = [
+    "_http_agent",
+    "_http_client",
+    "_http_common",
+    "_http_incoming",
+    "_http_outgoing",
+    "_http_server",
+    "_stream_duplex",
+    "_stream_passthrough",
+    "_stream_readable",
+    "_stream_transform",
+    "_stream_wrap",
+    "_stream_writable",
+    "_tls_common",
+    "_tls_wrap",
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Python standard library modules, as listed by `sys.stdlib_module_names`
+/// (3.11, which still ships the modules later releases removed)
+const PYTHON_STANDARD_LIBRARY: [&str; 305] = [
+    "__future__",
+    "_abc",
+    "_aix_support",
+    "_ast",
+    "_asyncio",
+    "_bisect",
+    "_blake2",
+    "_bootsubprocess",
+    "_bz2",
+    "_codecs",
+    "_codecs_cn",
+    "_codecs_hk",
+    "_codecs_iso2022",
+    "_codecs_jp",
+    "_codecs_kr",
+    "_codecs_tw",
+    "_collections",
+    "_collections_abc",
+    "_compat_pickle",
+    "_compression",
+    "_contextvars",
+    "_crypt",
+    "_csv",
+    "_ctypes",
+    "_curses",
+    "_curses_panel",
+    "_datetime",
+    "_dbm",
+    "_decimal",
+    "_elementtree",
+    "_frozen_importlib",
+    "_frozen_importlib_external",
+    "_functools",
+    "_gdbm",
+    "_hashlib",
+    "_heapq",
+    "_imp",
+    "_io",
+    "_json",
+    "_locale",
+    "_lsprof",
+    "_lzma",
+    "_markupbase",
+    "_md5",
+    "_msi",
+    "_multibytecodec",
+    "_multiprocessing",
+    "_opcode",
+    "_operator",
+    "_osx_support",
+    "_overlapped",
+    "_pickle",
+    "_posixshmem",
+    "_posixsubprocess",
+    "_py_abc",
+    "_pydecimal",
+    "_pyio",
+    "_queue",
+    "_random",
+    "_scproxy",
+    "_sha1",
+    "_sha256",
+    "_sha3",
+    "_sha512",
+    "_signal",
+    "_sitebuiltins",
+    "_socket",
+    "_sqlite3",
+    "_sre",
+    "_ssl",
+    "_stat",
+    "_statistics",
+    "_string",
+    "_strptime",
+    "_struct",
+    "_symtable",
+    "_thread",
+    "_threading_local",
+    "_tkinter",
+    "_tokenize",
+    "_tracemalloc",
+    "_typing",
+    "_uuid",
+    "_warnings",
+    "_weakref",
+    "_weakrefset",
+    "_winapi",
+    "_zoneinfo",
+    "abc",
+    "aifc",
+    "antigravity",
+    "argparse",
+    "array",
+    "ast",
+    "asynchat",
+    "asyncio",
+    "asyncore",
+    "atexit",
+    "audioop",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "cProfile",
+    "calendar",
+    "cgi",
+    "cgitb",
+    "chunk",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "codeop",
+    "collections",
+    "colorsys",
+    "compileall",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "crypt",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "distutils",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
+    "functools",
+    "gc",
+    "genericpath",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "idlelib",
+    "imaplib",
+    "imghdr",
+    "imp",
+    "importlib",
+    "inspect",
+    "io",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "lib2to3",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "mailcap",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "modulefinder",
+    "msilib",
+    "msvcrt",
+    "multiprocessing",
+    "netrc",
+    "nis",
+    "nntplib",
+    "nt",
+    "ntpath",
+    "nturl2path",
+    "numbers",
+    "opcode",
+    "operator",
+    "optparse",
+    "os",
+    "ossaudiodev",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pickletools",
+    "pipes",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "posixpath",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pyclbr",
+    "pydoc",
+    "pydoc_data",
+    "pyexpat",
+    "queue",
+    "quopri",
+    "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "rlcompleter",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtpd",
+    "smtplib",
+    "sndhdr",
+    "socket",
+    "socketserver",
+    "spwd",
+    "sqlite3",
+    "sre_compile",
+    "sre_constants",
+    "sre_parse",
+    "ssl",
+    "stat",
+    "statistics",
+    "string",
+    "stringprep",
+    "struct",
+    "subprocess",
+    "sunau",
+    "symtable",
+    "sys",
+    "sysconfig",
+    "syslog",
+    "tabnanny",
+    "tarfile",
+    "telnetlib",
+    "tempfile",
+    "termios",
+    "textwrap",
+    "this",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "trace",
+    "traceback",
+    "tracemalloc",
+    "tty",
+    "turtle",
+    "turtledemo",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uu",
+    "uuid",
+    "venv",
+    "warnings",
+    "wave",
+    "weakref",
+    "webbrowser",
+    "winreg",
+    "winsound",
+    "wsgiref",
+    "xdrlib",
+    "xml",
+    "xmlrpc",
+    "zipapp",
+    "zipfile",
+    "zipimport",
+    "zlib",
+    "zoneinfo",
+];
+
+/// Rust path roots that never name a dependency
+const RUST_BUILTIN_ROOTS: [&str; 7] = ["crate", "self", "super", "std", "core", "alloc", "test"];
+
+/// Packages imported by a project's own source files
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceImports {
+    pub language: String,
+    pub source_file_count: usize,
+    /// Crate names, npm packages, top-level Python modules or Go import paths,
+    /// in first-seen order
+    pub imported_packages: Vec<String>,
+    /// Rust only: roots of qualified paths such as `tauri::Builder`, which
+    /// name a crate used without `use` or a module the crate imported itself
+    pub path_roots: Vec<String>,
+    /// Top-level Rust and Python modules defined by the project's own files
+    pub local_modules: Vec<String>,
+}
+
+impl SourceImports {
+    /// Whether any source file imports the package a manifest calls `package_name`
+    pub fn imports_package(&self, package_name: &str) -> bool {
+        self.imported_packages
+            .iter()
+            .chain(self.path_roots.iter())
+            .any(|imported_package| {
+                package_provides(&self.language, package_name, imported_package)
+            })
+    }
+
+    /// Imports that come from neither a declared package, the standard library
+    /// nor the project's own modules
+    pub fn undeclared_imports(&self, declared_packages: &[String]) -> Vec<String> {
+        self.imported_packages
+            .iter()
+            .filter(|imported_package| {
+                !is_standard_library(&self.language, imported_package)
+                    && !self.local_modules.contains(imported_package)
+                    && !declared_packages.iter().any(|declared_package| {
+                        package_provides(&self.language, declared_package, imported_package)
+                    })
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Whether `imported_package` is provided by the package a manifest calls `package_name`
+pub fn package_provides(language: &str, package_name: &str, imported_package: &str) -> bool {
+    match language {
+        // `actix-web` is imported as `actix_web`
+        "Rust" => package_name.replace('-', "_") == imported_package,
+        "Python" => {
+            let normalized_package = requirements::normalize_name(package_name);
+            requirements::normalize_name(notebooks::python_import_package(imported_package))
+                == normalized_package
+                || requirements::normalize_name(imported_package)
+                    == python_package_import(&normalized_package)
+        }
+        // Packages below a module path belong to it
+        "Go" => imported_package
+            .strip_prefix(package_name)
+            .is_some_and(|package_path| package_path.is_empty() || package_path.starts_with('/')),
+        _ => package_name == imported_package,
+    }
+}
+
+/// Modules that ship with the language rather than with a package
+pub fn is_standard_library(language: &str, imported_package: &str) -> bool {
+    match language {
+        "Rust" => imported_package == "proc_macro",
+        "JavaScript" => NODE_BUILTIN_MODULES.contains(&imported_package),
+        "Python" => PYTHON_STANDARD_LIBRARY.contains(&imported_package),
+        // Only module paths that start with a domain are downloaded
+        "Go" => !imported_package
+            .split('/')
+            .next()
+            .unwrap_or(imported_package)
+            .contains('.'),
+        _ => false,
+    }
+}
+
+/// Scan the project's source files, returning `None` when it has none in that language
+pub fn scan_source_imports(project_path: &Path, language: &str) -> Option<SourceImports> {
+    let source_extensions: &[&str] = match language {
+        "Rust" => &["rs"],
+        "JavaScript" => &[
+            "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "vue", "svelte", "astro",
+        ],
+        "Python" => &["py"],
+        "Go" => &["go"],
+        _ => return None,
+    };
+
+    let source_paths =
+        project_files::find_project_files(project_path, MAX_SEARCH_DEPTH, &|directory_entry| {
+            directory_entry
+                .path()
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|file_extension| source_extensions.contains(&file_extension))
+                && directory_entry
+                    .metadata()
+                    .is_ok_and(|file_metadata| file_metadata.len() <= MAX_SOURCE_FILE_SIZE)
+        });
+    if source_paths.is_empty() {
+        return None;
+    }
+
+    let mut imported_packages: Vec<String> = Vec::new();
+    let mut path_roots: Vec<String> = Vec::new();
+    let mut local_modules: Vec<String> = Vec::new();
+    for source_path in &source_paths {
+        let Ok(source_content) = fs::read_to_string(source_path) else {
+            continue;
+        };
+        let mut file_imports = match language {
+            "Rust" => extract_rust_imports(&source_content),
+            "JavaScript" => extract_javascript_imports(&source_content),
+            "Python" => notebooks::extract_python_imports(&source_content),
+            _ => extract_go_imports(&source_content),
+        };
+        let mut file_local_modules = Vec::new();
+        if matches!(language, "Rust" | "Python") {
+            file_local_modules.extend(top_level_module(project_path, source_path, language));
+        }
+        if language == "Rust" {
+            file_local_modules.extend(extract_rust_module_declarations(&source_content));
+            for path_root in extract_rust_path_roots(&source_content) {
+                if !path_roots.contains(&path_root) {
+                    path_roots.push(path_root);
+                }
+            }
+        }
+        for local_module in file_local_modules {
+            if !local_modules.contains(&local_module) {
+                local_modules.push(local_module);
+            }
+        }
+        for imported_package in file_imports.drain(..) {
+            if !imported_packages.contains(&imported_package) {
+                imported_packages.push(imported_package);
+            }
+        }
+    }
+
+    Some(SourceImports {
+        language: language.to_string(),
+        source_file_count: source_paths.len(),
+        imported_packages,
+        path_roots,
+        local_modules,
+    })
+}
+
+/// The top-level module a source file belongs to, if it can be imported by name
+///
+/// `src/routes.rs` defines `routes`, and `app.py` or `src/app/models.py` define
+/// `app` when `app/__init__.py` exists. Other directories such as `tests/` are
+/// never importable by name, so an `import requests` stays undeclared even when
+/// `tests/requests/` exists.
+fn top_level_module(project_path: &Path, source_path: &Path, language: &str) -> Option<String> {
+    let relative_path = source_path.strip_prefix(project_path).ok()?;
+    let mut path_components: Vec<String> = relative_path
+        .with_extension("")
+        .components()
+        .map(|path_component| path_component.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let source_root = if path_components.first().map(String::as_str) == Some("src") {
+        path_components.remove(0);
+        project_path.join("src")
+    } else if language == "Rust" {
+        return None;
+    } else {
+        project_path.to_path_buf()
+    };
+
+    let module_name = path_components.first()?.clone();
+    let is_module = path_components.len() == 1
+        || language == "Rust"
+        || source_root.join(&module_name).join("__init__.py").is_file();
+    is_module.then_some(module_name)
+}
+
+/// Crate names from `use krate::...` and `extern crate krate;`
+fn extract_rust_imports(rust_source: &str) -> Vec<String> {
+    let import_regex = regex::Regex::new(
+        r"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:use\s+(?:::)?([A-Za-z_]\w*)|extern\s+crate\s+([A-Za-z_]\w*))",
+    )
+    .unwrap();
+    let mut crate_names: Vec<String> = Vec::new();
+
+    for source_line in rust_source.lines() {
+        let Some(captures) = import_regex.captures(source_line) else {
+            continue;
+        };
+        let Some(crate_name) = captures.get(1).or_else(|| captures.get(2)) else {
+            continue;
+        };
+        let crate_name = crate_name.as_str();
+        if !RUST_BUILTIN_ROOTS.contains(&crate_name) && !crate_names.iter().any(|c| c == crate_name)
+        {
+            crate_names.push(crate_name.to_string());
+        }
+    }
+
+    crate_names
+}
+
+/// Module names from `mod name;` and `mod name { ... }`
+fn extract_rust_module_declarations(rust_source: &str) -> Vec<String> {
+    let module_regex =
+        regex::Regex::new(r"^\s*(?:pub(?:\([^)]*\))?\s+)?mod\s+([A-Za-z_]\w*)").unwrap();
+    rust_source
+        .lines()
+        .filter_map(|source_line| module_regex.captures(source_line))
+        .map(|captures| captures[1].to_string())
+        .collect()
+}
+
+/// Lower-case roots of `root::path` expressions outside `use` lines and comments
+fn extract_rust_path_roots(rust_source: &str) -> Vec<String> {
+    let path_root_regex = regex::Regex::new(r"(?:^|[^\w:])([a-z_][a-z0-9_]*)::").unwrap();
+    let mut path_roots: Vec<String> = Vec::new();
+
+    for source_line in rust_source.lines() {
+        let trimmed_line = source_line.trim_start();
+        if trimmed_line.starts_with("//") || trimmed_line.starts_with("use ") {
+            continue;
+        }
+        for captures in path_root_regex.captures_iter(source_line) {
+            let path_root = &captures[1];
+            if !RUST_BUILTIN_ROOTS.contains(&path_root)
+                && !path_roots.iter().any(|p| p == path_root)
+            {
+                path_roots.push(path_root.to_string());
+            }
+        }
+    }
+
+    path_roots
+}
+
+/// npm package names from `import`, `export ... from`, `require()` and `import()`
+fn extract_javascript_imports(javascript_source: &str) -> Vec<String> {
+    let import_regex = regex::Regex::new(
+        r#"(?:\bfrom|\bimport|\brequire\s*\(|\bimport\s*\()\s*['"]([^'"\n]+)['"]"#,
+    )
+    .unwrap();
+    let mut package_names: Vec<String> = Vec::new();
+
+    for captures in import_regex.captures_iter(javascript_source) {
+        if let Some(package_name) = javascript_package_name(&captures[1]) {
+            if !package_names.contains(&package_name) {
+                package_names.push(package_name);
+            }
+        }
+    }
+
+    package_names
+}
+
+/// `@scope/name/sub/path` becomes `@scope/name`; relative paths, aliases,
+/// URLs and runtime built-ins (`node:fs`) name no package
+fn javascript_package_name(module_specifier: &str) -> Option<String> {
+    let deno_specifier = module_specifier
+        .strip_prefix("npm:")
+        .or_else(|| module_specifier.strip_prefix("jsr:"));
+    let module_specifier = deno_specifier.unwrap_or(module_specifier);
+    if module_specifier.starts_with(['.', '/', '#', '~'])
+        || module_specifier.contains(':')
+        || module_specifier.starts_with("@/")
+    {
+        return None;
+    }
+
+    let mut path_segments = module_specifier.split('/');
+    let first_segment = path_segments.next()?;
+    let package_name = if first_segment.starts_with('@') {
+        format!("{}/{}", first_segment, path_segments.next()?)
+    } else {
+        first_segment.to_string()
+    };
+    // Deno specifiers carry a version: `npm:hono@4`, `jsr:@std/path@1`
+    let version_index = package_name
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == '@')
+        .map(|(version_index, _)| version_index)
+        .filter(|_| deno_specifier.is_some());
+    let package_name = match version_index {
+        Some(version_index) => package_name[..version_index].to_string(),
+        None => package_name,
+    };
+    (!package_name.is_empty()).then_some(package_name)
+}
+
+/// Import paths from single-line and grouped Go import declarations
+fn extract_go_imports(go_source: &str) -> Vec<String> {
+    let import_path_regex = regex::Regex::new(r#"^\s*(?:[\w.]+\s+)?"([^"]+)""#).unwrap();
+    let mut import_paths: Vec<String> = Vec::new();
+    let mut in_import_block = false;
+
+    for source_line in go_source.lines() {
+        let trimmed_line = source_line.trim();
+        let import_spec = if in_import_block {
+            if trimmed_line.starts_with(')') {
+                in_import_block = false;
+                continue;
+            }
+            trimmed_line
+        } else if let Some(import_rest) = trimmed_line.strip_prefix("import") {
+            let import_rest = import_rest.trim_start();
+            if import_rest.starts_with('(') {
+                in_import_block = true;
+                continue;
+            }
+            import_rest
+        } else {
+            continue;
+        };
+
+        if let Some(captures) = import_path_regex.captures(import_spec) {
+            let import_path = captures[1].to_string();
+            if !import_paths.contains(&import_path) {
+                import_paths.push(import_path);
+            }
+        }
+    }
+
+    import_paths
+}
+
+/// The normalized module a PyPI distribution is imported as, where the two
+/// differ in more than separators
+fn python_package_import(normalized_package: &str) -> String {
+    // `psycopg2-binary` and `opencv-python-headless` are builds of the same package
+    let base_package = ["-binary", "-headless", "-cpu", "-gpu"]
+        .iter()
+        .find_map(|build_suffix| normalized_package.strip_suffix(build_suffix))
+        .unwrap_or(normalized_package);
+    if let Some((import_name, _)) = notebooks::PYTHON_IMPORT_DISTRIBUTIONS
+        .iter()
+        .find(|(_, distribution)| requirements::normalize_name(distribution) == base_package)
+    {
+        return requirements::normalize_name(import_name);
+    }
+    match base_package {
+        "pytorch" => "torch".to_string(),
+        // `google-cloud-storage` and `google-auth` live in the `google` namespace
+        _ if base_package.starts_with("google-") => "google".to_string(),
+        _ => base_package.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_imports() {
+        assert_eq!(
+            extract_rust_imports(
+                "use std::sync::Arc;\nuse axum::{routing::get, Router};\npub(crate) use ::serde_json;\nextern crate rocket;\nuse crate::routes;\n"
+            ),
+            vec!["axum", "serde_json", "rocket"]
+        );
+        assert_eq!(
+            extract_rust_path_roots("fn main() {\n    tauri::Builder::default().run(ctx);\n    // tokio::spawn\n    let n = std::env::args().count();\n}\n"),
+            vec!["tauri"]
+        );
+        assert_eq!(
+            extract_javascript_imports(
+                "import React from 'react';\nimport {\n  Button,\n} from \"@mui/material/Button\";\nimport './styles.css';\nconst express = require('express');\nconst fs = require('node:fs');\nimport { z } from 'npm:zod@3';\nexport * from '../lib';\nconst page = await import('next/dynamic');\n"
+            ),
+            vec!["react", "@mui/material", "express", "zod", "next"]
+        );
+        // The import regex also matches prose in comments
+        assert_eq!(
+            extract_javascript_imports(
+                "// value copied from 'ñandú'\nimport { serve } from 'jsr:@std/http@1';\n"
+            ),
+            vec!["ñandú", "@std/http"]
+        );
+        assert_eq!(
+            extract_go_imports(
+                "package main\n\nimport \"fmt\"\n\nimport (\n\t\"net/http\"\n\techo \"github.com/labstack/echo/v4\"\n\t_ \"github.com/lib/pq\"\n)\n"
+            ),
+            vec!["fmt", "net/http", "github.com/labstack/echo/v4", "github.com/lib/pq"]
+        );
+    }
+
+    #[test]
+    fn test_is_standard_library() {
+        for python_module in ["zlib", "pdb", "cProfile", "wsgiref", "socketserver"] {
+            assert!(is_standard_library("Python", python_module));
+        }
+        for node_module in ["zlib", "vm", "punycode", "wasi"] {
+            assert!(is_standard_library("JavaScript", node_module));
+        }
+        assert!(!is_standard_library("Python", "requests"));
+        assert!(!is_standard_library("JavaScript", "test"));
+    }
+
+    #[test]
+    fn test_python_distribution_imports() {
+        let python_imports = SourceImports {
+            language: "Python".to_string(),
+            source_file_count: 1,
+            imported_packages: [
+                "dotenv",
+                "dateutil",
+                "psycopg2",
+                "jwt",
+                "google",
+                "attr",
+                "cv2",
+                "multipart",
+            ]
+            .iter()
+            .map(|imported_package| imported_package.to_string())
+            .collect(),
+            path_roots: Vec::new(),
+            local_modules: Vec::new(),
+        };
+        let declared_packages: Vec<String> = [
+            "python-dotenv",
+            "python-dateutil",
+            "psycopg2-binary",
+            "PyJWT",
+            "protobuf",
+            "attrs",
+            "opencv-python-headless",
+            "python-multipart",
+            "google-cloud-storage",
+        ]
+        .iter()
+        .map(|package_name| package_name.to_string())
+        .collect();
+        for package_name in &declared_packages {
+            assert!(
+                python_imports.imports_package(package_name),
+                "{}",
+                package_name
+            );
+        }
+        assert!(python_imports
+            .undeclared_imports(&declared_packages)
+            .is_empty());
+        assert!(!python_imports.imports_package("python-jose"));
+    }
+
+    #[test]
+    fn test_scan_source_imports() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("app")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("venv").join("lib")).unwrap();
+        fs::write(
+            temp_dir.path().join("app").join("model.py"),
+            "import sklearn\nfrom fastapi import FastAPI\nimport torch.nn\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("app").join("__init__.py"), "").unwrap();
+        fs::write(
+            temp_dir.path().join("venv").join("lib").join("django.py"),
+            "import django\n",
+        )
+        .unwrap();
+        // `tests/requests/` is not a package, so `requests` still needs declaring
+        fs::create_dir_all(temp_dir.path().join("tests").join("requests")).unwrap();
+        fs::write(
+            temp_dir
+                .path()
+                .join("tests")
+                .join("requests")
+                .join("test_client.py"),
+            "import requests\nfrom app import model\n",
+        )
+        .unwrap();
+
+        let source_imports = scan_source_imports(temp_dir.path(), "Python").unwrap();
+        assert_eq!(source_imports.source_file_count, 3);
+        assert_eq!(source_imports.local_modules, vec!["app"]);
+        assert_eq!(
+            source_imports.undeclared_imports(&[
+                "scikit-learn".to_string(),
+                "fastapi".to_string(),
+                "torch".to_string(),
+            ]),
+            vec!["requests"]
+        );
+        assert!(source_imports.imports_package("scikit-learn"));
+        assert!(source_imports.imports_package("FastAPI"));
+        assert!(source_imports.imports_package("pytorch"));
+        assert!(!source_imports.imports_package("django"));
+        assert!(scan_source_imports(temp_dir.path(), "Go").is_none());
+
+        let go_imports = SourceImports {
+            language: "Go".to_string(),
+            source_file_count: 1,
+            imported_packages: vec![
+                "github.com/labstack/echo/v4/middleware".to_string(),
+                "net/http".to_string(),
+                "github.com/google/uuid".to_string(),
+            ],
+            path_roots: Vec::new(),
+            local_modules: Vec::new(),
+        };
+        assert!(go_imports.imports_package("github.com/labstack/echo"));
+        assert!(!go_imports.imports_package("github.com/labstack/ec"));
+        assert_eq!(
+            go_imports.undeclared_imports(&["github.com/labstack/echo/v4".to_string()]),
+            vec!["github.com/google/uuid"]
+        );
+    }
+}
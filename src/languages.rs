@@ -8,44 +8,105 @@ pub fn get_language_status(language: &str, version: &str) -> LanguageStatus {
         "Go" => get_go_status(version),
         "Python" => get_python_status(version),
         "Java" => get_java_status(version),
+        "Scala" => get_scala_status(version),
         _ => LanguageStatus::Unknown,
     }
 }
 
 fn get_rust_status(version_string: &str) -> LanguageStatus {
-    let normalized_version = clean_version_string(version_string);
+    let resolved_version =
+        resolve_rust_channel(version_string).unwrap_or_else(|| version_string.to_string());
+    let normalized_version = clean_version_string(&resolved_version);
 
-    match normalized_version.as_str() {
-        version
-            if version.starts_with("1.75")
-                || version.starts_with("1.76")
-                || version.starts_with("1.77")
-                || version.starts_with("1.78") =>
-        {
-            LanguageStatus::Supported
-        }
-        version
-            if version.starts_with("1.70")
-                || version.starts_with("1.71")
-                || version.starts_with("1.72")
-                || version.starts_with("1.73")
-                || version.starts_with("1.74") =>
-        {
-            LanguageStatus::EndingSoon {
-                date: "2024-12-31".to_string(),
-            }
-        }
-        version if version.starts_with("1.6") || version.starts_with("1.5") => {
-            LanguageStatus::EndOfLife {
-                date: "2023-01-01".to_string(),
-            }
-        }
+    let minor_version = normalized_version
+        .strip_prefix("1.")
+        .and_then(|minor_and_patch| minor_and_patch.split('.').next()?.parse::<u32>().ok());
+
+    match minor_version {
+        // Every release from 1.75 on, including channels resolved past the latest stable
+        Some(minor_version) if minor_version >= 75 => LanguageStatus::Supported,
+        Some(70..=74) => LanguageStatus::EndingSoon {
+            date: "2024-12-31".to_string(),
+        },
+        Some(50..=69) => LanguageStatus::EndOfLife {
+            date: "2023-01-01".to_string(),
+        },
         _ => LanguageStatus::Unknown,
     }
 }
 
+/// Resolve dated toolchain channels (`nightly-2024-05-01`, `beta-2024-05-01`) to the
+/// stable release they turn into
+pub fn resolve_rust_channel(version_string: &str) -> Option<String> {
+    let (channel_name, channel_date) = version_string.trim().split_once('-')?;
+    let release_offset = match channel_name {
+        "stable" => 0,
+        "beta" => 1,
+        "nightly" => 2,
+        _ => return None,
+    };
+
+    let mut date_fields = channel_date
+        .splitn(3, '-')
+        .map(|field| field.parse::<i64>());
+    let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) =
+        (date_fields.next(), date_fields.next(), date_fields.next())
+    else {
+        return None;
+    };
+
+    // Stable releases ship every six weeks; 1.70.0 shipped on 2023-06-01
+    let days_since_anchor = days_from_civil(year, month, day) - days_from_civil(2023, 6, 1);
+    let stable_minor_version = 70 + days_since_anchor.div_euclid(42);
+    if stable_minor_version < 0 {
+        return None;
+    }
+    Some(format!("1.{}", stable_minor_version + release_offset))
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Resolve `.nvmrc` style LTS aliases (`lts/hydrogen`, `lts/*`) to a major version
+pub fn resolve_node_alias(version_string: &str) -> Option<String> {
+    let lts_codename = version_string.trim().to_lowercase();
+    let lts_codename = lts_codename.strip_prefix("lts/")?;
+    let node_major_version = match lts_codename {
+        "argon" => 4,
+        "boron" => 6,
+        "carbon" => 8,
+        "dubnium" => 10,
+        "erbium" => 12,
+        "fermium" => 14,
+        "gallium" => 16,
+        "hydrogen" => 18,
+        "iron" => 20,
+        "jod" => 22,
+        "krypton" | "*" => 24,
+        _ => return None,
+    };
+    Some(node_major_version.to_string())
+}
+
 fn get_node_status(version_string: &str) -> LanguageStatus {
-    let normalized_version = clean_version_string(version_string);
+    // `node`, `latest` and friends always track the newest release
+    if matches!(
+        version_string.trim(),
+        "node" | "latest" | "current" | "stable"
+    ) {
+        return LanguageStatus::Supported;
+    }
+
+    let version_string =
+        resolve_node_alias(version_string).unwrap_or_else(|| version_string.to_string());
+    let normalized_version = clean_version_string(&version_string);
     let node_major_version = extract_major_version(&normalized_version);
 
     match node_major_version {
@@ -144,6 +205,149 @@ fn get_java_status(version_string: &str) -> LanguageStatus {
     }
 }
 
+fn get_scala_status(version_string: &str) -> LanguageStatus {
+    let normalized_version = clean_version_string(version_string);
+    let version_segments: Vec<&str> = normalized_version.split('.').collect();
+
+    if version_segments.len() < 2 {
+        return LanguageStatus::Unknown;
+    }
+
+    let scala_major = version_segments[0].parse::<u32>().unwrap_or(0);
+    let scala_minor = version_segments[1].parse::<u32>().unwrap_or(0);
+
+    match (scala_major, scala_minor) {
+        // 3.3 is the first LTS line; 3.0-3.2 stopped receiving fixes once it shipped
+        (3, minor) if minor >= 3 => LanguageStatus::Supported,
+        (3, _) => LanguageStatus::EndOfLife {
+            date: "2023-05-30".to_string(),
+        },
+        (2, 13) => LanguageStatus::Supported,
+        (2, 12) => LanguageStatus::EndingSoon {
+            date: "2025-12-31".to_string(),
+        },
+        (2, _) => LanguageStatus::EndOfLife {
+            date: "2017-11-09".to_string(),
+        },
+        _ => LanguageStatus::Unknown,
+    }
+}
+
+/// Get the end-of-life status for a database, cache or search engine version
+pub fn get_service_status(technology: &str, version: &str) -> LanguageStatus {
+    match technology {
+        "PostgreSQL" => get_postgres_status(version),
+        "MySQL" => get_mysql_status(version),
+        "Redis" => get_redis_status(version),
+        "MongoDB" => get_mongodb_status(version),
+        "Elasticsearch" => get_elasticsearch_status(version),
+        _ => LanguageStatus::Unknown,
+    }
+}
+
+fn get_postgres_status(version_string: &str) -> LanguageStatus {
+    let normalized_version = clean_version_string(version_string);
+    // Before 10 the major version had two segments (9.6)
+    match extract_major_version(&normalized_version) {
+        0 => LanguageStatus::Unknown,
+        version if version >= 15 => LanguageStatus::Supported,
+        14 => LanguageStatus::EndingSoon {
+            date: "2026-11-12".to_string(),
+        },
+        13 => LanguageStatus::EndOfLife {
+            date: "2025-11-13".to_string(),
+        },
+        12 => LanguageStatus::EndOfLife {
+            date: "2024-11-21".to_string(),
+        },
+        11 => LanguageStatus::EndOfLife {
+            date: "2023-11-09".to_string(),
+        },
+        10 => LanguageStatus::EndOfLife {
+            date: "2022-11-10".to_string(),
+        },
+        _ => LanguageStatus::EndOfLife {
+            date: "2021-11-11".to_string(),
+        },
+    }
+}
+
+fn get_mysql_status(version_string: &str) -> LanguageStatus {
+    let normalized_version = clean_version_string(version_string);
+    let version_segments: Vec<u32> = normalized_version
+        .split('.')
+        .map_while(|segment| segment.parse().ok())
+        .collect();
+
+    match version_segments.as_slice() {
+        [] => LanguageStatus::Unknown,
+        // 8.4 is the LTS line; 9.x are innovation releases
+        [major, ..] if *major >= 9 => LanguageStatus::Supported,
+        [8, minor, ..] if *minor >= 4 => LanguageStatus::Supported,
+        [8] => LanguageStatus::Supported,
+        [8, ..] => LanguageStatus::EndOfLife {
+            date: "2026-04-30".to_string(),
+        },
+        [5, 7, ..] => LanguageStatus::EndOfLife {
+            date: "2023-10-31".to_string(),
+        },
+        _ => LanguageStatus::EndOfLife {
+            date: "2021-02-01".to_string(),
+        },
+    }
+}
+
+fn get_redis_status(version_string: &str) -> LanguageStatus {
+    let normalized_version = clean_version_string(version_string);
+
+    match extract_major_version(&normalized_version) {
+        0 => LanguageStatus::Unknown,
+        version if version >= 7 => LanguageStatus::Supported,
+        6 => LanguageStatus::EndOfLife {
+            date: "2025-02-28".to_string(),
+        },
+        _ => LanguageStatus::EndOfLife {
+            date: "2022-04-30".to_string(),
+        },
+    }
+}
+
+fn get_mongodb_status(version_string: &str) -> LanguageStatus {
+    let normalized_version = clean_version_string(version_string);
+
+    match extract_major_version(&normalized_version) {
+        0 => LanguageStatus::Unknown,
+        version if version >= 8 => LanguageStatus::Supported,
+        7 => LanguageStatus::EndingSoon {
+            date: "2027-08-31".to_string(),
+        },
+        6 => LanguageStatus::EndOfLife {
+            date: "2025-07-31".to_string(),
+        },
+        5 => LanguageStatus::EndOfLife {
+            date: "2024-10-31".to_string(),
+        },
+        _ => LanguageStatus::EndOfLife {
+            date: "2024-02-29".to_string(),
+        },
+    }
+}
+
+fn get_elasticsearch_status(version_string: &str) -> LanguageStatus {
+    let normalized_version = clean_version_string(version_string);
+
+    match extract_major_version(&normalized_version) {
+        0 => LanguageStatus::Unknown,
+        version if version >= 8 => LanguageStatus::Supported,
+        7 => LanguageStatus::EndOfLife {
+            date: "2026-01-15".to_string(),
+        },
+        _ => LanguageStatus::EndOfLife {
+            date: "2022-02-10".to_string(),
+        },
+    }
+}
+
 /// Clean version string by removing common prefixes and suffixes
 fn clean_version_string(raw_version: &str) -> String {
     raw_version
@@ -195,6 +399,8 @@ mod tests {
     #[test]
     fn test_rust_status() {
         assert_eq!(get_rust_status("1.75.0"), LanguageStatus::Supported);
+        assert_eq!(get_rust_status("1.79.0"), LanguageStatus::Supported);
+        assert_eq!(get_rust_status("1.85"), LanguageStatus::Supported);
         assert_eq!(
             get_rust_status("1.70.0"),
             LanguageStatus::EndingSoon {
@@ -207,6 +413,64 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_service_status() {
+        assert!(matches!(
+            get_service_status("PostgreSQL", "13"),
+            LanguageStatus::EndOfLife { .. }
+        ));
+        assert_eq!(
+            get_service_status("PostgreSQL", "16.2"),
+            LanguageStatus::Supported
+        );
+        assert_eq!(
+            get_service_status("MySQL", "8.4"),
+            LanguageStatus::Supported
+        );
+        assert!(matches!(
+            get_service_status("MySQL", "5.7"),
+            LanguageStatus::EndOfLife { .. }
+        ));
+        assert_eq!(
+            get_service_status("Redis", "7.2"),
+            LanguageStatus::Supported
+        );
+        assert!(matches!(
+            get_service_status("MongoDB", "7.0"),
+            LanguageStatus::EndingSoon { .. }
+        ));
+        assert_eq!(
+            get_service_status("Memcached", "1.6"),
+            LanguageStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_resolve_rust_channel() {
+        assert_eq!(
+            resolve_rust_channel("nightly-2024-05-01"),
+            Some("1.79".to_string())
+        );
+        assert_eq!(
+            resolve_rust_channel("beta-2024-05-01"),
+            Some("1.78".to_string())
+        );
+        assert_eq!(
+            resolve_rust_channel("stable-2023-12-28"),
+            Some("1.75".to_string())
+        );
+        assert_eq!(resolve_rust_channel("nightly"), None);
+        assert_eq!(resolve_rust_channel("1.75.0"), None);
+        assert_eq!(
+            get_rust_status("nightly-2024-05-01"),
+            LanguageStatus::Supported
+        );
+        assert_eq!(
+            get_rust_status("nightly-2023-11-20"),
+            LanguageStatus::Supported
+        );
+    }
+
     #[test]
     fn test_node_status() {
         assert_eq!(get_node_status("20.0.0"), LanguageStatus::Supported);
@@ -215,6 +479,21 @@ mod tests {
             get_node_status("14.0.0"),
             LanguageStatus::EndOfLife { .. }
         ));
+        assert_eq!(get_node_status("lts/iron"), LanguageStatus::Supported);
+        assert_eq!(get_node_status("node"), LanguageStatus::Supported);
+        assert!(matches!(
+            get_node_status("lts/fermium"),
+            LanguageStatus::EndOfLife { .. }
+        ));
+    }
+
+    #[test]
+    fn test_resolve_node_alias() {
+        assert_eq!(resolve_node_alias("lts/hydrogen"), Some("18".to_string()));
+        assert_eq!(resolve_node_alias("lts/Jod"), Some("22".to_string()));
+        assert_eq!(resolve_node_alias("lts/*"), Some("24".to_string()));
+        assert_eq!(resolve_node_alias("lts/unknown"), None);
+        assert_eq!(resolve_node_alias("20.11.0"), None);
     }
 
     #[test]
@@ -252,6 +531,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_scala_status() {
+        assert_eq!(get_scala_status("3.3.1"), LanguageStatus::Supported);
+        assert_eq!(get_scala_status("2.13.12"), LanguageStatus::Supported);
+        assert!(matches!(
+            get_scala_status("2.12.18"),
+            LanguageStatus::EndingSoon { .. }
+        ));
+        assert!(matches!(
+            get_scala_status("3.1.3"),
+            LanguageStatus::EndOfLife { .. }
+        ));
+        assert!(matches!(
+            get_scala_status("2.11.12"),
+            LanguageStatus::EndOfLife { .. }
+        ));
+    }
+
     #[test]
     fn test_unknown_language() {
         assert_eq!(
@@ -1,11 +1,62 @@
+mod build_tools;
+mod ci;
+mod code_quality;
+mod compose;
+mod config_files;
+mod container_images;
 mod detector;
+mod dockerfile;
+mod edn;
 mod frameworks;
+mod go_mod;
+mod imports;
 mod languages;
+mod notebooks;
+mod project_files;
 mod project_info;
+mod requirements;
+mod test_frameworks;
+mod tool_versions;
+mod version_consistency;
 
-pub use project_info::{LanguageStatus, ProjectInfo};
+pub use project_info::{
+    BackingServiceKind, BuildStage, BuildTool, CiPipeline, CodeQualityKind, CodeQualityTool,
+    ComposeService, DependencyReport, Dockerfile, FrameworkCategory, ImageRuntime, LanguageStatus,
+    NotebookSummary, ProjectInfo, RustToolchain, TestFramework, TestSetup, ToolVersion,
+    VersionConflict, VersionConsistency, VersionSource,
+};
+
+/// Optional analyses that `examine` leaves off because they read every source file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExamineOptions {
+    /// Confirm declared frameworks against the imports in source files, detect
+    /// frameworks from imports alone when no manifest declares one, and report
+    /// unused and undeclared dependencies
+    pub scan_imports: bool,
+}
+
+impl ExamineOptions {
+    /// Options with every optional analysis turned off
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn the source import scan on or off
+    pub fn with_import_scan(mut self, scan_imports: bool) -> Self {
+        self.scan_imports = scan_imports;
+        self
+    }
+}
 
 /// Examines the project at the given path and returns its information.
 pub fn examine<P: AsRef<std::path::Path>>(path: P) -> Result<ProjectInfo, String> {
     detector::detect_project_info(path.as_ref())
 }
+
+/// Examines the project at the given path with optional analyses turned on.
+pub fn examine_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    options: &ExamineOptions,
+) -> Result<ProjectInfo, String> {
+    detector::detect_project_info_with_options(path.as_ref(), options)
+}
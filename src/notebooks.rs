@@ -0,0 +1,326 @@
+//! Jupyter notebook reader.
+//!
+//! Notebooks are plain JSON: the kernel comes from `metadata.kernelspec` and
+//! `metadata.language_info`, and the libraries a notebook uses are read from
+//! the `import` statements in its code cells.
+
+use crate::project_files;
+use crate::project_info::NotebookSummary;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How deep to look for notebooks below the project root
+const MAX_SEARCH_DEPTH: usize = 4;
+
+/// A single parsed `.ipynb` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notebook {
+    /// Path relative to the project root
+    pub relative_path: PathBuf,
+    /// Project language of the kernel, e.g. `R` for the `ir` kernel
+    pub language: Option<&'static str>,
+    pub language_version: Option<String>,
+    pub kernel_name: Option<String>,
+    /// Top-level modules imported by code cells, in first-seen order
+    pub imported_modules: Vec<String>,
+}
+
+/// Every notebook in the project, sorted by path
+pub fn read_notebooks(project_path: &Path) -> Vec<Notebook> {
+    let notebook_paths =
+        project_files::find_project_files(project_path, MAX_SEARCH_DEPTH, &|directory_entry| {
+            directory_entry
+                .path()
+                .extension()
+                .is_some_and(|e| e == "ipynb")
+        });
+
+    notebook_paths
+        .iter()
+        .filter_map(|notebook_path| {
+            let mut notebook = read_notebook(notebook_path)?;
+            notebook.relative_path = notebook_path
+                .strip_prefix(project_path)
+                .unwrap_or(notebook_path)
+                .to_path_buf();
+            Some(notebook)
+        })
+        .collect()
+}
+
+/// Parse a notebook file, returning `None` if it isn't valid notebook JSON
+pub fn read_notebook(notebook_path: &Path) -> Option<Notebook> {
+    let notebook_content = fs::read_to_string(notebook_path).ok()?;
+    let notebook_json: serde_json::Value = serde_json::from_str(&notebook_content).ok()?;
+    let notebook_metadata = notebook_json.get("metadata");
+
+    let kernelspec = notebook_metadata.and_then(|metadata| metadata.get("kernelspec"));
+    let language_info = notebook_metadata.and_then(|metadata| metadata.get("language_info"));
+    let metadata_string = |section: Option<&serde_json::Value>, key: &str| {
+        section
+            .and_then(|section| section.get(key))
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    };
+
+    let kernel_language = metadata_string(language_info, "name")
+        .or_else(|| metadata_string(kernelspec, "language"))
+        .unwrap_or_else(|| "python".to_string());
+    let language = kernel_project_language(&kernel_language);
+
+    // nbformat 4 keeps cells at the top level; nbformat 3 nests them in worksheets
+    let notebook_cells: Vec<&serde_json::Value> = match notebook_json.get("cells") {
+        Some(cells) => cells.as_array().into_iter().flatten().collect(),
+        None => notebook_json
+            .get("worksheets")
+            .and_then(|worksheets| worksheets.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|worksheet| worksheet.get("cells").and_then(|c| c.as_array()))
+            .flatten()
+            .collect(),
+    };
+
+    let mut imported_modules = Vec::new();
+    if language == Some("Python") {
+        for code_cell in notebook_cells
+            .iter()
+            .filter(|cell| cell.get("cell_type").and_then(|t| t.as_str()) == Some("code"))
+        {
+            let cell_source = code_cell.get("source").or_else(|| code_cell.get("input"));
+            for imported_module in extract_python_imports(&join_cell_source(cell_source)) {
+                if !imported_modules.contains(&imported_module) {
+                    imported_modules.push(imported_module);
+                }
+            }
+        }
+    }
+
+    Some(Notebook {
+        relative_path: notebook_path.to_path_buf(),
+        language,
+        language_version: metadata_string(language_info, "version"),
+        kernel_name: metadata_string(kernelspec, "name"),
+        imported_modules,
+    })
+}
+
+/// Cell sources are either one string or a list of lines
+fn join_cell_source(cell_source: Option<&serde_json::Value>) -> String {
+    match cell_source {
+        Some(serde_json::Value::String(source_text)) => source_text.clone(),
+        Some(serde_json::Value::Array(source_lines)) => source_lines
+            .iter()
+            .filter_map(|source_line| source_line.as_str())
+            .collect(),
+        _ => String::new(),
+    }
+}
+
+/// Top-level module names from `import a.b, c as d` and `from e.f import g` lines
+pub fn extract_python_imports(python_source: &str) -> Vec<String> {
+    let import_regex =
+        regex::Regex::new(r"^\s*(?:from\s+([A-Za-z_][\w.]*)\s+import\b|import\s+([\w.,\s]+))")
+            .unwrap();
+    let mut imported_modules = Vec::new();
+
+    for source_line in python_source.lines() {
+        let Some(captures) = import_regex.captures(source_line) else {
+            continue;
+        };
+        let module_paths: Vec<&str> = match (captures.get(1), captures.get(2)) {
+            (Some(from_module), _) => vec![from_module.as_str()],
+            (None, Some(import_list)) => import_list
+                .as_str()
+                .split(',')
+                .filter_map(|import_item| import_item.split_whitespace().next())
+                .collect(),
+            _ => continue,
+        };
+        for module_path in module_paths {
+            let top_level_module = module_path.split('.').next().unwrap_or(module_path);
+            if !top_level_module.is_empty()
+                && !imported_modules.iter().any(|m| m == top_level_module)
+            {
+                imported_modules.push(top_level_module.to_string());
+            }
+        }
+    }
+
+    imported_modules
+}
+
+/// Import names and the PyPI distribution that provides them, where the two
+/// differ in more than separators and case
+pub const PYTHON_IMPORT_DISTRIBUTIONS: [(&str, &str); 41] = [
+    ("sklearn", "scikit-learn"),
+    ("skimage", "scikit-image"),
+    ("airflow", "apache-airflow"),
+    ("cv2", "opencv-python"),
+    ("PIL", "pillow"),
+    ("yaml", "pyyaml"),
+    ("bs4", "beautifulsoup4"),
+    ("dotenv", "python-dotenv"),
+    ("dateutil", "python-dateutil"),
+    ("multipart", "python-multipart"),
+    ("jose", "python-jose"),
+    ("slugify", "python-slugify"),
+    ("magic", "python-magic"),
+    ("docx", "python-docx"),
+    ("pptx", "python-pptx"),
+    ("ldap", "python-ldap"),
+    ("socketio", "python-socketio"),
+    ("engineio", "python-engineio"),
+    ("telegram", "python-telegram-bot"),
+    ("Levenshtein", "python-levenshtein"),
+    ("jwt", "pyjwt"),
+    ("OpenSSL", "pyopenssl"),
+    ("serial", "pyserial"),
+    ("usb", "pyusb"),
+    ("zmq", "pyzmq"),
+    ("gi", "pygobject"),
+    ("fitz", "pymupdf"),
+    ("win32api", "pywin32"),
+    ("Crypto", "pycryptodome"),
+    ("attr", "attrs"),
+    ("google", "protobuf"),
+    ("googleapiclient", "google-api-python-client"),
+    ("grpc", "grpcio"),
+    ("dns", "dnspython"),
+    ("git", "gitpython"),
+    ("MySQLdb", "mysqlclient"),
+    ("websocket", "websocket-client"),
+    ("discord", "discord.py"),
+    ("kafka", "kafka-python"),
+    ("Bio", "biopython"),
+    ("pkg_resources", "setuptools"),
+];
+
+/// The PyPI distribution that provides an import name, where the two differ
+pub fn python_import_package(module_name: &str) -> &str {
+    PYTHON_IMPORT_DISTRIBUTIONS
+        .iter()
+        .find(|(import_name, _)| *import_name == module_name)
+        .map_or(module_name, |(_, distribution)| distribution)
+}
+
+/// Map a kernel's language name to the language `examine` reports
+fn kernel_project_language(kernel_language: &str) -> Option<&'static str> {
+    match kernel_language.to_lowercase().as_str() {
+        "python" | "python3" | "ipython" => Some("Python"),
+        "r" => Some("R"),
+        "julia" => Some("Julia"),
+        "scala" => Some("Scala"),
+        "javascript" | "typescript" => Some("JavaScript"),
+        "rust" => Some("Rust"),
+        "go" => Some("Go"),
+        "java" => Some("Java"),
+        "c++" | "cpp" => Some("C++"),
+        "c#" | "csharp" => Some("C#"),
+        "clojure" => Some("Clojure"),
+        "ocaml" => Some("OCaml"),
+        "lua" => Some("Lua"),
+        "ruby" => Some("Ruby"),
+        "haskell" => Some("Haskell"),
+        _ => None,
+    }
+}
+
+/// Totals across notebooks; the kernel is the most common one
+pub fn summarize_notebooks(notebooks: &[Notebook]) -> Option<NotebookSummary> {
+    let mut language_counts: HashMap<&str, usize> = HashMap::new();
+    for notebook_language in notebooks.iter().filter_map(|notebook| notebook.language) {
+        *language_counts.entry(notebook_language).or_insert(0) += 1;
+    }
+    let kernel_language = notebooks
+        .iter()
+        .filter_map(|notebook| notebook.language)
+        .max_by_key(|notebook_language| language_counts[notebook_language]);
+
+    let kernel_notebook = notebooks
+        .iter()
+        .find(|notebook| notebook.language.is_some() && notebook.language == kernel_language);
+
+    let mut imported_libraries: Vec<String> = Vec::new();
+    for imported_module in notebooks
+        .iter()
+        .flat_map(|notebook| notebook.imported_modules.iter())
+    {
+        if !imported_libraries.contains(imported_module) {
+            imported_libraries.push(imported_module.clone());
+        }
+    }
+
+    (!notebooks.is_empty()).then(|| NotebookSummary {
+        notebook_count: notebooks.len(),
+        kernel_language: kernel_language.map(|language| language.to_string()),
+        kernel_name: kernel_notebook.and_then(|notebook| notebook.kernel_name.clone()),
+        kernel_version: notebooks
+            .iter()
+            .filter(|notebook| notebook.language == kernel_language)
+            .find_map(|notebook| notebook.language_version.clone()),
+        imported_libraries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_python_imports() {
+        let imported_modules = extract_python_imports(
+            "import numpy as np\nimport os, sys\nfrom sklearn.model_selection import train_test_split\n  import torch.nn as nn\n# import commented\nprint('import x')\n",
+        );
+        assert_eq!(
+            imported_modules,
+            vec!["numpy", "os", "sys", "sklearn", "torch"]
+        );
+        assert_eq!(python_import_package("sklearn"), "scikit-learn");
+        assert_eq!(python_import_package("torch"), "torch");
+    }
+
+    #[test]
+    fn test_read_notebooks() {
+        let temp_dir = TempDir::new().unwrap();
+        let notebooks_dir = temp_dir.path().join("notebooks");
+        fs::create_dir_all(notebooks_dir.join(".ipynb_checkpoints")).unwrap();
+        fs::write(
+            notebooks_dir.join("train.ipynb"),
+            r#"{"nbformat": 4, "metadata": {"kernelspec": {"name": "python3", "language": "python"},
+                "language_info": {"name": "python", "version": "3.11.5"}},
+                "cells": [
+                    {"cell_type": "markdown", "source": ["import pandas"]},
+                    {"cell_type": "code", "source": ["import torch\n", "from transformers import AutoModel\n"]}
+                ]}"#,
+        )
+        .unwrap();
+        fs::write(
+            notebooks_dir
+                .join(".ipynb_checkpoints")
+                .join("train-checkpoint.ipynb"),
+            r#"{"metadata": {}, "cells": []}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("explore.ipynb"),
+            r#"{"metadata": {"kernelspec": {"name": "ir", "language": "R"}}, "cells": []}"#,
+        )
+        .unwrap();
+
+        let notebooks = read_notebooks(temp_dir.path());
+        assert_eq!(notebooks.len(), 2);
+        assert_eq!(notebooks[0].language, Some("R"));
+        assert_eq!(
+            notebooks[1].relative_path,
+            Path::new("notebooks").join("train.ipynb")
+        );
+        assert_eq!(notebooks[1].imported_modules, vec!["torch", "transformers"]);
+
+        let summary = summarize_notebooks(&notebooks).unwrap();
+        assert_eq!(summary.notebook_count, 2);
+        assert_eq!(summary.imported_libraries, vec!["torch", "transformers"]);
+    }
+}
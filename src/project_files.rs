@@ -0,0 +1,116 @@
+//! Project file walker.
+//!
+//! Notebooks, source files and test files are found by walking the project
+//! tree. Hidden directories (`.git`, `.venv`, `.ipynb_checkpoints`) and the
+//! directories that hold dependencies, environments or build output are never
+//! entered, so vendored code is not mistaken for the project's own.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directories that hold dependencies, environments or build output
+const SKIPPED_DIRECTORIES: [&str; 8] = [
+    "node_modules",
+    "target",
+    "vendor",
+    "venv",
+    "site-packages",
+    "__pycache__",
+    "dist",
+    "build",
+];
+
+/// Files at most `max_depth` directories below the project root that
+/// `is_wanted` accepts, sorted by path
+pub fn find_project_files(
+    project_path: &Path,
+    max_depth: usize,
+    is_wanted: &dyn Fn(&fs::DirEntry) -> bool,
+) -> Vec<PathBuf> {
+    let mut file_paths = Vec::new();
+    collect_project_files(project_path, 0, max_depth, is_wanted, &mut file_paths);
+    file_paths.sort();
+    file_paths
+}
+
+fn collect_project_files(
+    directory: &Path,
+    depth: usize,
+    max_depth: usize,
+    is_wanted: &dyn Fn(&fs::DirEntry) -> bool,
+    file_paths: &mut Vec<PathBuf>,
+) {
+    let Ok(directory_entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for directory_entry in directory_entries.flatten() {
+        let entry_path = directory_entry.path();
+        let entry_name = directory_entry.file_name();
+        let entry_name = entry_name.to_string_lossy();
+
+        if entry_path.is_dir() {
+            if depth < max_depth
+                && !entry_name.starts_with('.')
+                && !SKIPPED_DIRECTORIES.contains(&entry_name.as_ref())
+            {
+                collect_project_files(&entry_path, depth + 1, max_depth, is_wanted, file_paths);
+            }
+        } else if is_wanted(&directory_entry) {
+            file_paths.push(entry_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_project_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        for directory in ["src/app/deep", "node_modules/pkg", ".venv/lib", "dist"] {
+            fs::create_dir_all(project_path.join(directory)).unwrap();
+        }
+        for file_path in [
+            "main.py",
+            "src/app/models.py",
+            "src/app/deep/util.py",
+            "node_modules/pkg/index.py",
+            ".venv/lib/site.py",
+            "dist/bundle.py",
+            "README.md",
+        ] {
+            fs::write(project_path.join(file_path), "").unwrap();
+        }
+
+        let is_python = |directory_entry: &fs::DirEntry| {
+            directory_entry
+                .path()
+                .extension()
+                .is_some_and(|e| e == "py")
+        };
+        let relative_paths = |file_paths: Vec<PathBuf>| -> Vec<String> {
+            file_paths
+                .iter()
+                .map(|file_path| {
+                    file_path
+                        .strip_prefix(project_path)
+                        .unwrap()
+                        .display()
+                        .to_string()
+                })
+                .collect()
+        };
+        assert_eq!(
+            relative_paths(find_project_files(project_path, 8, &is_python)),
+            vec!["main.py", "src/app/deep/util.py", "src/app/models.py"]
+        );
+        assert_eq!(
+            relative_paths(find_project_files(project_path, 2, &is_python)),
+            vec!["main.py", "src/app/models.py"]
+        );
+    }
+}
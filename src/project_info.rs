@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Complete information about a detected project
@@ -6,12 +7,40 @@ use std::fmt;
 pub struct ProjectInfo {
     pub language: String,
     pub language_version: Option<String>,
+    pub language_version_source: Option<String>,
     pub language_status: LanguageStatus,
     pub framework: Option<String>,
     pub framework_version: Option<String>,
     pub framework_details: Option<FrameworkDetails>,
+    pub framework_category: Option<FrameworkCategory>,
     pub project_name: Option<String>,
     pub project_path: String,
+    pub runtime: Option<String>,
+    pub runtime_version: Option<String>,
+    #[serde(default)]
+    pub cross_versions: Vec<String>,
+    #[serde(default)]
+    pub tool_versions: Vec<ToolVersion>,
+    #[serde(default)]
+    pub version_conflicts: Vec<VersionConflict>,
+    #[serde(default)]
+    pub version_consistency: Vec<VersionConsistency>,
+    pub notebooks: Option<NotebookSummary>,
+    pub rust_toolchain: Option<RustToolchain>,
+    #[serde(default)]
+    pub build_tools: Vec<BuildTool>,
+    pub testing: Option<TestSetup>,
+    #[serde(default)]
+    pub code_quality: Vec<CodeQualityTool>,
+    pub dependency_report: Option<DependencyReport>,
+    #[serde(default)]
+    pub ci_pipelines: Vec<CiPipeline>,
+    #[serde(default)]
+    pub dockerfiles: Vec<Dockerfile>,
+    #[serde(default)]
+    pub compose_services: Vec<ComposeService>,
+    #[serde(default)]
+    pub members: Vec<ProjectInfo>,
 }
 
 /// End of life status for language versions
@@ -23,6 +52,22 @@ pub enum LanguageStatus {
     Unknown,
 }
 
+/// Broad kind of project a framework is used for
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FrameworkCategory {
+    Web,
+    MachineLearning,
+    DataApp,
+    DataPipeline,
+    Mobile,
+    Desktop,
+    Embedded,
+    Infrastructure,
+    Game,
+    Cli,
+    Other,
+}
+
 /// Detailed information about the detected framework
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameworkDetails {
@@ -32,6 +77,196 @@ pub struct FrameworkDetails {
     pub description: Option<String>,
 }
 
+/// A tool version pinned in `.tool-versions` or `mise.toml`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ToolVersion {
+    pub tool: String,
+    pub language: Option<String>,
+    pub version: String,
+    pub source: String,
+}
+
+/// A version pin that disagrees with the version reported for a language
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionConflict {
+    pub language: String,
+    pub version: String,
+    pub version_source: Option<String>,
+    pub conflicting_version: String,
+    pub conflicting_source: String,
+}
+
+/// A version (or requirement) for a language and the file or field it came from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionSource {
+    pub version: String,
+    pub source: String,
+}
+
+/// Every version a language is declared or tested with, and the ones that disagree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionConsistency {
+    pub language: String,
+    /// Pins and requirements from version files, manifests, toolchain files and
+    /// container images. Minimums such as Cargo's `rust-version` and the go.mod
+    /// `go` line are recorded as `>=` requirements
+    pub declared_versions: Vec<VersionSource>,
+    /// Versions CI pipelines run against, with the pipeline config as the source
+    pub tested_versions: Vec<VersionSource>,
+    pub conflicts: Vec<VersionConflict>,
+}
+
+impl VersionConsistency {
+    /// Whether every declared and tested version can describe the same release
+    pub fn is_consistent(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Rust edition and toolchain settings from `Cargo.toml`, `rust-toolchain.toml`
+/// and `.cargo/config.toml`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RustToolchain {
+    pub edition: Option<String>,
+    pub channel: Option<String>,
+    pub profile: Option<String>,
+    pub components: Vec<String>,
+    pub targets: Vec<String>,
+    pub build_targets: Vec<String>,
+}
+
+/// A build system or bundler, e.g. Gradle from `build.gradle.kts`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BuildTool {
+    pub name: String,
+    pub version: Option<String>,
+    pub source: String,
+}
+
+/// How the project tests itself
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestSetup {
+    pub frameworks: Vec<TestFramework>,
+    /// Test directories relative to the project root, e.g. `tests` or `src/test`
+    pub test_directories: Vec<String>,
+}
+
+/// A test framework or runner, e.g. Vitest from `package.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestFramework {
+    pub name: String,
+    pub version: Option<String>,
+    pub source: String,
+}
+
+/// What a code quality tool checks or rewrites
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CodeQualityKind {
+    Linter,
+    Formatter,
+    TypeChecker,
+    EditorConfig,
+}
+
+/// A configured linter, formatter or type checker, e.g. Ruff from `pyproject.toml`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CodeQualityTool {
+    pub name: String,
+    pub kind: CodeQualityKind,
+    pub config_file: String,
+    /// Key settings where the config is declarative; line lengths are under `line_length`
+    pub settings: BTreeMap<String, String>,
+}
+
+/// A CI/CD pipeline config, e.g. GitHub Actions from `.github/workflows/ci.yml`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CiPipeline {
+    pub provider: String,
+    pub config_file: String,
+    /// Language versions the pipeline runs or tests against, keyed by language
+    pub tested_versions: BTreeMap<String, Vec<String>>,
+}
+
+/// A Dockerfile or Containerfile and its build stages, in order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Dockerfile {
+    /// Path relative to the project root, e.g. `Dockerfile` or `docker/api.Dockerfile`
+    pub path: String,
+    pub stages: Vec<BuildStage>,
+}
+
+/// One `FROM` stage of a Dockerfile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BuildStage {
+    /// The `AS` name, if the stage has one
+    pub name: Option<String>,
+    /// The image reference with build args resolved, or the earlier stage it builds on
+    pub base_image: String,
+    /// The language runtime the base image ships, inherited from earlier stages
+    pub runtime: Option<ImageRuntime>,
+    pub exposed_ports: Vec<String>,
+    pub entrypoint: Option<String>,
+    pub cmd: Option<String>,
+}
+
+/// A language runtime baked into a container image, e.g. Python 3.8 from `python:3.8-slim`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImageRuntime {
+    pub language: String,
+    pub version: String,
+    pub status: LanguageStatus,
+}
+
+impl Dockerfile {
+    /// The stage `docker build` produces when no `--target` is given
+    pub fn final_stage(&self) -> Option<&BuildStage> {
+        self.stages.last()
+    }
+}
+
+/// What a backing service does for the application
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BackingServiceKind {
+    Database,
+    Cache,
+    Queue,
+    Search,
+}
+
+/// A service from `compose.yaml` or `docker-compose*.yml`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ComposeService {
+    pub name: String,
+    /// The image reference; `None` when the service only has a `build:` section
+    pub image: Option<String>,
+    /// The database, cache, queue or search engine the image runs, e.g. `PostgreSQL`
+    pub technology: Option<String>,
+    pub kind: Option<BackingServiceKind>,
+    pub version: Option<String>,
+    pub status: LanguageStatus,
+    pub source: String,
+}
+
+/// Jupyter notebooks found in the project
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotebookSummary {
+    pub notebook_count: usize,
+    pub kernel_language: Option<String>,
+    pub kernel_name: Option<String>,
+    pub kernel_version: Option<String>,
+    pub imported_libraries: Vec<String>,
+}
+
+/// Manifest dependencies checked against the imports in source files
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DependencyReport {
+    pub source_file_count: usize,
+    /// Declared dependencies that no source file imports
+    pub unused_dependencies: Vec<String>,
+    /// Imported packages that no manifest declares
+    pub undeclared_imports: Vec<String>,
+}
+
 impl fmt::Display for LanguageStatus {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -43,18 +278,79 @@ impl fmt::Display for LanguageStatus {
     }
 }
 
+impl fmt::Display for FrameworkCategory {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category_name = match self {
+            FrameworkCategory::Web => "Web",
+            FrameworkCategory::MachineLearning => "Machine Learning",
+            FrameworkCategory::DataApp => "Data App",
+            FrameworkCategory::DataPipeline => "Data Pipeline",
+            FrameworkCategory::Mobile => "Mobile",
+            FrameworkCategory::Desktop => "Desktop",
+            FrameworkCategory::Embedded => "Embedded",
+            FrameworkCategory::Infrastructure => "Infrastructure",
+            FrameworkCategory::Game => "Game",
+            FrameworkCategory::Cli => "CLI",
+            FrameworkCategory::Other => "Other",
+        };
+        write!(formatter, "{}", category_name)
+    }
+}
+
+impl fmt::Display for BackingServiceKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind_name = match self {
+            BackingServiceKind::Database => "Database",
+            BackingServiceKind::Cache => "Cache",
+            BackingServiceKind::Queue => "Queue",
+            BackingServiceKind::Search => "Search",
+        };
+        write!(formatter, "{}", kind_name)
+    }
+}
+
+impl fmt::Display for CodeQualityKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind_name = match self {
+            CodeQualityKind::Linter => "Linter",
+            CodeQualityKind::Formatter => "Formatter",
+            CodeQualityKind::TypeChecker => "Type Checker",
+            CodeQualityKind::EditorConfig => "Editor Config",
+        };
+        write!(formatter, "{}", kind_name)
+    }
+}
+
 impl ProjectInfo {
     /// Create a new project info instance
     pub fn new<P: Into<String>>(detected_language: String, analyzed_project_path: P) -> Self {
         Self {
             language: detected_language,
             language_version: None,
+            language_version_source: None,
             language_status: LanguageStatus::Unknown,
             framework: None,
             framework_version: None,
             framework_details: None,
+            framework_category: None,
             project_name: None,
             project_path: analyzed_project_path.into(),
+            runtime: None,
+            runtime_version: None,
+            cross_versions: Vec::new(),
+            tool_versions: Vec::new(),
+            version_conflicts: Vec::new(),
+            version_consistency: Vec::new(),
+            notebooks: None,
+            rust_toolchain: None,
+            build_tools: Vec::new(),
+            testing: None,
+            code_quality: Vec::new(),
+            dependency_report: None,
+            ci_pipelines: Vec::new(),
+            dockerfiles: Vec::new(),
+            compose_services: Vec::new(),
+            members: Vec::new(),
         }
     }
 
@@ -64,6 +360,12 @@ impl ProjectInfo {
         self
     }
 
+    /// Set the file the language version was read from
+    pub fn with_language_version_source(mut self, version_source: String) -> Self {
+        self.language_version_source = Some(version_source);
+        self
+    }
+
     /// Set the language status
     pub fn with_language_status(mut self, eol_status: LanguageStatus) -> Self {
         self.language_status = eol_status;
@@ -87,12 +389,142 @@ impl ProjectInfo {
         self
     }
 
+    /// Set the category of the detected framework
+    pub fn with_framework_category(mut self, detected_category: FrameworkCategory) -> Self {
+        self.framework_category = Some(detected_category);
+        self
+    }
+
     /// Set the project name
     pub fn with_project_name(mut self, detected_name: String) -> Self {
         self.project_name = Some(detected_name);
         self
     }
 
+    /// Set the runtime or dialect the project targets
+    pub fn with_runtime(
+        mut self,
+        detected_runtime: String,
+        detected_runtime_version: Option<String>,
+    ) -> Self {
+        self.runtime = Some(detected_runtime);
+        self.runtime_version = detected_runtime_version;
+        self
+    }
+
+    /// Set the additional language versions the project is cross-built for
+    pub fn with_cross_versions(mut self, detected_cross_versions: Vec<String>) -> Self {
+        self.cross_versions = detected_cross_versions;
+        self
+    }
+
+    /// Set the tool versions pinned for the project
+    pub fn with_tool_versions(mut self, pinned_tool_versions: Vec<ToolVersion>) -> Self {
+        self.tool_versions = pinned_tool_versions;
+        self
+    }
+
+    /// Set the version pins that disagree with the detected language version
+    pub fn with_version_conflicts(mut self, detected_conflicts: Vec<VersionConflict>) -> Self {
+        self.version_conflicts = detected_conflicts;
+        self
+    }
+
+    /// Set the per-language reports of every declared and tested version
+    pub fn with_version_consistency(
+        mut self,
+        version_consistency_reports: Vec<VersionConsistency>,
+    ) -> Self {
+        self.version_consistency = version_consistency_reports;
+        self
+    }
+
+    /// Set the Jupyter notebooks found in the project
+    pub fn with_notebooks(mut self, notebook_summary: NotebookSummary) -> Self {
+        self.notebooks = Some(notebook_summary);
+        self
+    }
+
+    /// Set the Rust edition and toolchain settings
+    pub fn with_rust_toolchain(mut self, detected_toolchain: RustToolchain) -> Self {
+        self.rust_toolchain = Some(detected_toolchain);
+        self
+    }
+
+    /// Set the build systems and bundlers the project uses
+    pub fn with_build_tools(mut self, detected_build_tools: Vec<BuildTool>) -> Self {
+        self.build_tools = detected_build_tools;
+        self
+    }
+
+    /// Set the test frameworks and test directories
+    pub fn with_testing(mut self, test_setup: TestSetup) -> Self {
+        self.testing = Some(test_setup);
+        self
+    }
+
+    /// Set the configured linters, formatters and type checkers
+    pub fn with_code_quality(mut self, code_quality_tools: Vec<CodeQualityTool>) -> Self {
+        self.code_quality = code_quality_tools;
+        self
+    }
+
+    /// Set the unused and undeclared dependency report
+    pub fn with_dependency_report(mut self, dependency_report: DependencyReport) -> Self {
+        self.dependency_report = Some(dependency_report);
+        self
+    }
+
+    /// Set the CI/CD pipelines and the language versions they test
+    pub fn with_ci_pipelines(mut self, detected_pipelines: Vec<CiPipeline>) -> Self {
+        self.ci_pipelines = detected_pipelines;
+        self
+    }
+
+    /// Every version of the project's language that a CI pipeline tests, deduplicated
+    pub fn ci_tested_versions(&self) -> Vec<String> {
+        let mut tested_versions: Vec<String> = Vec::new();
+        for ci_pipeline in &self.ci_pipelines {
+            for tested_version in ci_pipeline
+                .tested_versions
+                .get(&self.language)
+                .into_iter()
+                .flatten()
+            {
+                if !tested_versions.contains(tested_version) {
+                    tested_versions.push(tested_version.clone());
+                }
+            }
+        }
+        tested_versions
+    }
+
+    /// Set the Dockerfiles and their build stages
+    pub fn with_dockerfiles(mut self, detected_dockerfiles: Vec<Dockerfile>) -> Self {
+        self.dockerfiles = detected_dockerfiles;
+        self
+    }
+
+    /// Set the services declared in Docker Compose files
+    pub fn with_compose_services(mut self, detected_services: Vec<ComposeService>) -> Self {
+        self.compose_services = detected_services;
+        self
+    }
+
+    /// Compose services that are databases, caches, queues or search engines
+    pub fn backing_services(&self) -> Vec<&ComposeService> {
+        self.compose_services
+            .iter()
+            .filter(|compose_service| compose_service.kind.is_some())
+            .collect()
+    }
+
+    /// Set the member projects of a multi-project workspace
+    pub fn with_members(mut self, member_projects: Vec<ProjectInfo>) -> Self {
+        self.members = member_projects;
+        self
+    }
+
     /// Get a summary string of the project
     pub fn summary(&self) -> String {
         let mut summary_parts = vec![self.language.clone()];
@@ -141,6 +573,15 @@ mod tests {
         assert_eq!(project_info.project_name, Some("my-app".to_string()));
     }
 
+    #[test]
+    fn test_with_runtime() {
+        let project_info = ProjectInfo::new("Clojure".to_string(), "/test/path")
+            .with_runtime("JVM".to_string(), None);
+
+        assert_eq!(project_info.runtime, Some("JVM".to_string()));
+        assert!(project_info.runtime_version.is_none());
+    }
+
     #[test]
     fn test_summary() {
         let project_info = ProjectInfo::new("JavaScript".to_string(), "/test")
@@ -170,6 +611,16 @@ mod tests {
         assert_eq!(LanguageStatus::Unknown.to_string(), "❓ Unknown");
     }
 
+    #[test]
+    fn test_framework_category_display() {
+        assert_eq!(FrameworkCategory::Web.to_string(), "Web");
+        assert_eq!(
+            FrameworkCategory::MachineLearning.to_string(),
+            "Machine Learning"
+        );
+        assert_eq!(FrameworkCategory::DataPipeline.to_string(), "Data Pipeline");
+    }
+
     #[test]
     fn test_framework_details() {
         let framework_details = FrameworkDetails {
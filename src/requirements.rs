@@ -0,0 +1,438 @@
+//! PEP 508 requirement parsing and pip requirements file reading.
+//!
+//! Shared by every Python manifest reader so `requirements.txt`, PEP 621
+//! `dependencies`, `setup.cfg`, `setup.py` and Conda `pip:` entries all agree
+//! on what a package name and version are.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single parsed dependency specification
+#[derive(Debug, Clone, PartialEq)]
+pub struct Requirement {
+    /// Normalized (PEP 503) project name, e.g. `django-environ`
+    pub name: String,
+    pub extras: Vec<String>,
+    /// Version specifier without the name, e.g. `>=4.2,<5.0`
+    pub specifier: Option<String>,
+    pub url: Option<String>,
+    pub marker: Option<String>,
+}
+
+impl Requirement {
+    /// A requirement with just a name and optional specifier
+    pub fn new(raw_name: &str, specifier: Option<String>) -> Self {
+        Self {
+            name: normalize_name(raw_name),
+            extras: Vec::new(),
+            specifier: specifier.filter(|specifier| !specifier.is_empty() && specifier != "*"),
+            url: None,
+            marker: None,
+        }
+    }
+
+    /// The version named by the first specifier clause, e.g. `4.2` for `>=4.2,<5.0`
+    pub fn version(&self) -> Option<String> {
+        let first_clause = self.specifier.as_deref()?.split(',').next()?;
+        let version = first_clause
+            .trim()
+            .trim_start_matches(['=', '>', '<', '!', '~', '^'])
+            .trim();
+        (!version.is_empty()).then(|| version.to_string())
+    }
+}
+
+/// Normalize a project name per PEP 503: lower-case, runs of `-`, `_`, `.` become `-`
+pub fn normalize_name(raw_name: &str) -> String {
+    let mut normalized_name = String::with_capacity(raw_name.len());
+    let mut previous_was_separator = false;
+    for character in raw_name.trim().chars() {
+        if matches!(character, '-' | '_' | '.') {
+            if !previous_was_separator {
+                normalized_name.push('-');
+            }
+            previous_was_separator = true;
+        } else {
+            normalized_name.push(character.to_ascii_lowercase());
+            previous_was_separator = false;
+        }
+    }
+    normalized_name
+}
+
+/// Parse a PEP 508 requirement such as `requests[socks]>=2.31; python_version >= "3.8"`
+pub fn parse_requirement(requirement_text: &str) -> Option<Requirement> {
+    let requirement_text = strip_comment(requirement_text).trim();
+
+    let name_length = requirement_text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement_text.len());
+    if name_length == 0 {
+        return None;
+    }
+    let mut requirement = Requirement::new(&requirement_text[..name_length], None);
+    let mut remainder = requirement_text[name_length..].trim_start();
+
+    if let Some(extras_text) = remainder.strip_prefix('[') {
+        let closing_index = extras_text.find(']')?;
+        requirement.extras = extras_text[..closing_index]
+            .split(',')
+            .map(normalize_name)
+            .filter(|extra| !extra.is_empty())
+            .collect();
+        remainder = extras_text[closing_index + 1..].trim_start();
+    }
+
+    if let Some(url_text) = remainder.strip_prefix('@') {
+        let url_text = url_text.trim_start();
+        // A marker after a URL must be separated by whitespace
+        let url_end = url_text.find(char::is_whitespace).unwrap_or(url_text.len());
+        requirement.url = Some(url_text[..url_end].to_string());
+        remainder = url_text[url_end..].trim_start();
+    } else {
+        let specifier_end = remainder.find(';').unwrap_or(remainder.len());
+        let specifier: String = remainder[..specifier_end]
+            .trim()
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        requirement.specifier = (!specifier.is_empty()).then_some(specifier);
+        remainder = &remainder[specifier_end..];
+    }
+
+    if let Some(marker_text) = remainder.trim_start().strip_prefix(';') {
+        let marker_text = marker_text.trim();
+        requirement.marker = (!marker_text.is_empty()).then(|| marker_text.to_string());
+    }
+
+    Some(requirement)
+}
+
+/// `#` starts a comment at the beginning of a line or after whitespace
+fn strip_comment(requirement_line: &str) -> &str {
+    let comment_start = requirement_line
+        .char_indices()
+        .find(|&(character_index, character)| {
+            character == '#'
+                && (character_index == 0
+                    || requirement_line[..character_index].ends_with(char::is_whitespace))
+        })
+        .map(|(character_index, _)| character_index);
+    match comment_start {
+        Some(comment_index) => &requirement_line[..comment_index],
+        None => requirement_line,
+    }
+}
+
+/// The requirements files of a project: `requirements.txt`, `requirements/*.txt`
+/// and `requirements-*.txt`, in that order
+pub fn find_requirements_files(project_path: &Path) -> Vec<PathBuf> {
+    let mut requirements_files = Vec::new();
+
+    let root_requirements = project_path.join("requirements.txt");
+    if root_requirements.is_file() {
+        requirements_files.push(root_requirements);
+    }
+
+    let mut layout_files: Vec<PathBuf> = fs::read_dir(project_path.join("requirements"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|directory_entry| directory_entry.path())
+        .filter(|entry_path| entry_path.extension().and_then(|e| e.to_str()) == Some("txt"))
+        .collect();
+    layout_files.sort();
+    requirements_files.extend(layout_files);
+
+    let mut suffixed_files: Vec<PathBuf> = fs::read_dir(project_path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|directory_entry| directory_entry.path())
+        .filter(|entry_path| {
+            entry_path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .is_some_and(|file_name| {
+                    file_name.starts_with("requirements-") && file_name.ends_with(".txt")
+                })
+        })
+        .collect();
+    suffixed_files.sort();
+    requirements_files.extend(suffixed_files);
+
+    requirements_files
+}
+
+/// Read every requirement from pip requirements files, following `-r` includes
+///
+/// Versions from `-c` constraints files fill in requirements that don't pin
+/// one themselves. Each package is reported once, first declaration wins.
+/// A listed file that another listed file includes with `-c` is only read as
+/// constraints, so `requirements/constraints.txt` never adds dependencies.
+pub fn read_requirements_files(requirements_files: &[PathBuf]) -> Vec<Requirement> {
+    let mut visited_files = Vec::new();
+    let mut requirements = Vec::new();
+    let mut constraints = Vec::new();
+
+    let constraints_files = find_constraints_files(requirements_files);
+    for requirements_file in requirements_files {
+        if constraints_files.contains(&canonical_path(requirements_file)) {
+            continue;
+        }
+        collect_requirements_file(
+            requirements_file,
+            &mut visited_files,
+            &mut requirements,
+            &mut constraints,
+        );
+    }
+
+    for requirement in requirements
+        .iter_mut()
+        .filter(|requirement| requirement.specifier.is_none() && requirement.url.is_none())
+    {
+        if let Some(constraint) = constraints
+            .iter()
+            .find(|constraint: &&Requirement| constraint.name == requirement.name)
+        {
+            requirement.specifier = constraint.specifier.clone();
+        }
+    }
+
+    requirements
+}
+
+/// The files that `requirements_files` include with `-c`
+fn find_constraints_files(requirements_files: &[PathBuf]) -> Vec<PathBuf> {
+    let mut constraints_files = Vec::new();
+    for requirements_file in requirements_files {
+        let Ok(file_content) = fs::read_to_string(requirements_file) else {
+            continue;
+        };
+        let base_directory = requirements_file.parent().unwrap_or(Path::new("."));
+        for requirement_line in file_content.lines() {
+            if let Some(("-c" | "--constraint", option_value)) =
+                split_option(strip_comment(requirement_line).trim())
+            {
+                constraints_files.push(canonical_path(&base_directory.join(option_value)));
+            }
+        }
+    }
+    constraints_files
+}
+
+fn canonical_path(file_path: &Path) -> PathBuf {
+    file_path
+        .canonicalize()
+        .unwrap_or_else(|_| file_path.to_path_buf())
+}
+
+fn collect_requirements_file(
+    requirements_file: &Path,
+    visited_files: &mut Vec<PathBuf>,
+    requirements: &mut Vec<Requirement>,
+    constraints: &mut Vec<Requirement>,
+) {
+    let canonical_file = canonical_path(requirements_file);
+    if visited_files.contains(&canonical_file) {
+        return;
+    }
+    visited_files.push(canonical_file);
+
+    let Ok(file_content) = fs::read_to_string(requirements_file) else {
+        return;
+    };
+    let base_directory = requirements_file.parent().unwrap_or(Path::new("."));
+
+    // Backslash-continued lines form one logical line
+    let joined_content = file_content.replace("\\\r\n", " ").replace("\\\n", " ");
+    for requirement_line in joined_content.lines() {
+        let requirement_line = strip_comment(requirement_line).trim();
+        if requirement_line.is_empty() {
+            continue;
+        }
+
+        if let Some((option_name, option_value)) = split_option(requirement_line) {
+            match option_name {
+                "-r" | "--requirement" => collect_requirements_file(
+                    &base_directory.join(option_value),
+                    visited_files,
+                    requirements,
+                    constraints,
+                ),
+                "-c" | "--constraint" => {
+                    let mut constraint_requirements = Vec::new();
+                    collect_requirements_file(
+                        &base_directory.join(option_value),
+                        visited_files,
+                        &mut constraint_requirements,
+                        constraints,
+                    );
+                    constraints.extend(constraint_requirements);
+                }
+                "-e" | "--editable" => push_unique(requirements, parse_egg_fragment(option_value)),
+                _ => {}
+            }
+            continue;
+        }
+
+        // Direct references: `./libs/shared`, `git+https://...#egg=name`
+        if requirement_line.starts_with(['.', '/']) || requirement_line.contains("://") {
+            push_unique(requirements, parse_egg_fragment(requirement_line));
+            continue;
+        }
+
+        // Per-requirement pip options such as `--hash=sha256:...`
+        let requirement_text = requirement_line
+            .split(" --")
+            .next()
+            .unwrap_or(requirement_line);
+        push_unique(requirements, parse_requirement(requirement_text));
+    }
+}
+
+/// Split `-r base.txt`, `--requirement=base.txt` or `-rbase.txt` into option and value
+fn split_option(requirement_line: &str) -> Option<(&str, &str)> {
+    if !requirement_line.starts_with('-') {
+        return None;
+    }
+    if let Some(long_option) = requirement_line.strip_prefix("--") {
+        let option_end = long_option
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(long_option.len());
+        let option_value = long_option[option_end..].trim_start_matches('=').trim();
+        return Some((&requirement_line[..option_end + 2], option_value));
+    }
+    let (option_name, option_value) = requirement_line.split_at(2.min(requirement_line.len()));
+    Some((option_name, option_value.trim()))
+}
+
+/// A requirement named by a `#egg=name` URL fragment, if present
+fn parse_egg_fragment(direct_reference: &str) -> Option<Requirement> {
+    let egg_name = direct_reference.split("#egg=").nth(1)?;
+    let egg_name = egg_name.split(['&', ' ']).next()?;
+    let mut requirement = parse_requirement(egg_name)?;
+    requirement.url = Some(direct_reference.split('#').next()?.to_string());
+    Some(requirement)
+}
+
+fn push_unique(requirements: &mut Vec<Requirement>, requirement: Option<Requirement>) {
+    if let Some(requirement) = requirement {
+        if !requirements
+            .iter()
+            .any(|existing| existing.name == requirement.name)
+        {
+            requirements.push(requirement);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Django"), "django");
+        assert_eq!(normalize_name("django_environ"), "django-environ");
+        assert_eq!(normalize_name("zope.interface"), "zope-interface");
+        assert_eq!(normalize_name("Foo__-.Bar"), "foo-bar");
+    }
+
+    #[test]
+    fn test_requirement_version() {
+        let version_of =
+            |requirement_text: &str| parse_requirement(requirement_text).unwrap().version();
+        assert_eq!(version_of("django==4.2.0"), Some("4.2.0".to_string()));
+        assert_eq!(version_of("flask>=2.0.0"), Some("2.0.0".to_string()));
+        assert_eq!(version_of("fastapi~=0.68.0"), Some("0.68.0".to_string()));
+        assert_eq!(version_of("django>=4.2,<5.0"), Some("4.2".to_string()));
+        assert_eq!(version_of("requests"), None);
+    }
+
+    #[test]
+    fn test_parse_requirement() {
+        let requirement =
+            parse_requirement(r#"Django[argon2, bcrypt] >=4.2, <5.0 ; python_version >= "3.8""#)
+                .unwrap();
+        assert_eq!(requirement.name, "django");
+        assert_eq!(requirement.extras, vec!["argon2", "bcrypt"]);
+        assert_eq!(requirement.specifier, Some(">=4.2,<5.0".to_string()));
+        assert_eq!(
+            requirement.marker,
+            Some(r#"python_version >= "3.8""#.to_string())
+        );
+        assert_eq!(requirement.version(), Some("4.2".to_string()));
+
+        let requirement = parse_requirement("flask-cors==4.0.0  # CORS support").unwrap();
+        assert_eq!(requirement.name, "flask-cors");
+        assert_eq!(requirement.version(), Some("4.0.0".to_string()));
+
+        let requirement =
+            parse_requirement("pip @ https://example.com/pip-23.0.tar.gz ; os_name == 'nt'")
+                .unwrap();
+        assert_eq!(
+            requirement.url,
+            Some("https://example.com/pip-23.0.tar.gz".to_string())
+        );
+        assert_eq!(requirement.marker, Some("os_name == 'nt'".to_string()));
+        assert!(requirement.specifier.is_none());
+
+        let requirement = parse_requirement("requests (>=2.31)").unwrap();
+        assert_eq!(requirement.specifier, Some(">=2.31".to_string()));
+
+        assert!(parse_requirement("# just a comment").is_none());
+        assert!(parse_requirement("").is_none());
+    }
+
+    #[test]
+    fn test_read_requirements_files_with_includes() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout_dir = temp_dir.path().join("requirements");
+        fs::create_dir(&layout_dir).unwrap();
+        fs::write(
+            layout_dir.join("base.txt"),
+            "-c constraints.txt\nDjango\ncelery[redis]>=5.3 \\\n    ; python_version >= '3.8'\n",
+        )
+        .unwrap();
+        fs::write(layout_dir.join("constraints.txt"), "django==4.2.11\n").unwrap();
+        fs::write(
+            layout_dir.join("dev.txt"),
+            "-r base.txt\n--index-url https://pypi.org/simple\npytest==8.0.0 --hash=sha256:abc\n-e git+https://github.com/acme/lib.git#egg=acme_lib\n",
+        )
+        .unwrap();
+
+        let requirements_files = find_requirements_files(temp_dir.path());
+        assert_eq!(requirements_files.len(), 3);
+
+        let requirements = read_requirements_files(&requirements_files);
+        let names: Vec<&str> = requirements.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["django", "celery", "pytest", "acme-lib"]);
+        assert_eq!(requirements[0].version(), Some("4.2.11".to_string()));
+        assert_eq!(requirements[1].extras, vec!["redis"]);
+        assert!(requirements[1].marker.is_some());
+        assert_eq!(requirements[2].specifier, Some("==8.0.0".to_string()));
+    }
+
+    #[test]
+    fn test_constraints_file_listed_before_its_includer() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout_dir = temp_dir.path().join("requirements");
+        fs::create_dir(&layout_dir).unwrap();
+        fs::write(
+            layout_dir.join("constraints.txt"),
+            "django==4.2.11\nurllib3==2.2.1\n",
+        )
+        .unwrap();
+        fs::write(layout_dir.join("test.txt"), "-c constraints.txt\nDjango\n").unwrap();
+
+        let requirements = read_requirements_files(&find_requirements_files(temp_dir.path()));
+        assert_eq!(requirements.len(), 1);
+        assert_eq!(requirements[0].name, "django");
+        assert_eq!(requirements[0].version(), Some("4.2.11".to_string()));
+    }
+}
@@ -0,0 +1,442 @@
+//! Test framework detection.
+//!
+//! Test frameworks are found from declared dependencies, from their config
+//! files (`jest.config.ts`, `pytest.ini`, `.rspec`, ...), from JVM build
+//! files, and from the test files themselves for the frameworks that ship
+//! with the language (Go `testing`, Python `unittest`).
+
+use crate::go_mod;
+use crate::project_files;
+use crate::project_info::{TestFramework, TestSetup};
+use std::fs;
+use std::path::Path;
+
+/// How deep to look for test files below the project root
+const MAX_SEARCH_DEPTH: usize = 4;
+
+/// Test frameworks by the package (npm, PyPI, crate or Go module) that provides them
+const TEST_FRAMEWORK_PACKAGES: [(&str, &str); 13] = [
+    ("jest", "Jest"),
+    ("vitest", "Vitest"),
+    ("mocha", "Mocha"),
+    ("@playwright/test", "Playwright"),
+    ("playwright", "Playwright"),
+    ("cypress", "Cypress"),
+    ("pytest", "pytest"),
+    ("tox", "tox"),
+    ("nox", "nox"),
+    ("criterion", "criterion"),
+    ("proptest", "proptest"),
+    ("github.com/stretchr/testify", "testify"),
+    ("github.com/onsi/ginkgo", "Ginkgo"),
+];
+
+/// Config files that only exist for one test framework; a trailing `.`
+/// matches any extension
+const TEST_CONFIG_FILES: [(&str, &str); 13] = [
+    ("jest.config.", "Jest"),
+    ("vitest.config.", "Vitest"),
+    (".mocharc.", "Mocha"),
+    ("playwright.config.", "Playwright"),
+    ("cypress.config.", "Cypress"),
+    ("cypress.json", "Cypress"),
+    ("pytest.ini", "pytest"),
+    ("conftest.py", "pytest"),
+    ("tox.ini", "tox"),
+    ("noxfile.py", "nox"),
+    (".rspec", "RSpec"),
+    ("spec/spec_helper.rb", "RSpec"),
+    (".config/nextest.toml", "cargo-nextest"),
+];
+
+/// JVM test frameworks: `(group, artifact prefix, framework)`
+const JVM_TEST_FRAMEWORKS: [(&str, &str, &str); 4] = [
+    ("org.junit.jupiter", "junit-jupiter", "JUnit 5"),
+    ("org.junit", "junit-bom", "JUnit 5"),
+    ("junit", "junit", "JUnit 4"),
+    ("org.testng", "testng", "TestNG"),
+];
+
+/// Where tests conventionally live, relative to the project root
+const TEST_DIRECTORIES: [&str; 10] = [
+    "tests",
+    "test",
+    "spec",
+    "__tests__",
+    "e2e",
+    "cypress",
+    "benches",
+    "src/test",
+    "src/__tests__",
+    "integration-tests",
+];
+
+/// Test frameworks and test directories, or `None` if the project shows no sign of tests
+///
+/// `declared_dependencies` are `(package, version)` pairs from every manifest.
+pub fn detect_test_setup(
+    project_path: &Path,
+    declared_dependencies: &[(String, Option<String>)],
+) -> Option<TestSetup> {
+    let mut test_frameworks = Vec::new();
+
+    for (package_name, framework_name) in TEST_FRAMEWORK_PACKAGES {
+        // Go modules may carry a major version suffix (`ginkgo/v2`)
+        if let Some((_, package_version)) = declared_dependencies
+            .iter()
+            .find(|(dependency_name, _)| go_mod::module_path_matches(dependency_name, package_name))
+        {
+            let package_version = package_version
+                .as_ref()
+                .map(|version| version.trim_start_matches('v').to_string());
+            push_test_framework(
+                &mut test_frameworks,
+                framework_name,
+                package_version,
+                "dependencies",
+            );
+        }
+    }
+
+    // Versioned sources first, so a config file never hides a declared version
+    detect_gemfile_test_frameworks(project_path, &mut test_frameworks);
+    detect_jvm_test_frameworks(project_path, &mut test_frameworks);
+
+    let project_files: Vec<String> = fs::read_dir(project_path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|directory_entry| directory_entry.file_name().to_string_lossy().to_string())
+        .collect();
+    for (config_file, framework_name) in TEST_CONFIG_FILES {
+        let found_file = if config_file.ends_with('.') {
+            project_files
+                .iter()
+                .find(|file_name| file_name.starts_with(config_file))
+                .cloned()
+        } else {
+            project_path
+                .join(config_file)
+                .exists()
+                .then(|| config_file.to_string())
+        };
+        if let Some(found_file) = found_file {
+            push_test_framework(&mut test_frameworks, framework_name, None, &found_file);
+        }
+    }
+
+    // pytest also reads its settings from files shared with other tools
+    for (shared_config_file, pytest_section) in [
+        ("pyproject.toml", "[tool.pytest.ini_options]"),
+        ("setup.cfg", "[tool:pytest]"),
+        ("tox.ini", "[pytest]"),
+    ] {
+        if fs::read_to_string(project_path.join(shared_config_file))
+            .is_ok_and(|config_content| config_content.contains(pytest_section))
+        {
+            push_test_framework(&mut test_frameworks, "pytest", None, shared_config_file);
+        }
+    }
+
+    if let Ok(package_content) = fs::read_to_string(project_path.join("package.json")) {
+        let declares_jest_config = serde_json::from_str::<serde_json::Value>(&package_content)
+            .is_ok_and(|package_json| package_json.get("jest").is_some());
+        if declares_jest_config {
+            push_test_framework(&mut test_frameworks, "Jest", None, "package.json");
+        }
+    }
+
+    detect_builtin_test_frameworks(project_path, &mut test_frameworks);
+
+    let test_directories: Vec<String> = TEST_DIRECTORIES
+        .iter()
+        .filter(|test_directory| project_path.join(test_directory).is_dir())
+        .map(|test_directory| test_directory.to_string())
+        .collect();
+
+    (!test_frameworks.is_empty() || !test_directories.is_empty()).then_some(TestSetup {
+        frameworks: test_frameworks,
+        test_directories,
+    })
+}
+
+/// RSpec and Minitest from `gem` lines, with the version constraint if one is given
+fn detect_gemfile_test_frameworks(project_path: &Path, test_frameworks: &mut Vec<TestFramework>) {
+    let Ok(gemfile_content) = fs::read_to_string(project_path.join("Gemfile")) else {
+        return;
+    };
+    let gem_regex =
+        regex::Regex::new(r#"^\s*gem\s+['"]([\w-]+)['"](?:\s*,\s*['"]([^'"]+)['"])?"#).unwrap();
+
+    for captures in gemfile_content
+        .lines()
+        .filter_map(|gemfile_line| gem_regex.captures(gemfile_line))
+    {
+        let framework_name = match &captures[1] {
+            "rspec" | "rspec-rails" | "rspec-core" => "RSpec",
+            "minitest" => "Minitest",
+            _ => continue,
+        };
+        let gem_version = captures.get(2).map(|version| {
+            version
+                .as_str()
+                .trim_start_matches(['~', '>', '=', '<', ' '])
+                .to_string()
+        });
+        push_test_framework(test_frameworks, framework_name, gem_version, "Gemfile");
+    }
+
+    // Rails apps test with Minitest unless they opt into RSpec
+    if project_path.join("test/test_helper.rb").exists() {
+        push_test_framework(test_frameworks, "Minitest", None, "test/test_helper.rb");
+    }
+}
+
+/// JUnit and TestNG from Maven and Gradle build files
+fn detect_jvm_test_frameworks(project_path: &Path, test_frameworks: &mut Vec<TestFramework>) {
+    for build_file in ["pom.xml", "build.gradle.kts", "build.gradle"] {
+        let Ok(build_content) = fs::read_to_string(project_path.join(build_file)) else {
+            continue;
+        };
+        for (group_id, artifact_prefix, framework_name) in JVM_TEST_FRAMEWORKS {
+            if let Some(artifact_version) =
+                find_jvm_dependency(&build_content, group_id, artifact_prefix)
+            {
+                push_test_framework(
+                    test_frameworks,
+                    framework_name,
+                    artifact_version,
+                    build_file,
+                );
+            }
+        }
+    }
+}
+
+/// Look for `group:artifact:version` (Gradle) or a `<dependency>` block (Maven),
+/// returning the version if the dependency is there
+fn find_jvm_dependency(
+    build_content: &str,
+    group_id: &str,
+    artifact_prefix: &str,
+) -> Option<Option<String>> {
+    let gradle_regex = regex::Regex::new(&format!(
+        r#"['"]{}:{}[\w-]*(?::([0-9][\w.-]*))?['"]"#,
+        regex::escape(group_id),
+        regex::escape(artifact_prefix)
+    ))
+    .unwrap();
+    if let Some(captures) = gradle_regex.captures(build_content) {
+        return Some(captures.get(1).map(|version| version.as_str().to_string()));
+    }
+
+    let maven_regex = regex::Regex::new(&format!(
+        r"<groupId>\s*{}\s*</groupId>\s*<artifactId>\s*{}[\w-]*\s*</artifactId>(?:\s*<version>\s*([^<$\s]+)\s*</version>)?",
+        regex::escape(group_id),
+        regex::escape(artifact_prefix)
+    ))
+    .unwrap();
+    maven_regex
+        .captures(build_content)
+        .map(|captures| captures.get(1).map(|version| version.as_str().to_string()))
+}
+
+/// Go's `testing` package and Python's `unittest`, found from the test files
+fn detect_builtin_test_frameworks(project_path: &Path, test_frameworks: &mut Vec<TestFramework>) {
+    let test_file_paths =
+        project_files::find_project_files(project_path, MAX_SEARCH_DEPTH, &is_test_file);
+
+    if let Some(go_test_path) = test_file_paths
+        .iter()
+        .find(|test_file_path| test_file_path.extension().is_some_and(|e| e == "go"))
+    {
+        let relative_path = go_test_path
+            .strip_prefix(project_path)
+            .unwrap_or(go_test_path);
+        push_test_framework(
+            test_frameworks,
+            "testing",
+            None,
+            &relative_path.display().to_string(),
+        );
+    }
+
+    if let Some(unittest_path) = test_file_paths
+        .iter()
+        .filter(|test_file_path| test_file_path.extension().is_some_and(|e| e == "py"))
+        .find(|test_file_path| {
+            fs::read_to_string(test_file_path)
+                .is_ok_and(|test_content| uses_unittest(&test_content))
+        })
+    {
+        let relative_path = unittest_path
+            .strip_prefix(project_path)
+            .unwrap_or(unittest_path);
+        push_test_framework(
+            test_frameworks,
+            "unittest",
+            None,
+            &relative_path.display().to_string(),
+        );
+    }
+}
+
+/// Whether a Python test file writes unittest test cases
+///
+/// pytest suites often use `unittest.mock` alone, which doesn't count.
+fn uses_unittest(test_content: &str) -> bool {
+    let unittest_regex = regex::Regex::new(
+        r"(?m)^\s*(?:import\s+unittest\s*(?:#.*)?$|from\s+unittest\s+import\s+.*\b(?:IsolatedAsyncio)?TestCase\b)|\bunittest\.(?:IsolatedAsyncio)?TestCase\b",
+    )
+    .unwrap();
+    unittest_regex.is_match(test_content)
+}
+
+/// `*_test.go`, `test_*.py` and `*_test.py` files
+fn is_test_file(directory_entry: &fs::DirEntry) -> bool {
+    let entry_name = directory_entry.file_name();
+    let entry_name = entry_name.to_string_lossy();
+    entry_name.ends_with("_test.go")
+        || entry_name.ends_with("_test.py")
+        || (entry_name.starts_with("test_") && entry_name.ends_with(".py"))
+}
+
+fn push_test_framework(
+    test_frameworks: &mut Vec<TestFramework>,
+    framework_name: &str,
+    framework_version: Option<String>,
+    source: &str,
+) {
+    if test_frameworks
+        .iter()
+        .any(|test_framework| test_framework.name == framework_name)
+    {
+        return;
+    }
+    test_frameworks.push(TestFramework {
+        name: framework_name.to_string(),
+        version: framework_version,
+        source: source.to_string(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_test_setup_from_dependencies_and_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("tests")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("e2e")).unwrap();
+        fs::write(
+            temp_dir.path().join("playwright.config.ts"),
+            "export default {};\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[tool.pytest.ini_options]\ntestpaths = [\"tests\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("tests").join("test_api.py"),
+            "from unittest.mock import patch\nimport unittest.mock\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("tests").join("test_models.py"),
+            "import unittest\n",
+        )
+        .unwrap();
+
+        let declared_dependencies = vec![
+            ("vitest".to_string(), Some("^1.2.0".to_string())),
+            (
+                "github.com/onsi/ginkgo/v2".to_string(),
+                Some("v2.13.0".to_string()),
+            ),
+            ("jest-dom".to_string(), None),
+        ];
+        let test_setup = detect_test_setup(temp_dir.path(), &declared_dependencies).unwrap();
+        let framework_summary: Vec<(&str, Option<&str>, &str)> = test_setup
+            .frameworks
+            .iter()
+            .map(|test_framework| {
+                (
+                    test_framework.name.as_str(),
+                    test_framework.version.as_deref(),
+                    test_framework.source.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            framework_summary,
+            vec![
+                ("Vitest", Some("^1.2.0"), "dependencies"),
+                ("Ginkgo", Some("2.13.0"), "dependencies"),
+                ("Playwright", None, "playwright.config.ts"),
+                ("pytest", None, "pyproject.toml"),
+                ("unittest", None, "tests/test_models.py"),
+            ]
+        );
+        assert_eq!(test_setup.test_directories, vec!["tests", "e2e"]);
+
+        assert!(detect_test_setup(TempDir::new().unwrap().path(), &[]).is_none());
+    }
+
+    #[test]
+    fn test_uses_unittest() {
+        assert!(uses_unittest("import unittest\n"));
+        assert!(uses_unittest("from unittest import TestCase, mock\n"));
+        assert!(uses_unittest(
+            "import unittest.mock\n\nclass ModelTest(unittest.TestCase):\n    pass\n"
+        ));
+        assert!(!uses_unittest("from unittest.mock import patch\n"));
+        assert!(!uses_unittest(
+            "from unittest import mock\nimport unittest.mock\n"
+        ));
+    }
+
+    #[test]
+    fn test_detect_jvm_and_ruby_test_frameworks() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("build.gradle.kts"),
+            "dependencies {\n    testImplementation(\"org.junit.jupiter:junit-jupiter:5.10.1\")\n    testImplementation(\"org.testng:testng:7.8.0\")\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("pom.xml"),
+            "<dependency>\n  <groupId>junit</groupId>\n  <artifactId>junit</artifactId>\n  <version>4.13.2</version>\n</dependency>\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("Gemfile"),
+            "group :test do\n  gem 'rspec-rails', '~> 6.1'\nend\n",
+        )
+        .unwrap();
+
+        let test_setup = detect_test_setup(temp_dir.path(), &[]).unwrap();
+        let framework_versions: Vec<(&str, Option<&str>)> = test_setup
+            .frameworks
+            .iter()
+            .map(|test_framework| {
+                (
+                    test_framework.name.as_str(),
+                    test_framework.version.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            framework_versions,
+            vec![
+                ("RSpec", Some("6.1")),
+                ("JUnit 4", Some("4.13.2")),
+                ("JUnit 5", Some("5.10.1")),
+                ("TestNG", Some("7.8.0")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,284 @@
+//! Shared reader for asdf's `.tool-versions` and mise's `mise.toml`.
+//!
+//! Precedence: a language's own sources (`.nvmrc`, `Cargo.toml`, `go.mod`, ...)
+//! decide its version. Pins from these files only fill in when a language has
+//! none, with `.tool-versions` ahead of `mise.toml`. Pins that disagree with
+//! the reported version, or with each other, are reported as conflicts.
+
+use crate::project_info::{ToolVersion, VersionConflict};
+use std::fs;
+use std::path::Path;
+
+const MISE_CONFIG_FILES: [&str; 2] = ["mise.toml", ".mise.toml"];
+
+/// Every tool pinned in `.tool-versions` and the mise config files, in precedence order
+pub fn read_tool_versions(project_path: &Path) -> Vec<ToolVersion> {
+    let mut tool_versions = Vec::new();
+
+    if let Ok(tool_versions_content) = fs::read_to_string(project_path.join(".tool-versions")) {
+        for content_line in tool_versions_content.lines() {
+            let mut line_fields = content_line
+                .split('#')
+                .next()
+                .unwrap_or("")
+                .split_whitespace();
+            // The first listed version is the one asdf activates
+            if let (Some(tool_name), Some(tool_version)) = (line_fields.next(), line_fields.next())
+            {
+                push_tool_version(
+                    &mut tool_versions,
+                    tool_name,
+                    tool_version,
+                    ".tool-versions",
+                );
+            }
+        }
+    }
+
+    for mise_file in MISE_CONFIG_FILES {
+        let Some(tools_table) = fs::read_to_string(project_path.join(mise_file))
+            .ok()
+            .and_then(|mise_content| toml::from_str::<toml::Value>(&mise_content).ok())
+            .and_then(|parsed_toml| parsed_toml.get("tools").cloned())
+        else {
+            continue;
+        };
+
+        for (tool_name, tool_value) in tools_table.as_table().into_iter().flatten() {
+            // node = "20", node = ["20", "18"] or node = { version = "20" }
+            let tool_version = match tool_value {
+                toml::Value::Array(version_list) => version_list.first(),
+                toml::Value::Table(tool_table) => tool_table.get("version"),
+                _ => Some(tool_value),
+            };
+            if let Some(version_string) = tool_version.and_then(|v| v.as_str()) {
+                push_tool_version(&mut tool_versions, tool_name, version_string, mise_file);
+            }
+        }
+    }
+
+    tool_versions
+}
+
+fn push_tool_version(
+    tool_versions: &mut Vec<ToolVersion>,
+    tool_name: &str,
+    raw_version: &str,
+    version_source: &str,
+) {
+    // `system`, `ref:<sha>` and `path:<dir>` don't name a version
+    if raw_version == "system" || raw_version.contains(':') {
+        return;
+    }
+
+    let tool_language = tool_language(tool_name);
+    let tool_version = match tool_language {
+        Some("Java") => strip_distribution_prefix(raw_version),
+        _ => raw_version,
+    };
+
+    tool_versions.push(ToolVersion {
+        tool: tool_name.to_string(),
+        language: tool_language.map(|language| language.to_string()),
+        version: tool_version.to_string(),
+        source: version_source.to_string(),
+    });
+}
+
+/// The language an asdf plugin or mise tool provides
+pub fn tool_language(tool_name: &str) -> Option<&'static str> {
+    match tool_name.to_lowercase().as_str() {
+        "nodejs" | "node" => Some("JavaScript"),
+        "python" => Some("Python"),
+        "ruby" => Some("Ruby"),
+        "golang" | "go" => Some("Go"),
+        "java" => Some("Java"),
+        "erlang" => Some("Erlang"),
+        "elixir" => Some("Elixir"),
+        "rust" => Some("Rust"),
+        "terraform" => Some("Terraform"),
+        "scala" => Some("Scala"),
+        "kotlin" => Some("Kotlin"),
+        "clojure" => Some("Clojure"),
+        "php" => Some("PHP"),
+        "dart" => Some("Dart"),
+        "swift" => Some("Swift"),
+        "ghc" | "haskell" => Some("Haskell"),
+        "dotnet" | "dotnet-core" => Some("C#"),
+        "zig" => Some("Zig"),
+        "nim" => Some("Nim"),
+        "crystal" => Some("Crystal"),
+        "ocaml" => Some("OCaml"),
+        "lua" => Some("Lua"),
+        "r" => Some("R"),
+        "julia" => Some("Julia"),
+        _ => None,
+    }
+}
+
+/// The highest-precedence pin for a language
+pub fn find_language_version<'a>(
+    tool_versions: &'a [ToolVersion],
+    language: &str,
+) -> Option<&'a ToolVersion> {
+    tool_versions
+        .iter()
+        .find(|tool_version| tool_version.language.as_deref() == Some(language))
+}
+
+/// The highest-precedence pin for a specific tool, such as `deno` or `bun`
+pub fn find_tool_version<'a>(
+    tool_versions: &'a [ToolVersion],
+    tool_name: &str,
+) -> Option<&'a ToolVersion> {
+    tool_versions
+        .iter()
+        .find(|tool_version| tool_version.tool == tool_name)
+}
+
+/// Pins for `language` that disagree with the version the project reports
+pub fn find_conflicts(
+    tool_versions: &[ToolVersion],
+    language: &str,
+    reported_version: &str,
+    reported_source: Option<&str>,
+) -> Vec<VersionConflict> {
+    tool_versions
+        .iter()
+        .filter(|tool_version| tool_version.language.as_deref() == Some(language))
+        .filter(|tool_version| Some(tool_version.source.as_str()) != reported_source)
+        .filter(|tool_version| !versions_agree(reported_version, &tool_version.version))
+        .map(|tool_version| VersionConflict {
+            language: language.to_string(),
+            version: reported_version.to_string(),
+            version_source: reported_source.map(|source| source.to_string()),
+            conflicting_version: tool_version.version.clone(),
+            conflicting_source: tool_version.source.clone(),
+        })
+        .collect()
+}
+
+/// Whether two version strings can describe the same release
+///
+/// Only exact versions are compared, segment by segment, so `20` agrees with
+/// `20.11.0`. Ranges (`>=3.9`) and channel names (`stable`) can't be decided
+/// here and are treated as agreeing.
+pub fn versions_agree(first_version: &str, second_version: &str) -> bool {
+    let first_segments = exact_version_segments(first_version);
+    let second_segments = exact_version_segments(second_version);
+
+    match (first_segments, second_segments) {
+        (Some(first_segments), Some(second_segments)) => first_segments
+            .iter()
+            .zip(second_segments.iter())
+            .all(|(first_segment, second_segment)| first_segment == second_segment),
+        _ => true,
+    }
+}
+
+fn exact_version_segments(version_string: &str) -> Option<Vec<&str>> {
+    let version_string = version_string.trim().trim_start_matches('v');
+    if !version_string.starts_with(|c: char| c.is_ascii_digit())
+        || version_string.contains([' ', ',', '*', 'x', '|'])
+    {
+        return None;
+    }
+    Some(
+        version_string
+            .split(['.', '+', '-'])
+            .filter(|segment| !segment.is_empty())
+            .collect(),
+    )
+}
+
+/// `temurin-17.0.9+9` and `graalvm-community-21.0.1` become `17.0.9+9` and `21.0.1`
+fn strip_distribution_prefix(java_version: &str) -> &str {
+    java_version
+        .match_indices('-')
+        .map(|(dash_index, _)| &java_version[dash_index + 1..])
+        .find(|remainder| remainder.starts_with(|c: char| c.is_ascii_digit()))
+        .unwrap_or(java_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_tool_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".tool-versions"),
+            "# runtimes\nnodejs 20.11.0 18.19.0\npython 3.12.1\njava temurin-17.0.9+9\nterraform 1.6.6\nruby system\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("mise.toml"),
+            "[tools]\ngo = \"1.22.0\"\npython = { version = \"3.11\" }\n",
+        )
+        .unwrap();
+
+        let tool_versions = read_tool_versions(temp_dir.path());
+        assert_eq!(tool_versions.len(), 6);
+
+        let node = find_language_version(&tool_versions, "JavaScript").unwrap();
+        assert_eq!(node.version, "20.11.0");
+        assert_eq!(node.source, ".tool-versions");
+
+        let python = find_language_version(&tool_versions, "Python").unwrap();
+        assert_eq!(python.version, "3.12.1");
+
+        let java = find_language_version(&tool_versions, "Java").unwrap();
+        assert_eq!(java.version, "17.0.9+9");
+
+        let terraform = find_tool_version(&tool_versions, "terraform").unwrap();
+        assert_eq!(terraform.language, Some("Terraform".to_string()));
+
+        let go = find_language_version(&tool_versions, "Go").unwrap();
+        assert_eq!(go.source, "mise.toml");
+
+        assert!(find_language_version(&tool_versions, "Ruby").is_none());
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let tool_versions = vec![
+            ToolVersion {
+                tool: "python".to_string(),
+                language: Some("Python".to_string()),
+                version: "3.12.1".to_string(),
+                source: ".tool-versions".to_string(),
+            },
+            ToolVersion {
+                tool: "python".to_string(),
+                language: Some("Python".to_string()),
+                version: "3.11".to_string(),
+                source: "mise.toml".to_string(),
+            },
+        ];
+
+        let conflicts = find_conflicts(&tool_versions, "Python", "3.12", Some(".python-version"));
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].conflicting_version, "3.11");
+        assert_eq!(conflicts[0].conflicting_source, "mise.toml");
+
+        let conflicts = find_conflicts(&tool_versions, "Python", "3.12.1", Some(".tool-versions"));
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].version_source,
+            Some(".tool-versions".to_string())
+        );
+
+        assert!(find_conflicts(&tool_versions, "Python", ">=3.9", None).is_empty());
+    }
+
+    #[test]
+    fn test_versions_agree() {
+        assert!(versions_agree("20", "20.11.0"));
+        assert!(versions_agree("v1.22.0", "1.22"));
+        assert!(!versions_agree("3.11", "3.12.1"));
+        assert!(versions_agree(">=3.9", "3.12.1"));
+        assert!(versions_agree("stable", "1.75.0"));
+    }
+}
@@ -0,0 +1,325 @@
+//! Cross-source version consistency.
+//!
+//! A language version is often stated in several places at once: `.nvmrc`
+//! pins Node 18, `engines` requires `>=16`, the Dockerfile builds on `node:20`
+//! and CI tests 16, 18 and 20. Exact pins must agree with each other, and
+//! with every requirement they are checked against. A CI pipeline tests a set
+//! of versions, so it conflicts with a pin only when none of its versions
+//! matches it, and with a requirement for each tested version outside it.
+//! Requirement pairs and channel names (`stable`, `lts/*`) aren't compared.
+
+use crate::project_info::{VersionConflict, VersionConsistency, VersionSource};
+use crate::tool_versions::versions_agree;
+use std::cmp::Ordering;
+
+/// Compare every declared and tested version of a language.
+///
+/// Returns `None` unless at least two different sources state a version.
+pub fn check_version_consistency(
+    language: &str,
+    declared_versions: Vec<VersionSource>,
+    tested_versions: Vec<VersionSource>,
+) -> Option<VersionConsistency> {
+    let mut version_sources: Vec<&str> = declared_versions
+        .iter()
+        .chain(tested_versions.iter())
+        .map(|version_source| version_source.source.as_str())
+        .collect();
+    version_sources.sort_unstable();
+    version_sources.dedup();
+    if version_sources.len() < 2 {
+        return None;
+    }
+
+    let mut conflicts = Vec::new();
+    for (declared_index, declared) in declared_versions.iter().enumerate() {
+        for other_declared in &declared_versions[declared_index + 1..] {
+            if declared.source != other_declared.source
+                && versions_disagree(&declared.version, &other_declared.version)
+            {
+                conflicts.push(version_conflict(
+                    language,
+                    declared,
+                    &other_declared.version,
+                    &other_declared.source,
+                ));
+            }
+        }
+    }
+
+    let mut pipeline_sources: Vec<&str> = Vec::new();
+    for tested in &tested_versions {
+        if !pipeline_sources.contains(&tested.source.as_str()) {
+            pipeline_sources.push(&tested.source);
+        }
+    }
+    for pipeline_source in pipeline_sources {
+        let pipeline_versions: Vec<&str> = tested_versions
+            .iter()
+            .filter(|tested| tested.source == pipeline_source)
+            .map(|tested| tested.version.as_str())
+            .collect();
+        let exact_pipeline_versions: Vec<&str> = pipeline_versions
+            .iter()
+            .copied()
+            .filter(|pipeline_version| is_exact_version(pipeline_version))
+            .collect();
+
+        for declared in &declared_versions {
+            if is_exact_version(&declared.version) {
+                let is_tested = exact_pipeline_versions.is_empty()
+                    || exact_pipeline_versions.iter().any(|pipeline_version| {
+                        versions_agree(&declared.version, pipeline_version)
+                    });
+                if !is_tested {
+                    conflicts.push(version_conflict(
+                        language,
+                        declared,
+                        &exact_pipeline_versions.join(", "),
+                        pipeline_source,
+                    ));
+                }
+            } else {
+                for pipeline_version in &exact_pipeline_versions {
+                    if version_satisfies(pipeline_version, &declared.version) == Some(false) {
+                        conflicts.push(version_conflict(
+                            language,
+                            declared,
+                            pipeline_version,
+                            pipeline_source,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Some(VersionConsistency {
+        language: language.to_string(),
+        declared_versions,
+        tested_versions,
+        conflicts,
+    })
+}
+
+fn version_conflict(
+    language: &str,
+    declared: &VersionSource,
+    conflicting_version: &str,
+    conflicting_source: &str,
+) -> VersionConflict {
+    VersionConflict {
+        language: language.to_string(),
+        version: declared.version.clone(),
+        version_source: Some(declared.source.clone()),
+        conflicting_version: conflicting_version.to_string(),
+        conflicting_source: conflicting_source.to_string(),
+    }
+}
+
+fn versions_disagree(first_version: &str, second_version: &str) -> bool {
+    match (
+        is_exact_version(first_version),
+        is_exact_version(second_version),
+    ) {
+        (true, true) => !versions_agree(first_version, second_version),
+        (true, false) => version_satisfies(first_version, second_version) == Some(false),
+        (false, true) => version_satisfies(second_version, first_version) == Some(false),
+        (false, false) => false,
+    }
+}
+
+fn is_exact_version(version_string: &str) -> bool {
+    let version_string = version_string.trim().trim_start_matches('v');
+    version_string.starts_with(|c: char| c.is_ascii_digit())
+        && !version_string.contains([' ', ',', '*', 'x', '|', '<', '>', '=', '^', '~'])
+}
+
+/// Whether an exact version meets a requirement such as `>=3.9,<4`, `^18.2` or
+/// `>=16 <21 || 22`
+///
+/// `None` when either side can't be compared, e.g. channel names or `lts/*`.
+pub fn version_satisfies(version: &str, requirement: &str) -> Option<bool> {
+    if !is_exact_version(version) {
+        return None;
+    }
+    let version_numbers = numeric_segments(version);
+    let comparator_regex =
+        regex::Regex::new(r"(>=|<=|!=|==|~=|>|<|=|\^|~)?\s*v?(\d+(?:\.\d+)*)").unwrap();
+
+    let mut has_comparators = false;
+    for requirement_alternative in requirement.split("||") {
+        let comparators: Vec<regex::Captures> = comparator_regex
+            .captures_iter(requirement_alternative)
+            .collect();
+        if comparators.is_empty() {
+            continue;
+        }
+        has_comparators = true;
+
+        let satisfies_alternative = comparators.iter().all(|comparator| {
+            let bound_numbers = numeric_segments(&comparator[2]);
+            let operator = comparator.get(1).map_or("", |operator| operator.as_str());
+            satisfies_comparator(&version_numbers, operator, &bound_numbers)
+        });
+        if satisfies_alternative {
+            return Some(true);
+        }
+    }
+
+    if has_comparators {
+        Some(false)
+    } else {
+        // `*` and `x` accept anything; words like `latest` can't be compared
+        matches!(requirement.trim(), "*" | "x" | "").then_some(true)
+    }
+}
+
+fn satisfies_comparator(version_numbers: &[u64], operator: &str, bound_numbers: &[u64]) -> bool {
+    let ordering = compare_versions(version_numbers, bound_numbers);
+    match operator {
+        // A bare version in a requirement means that release line, e.g. `18` is `18.x`
+        "" | "=" | "==" => has_prefix(version_numbers, bound_numbers),
+        "!=" => !has_prefix(version_numbers, bound_numbers),
+        ">=" => ordering != Ordering::Less,
+        ">" => ordering == Ordering::Greater,
+        "<=" => ordering != Ordering::Greater,
+        "<" => ordering == Ordering::Less,
+        // `^1.2` keeps the first non-zero segment, `^0.3` keeps `0.3`
+        "^" => {
+            let fixed_length = bound_numbers
+                .iter()
+                .position(|bound_number| *bound_number != 0)
+                .map_or(bound_numbers.len(), |nonzero_index| nonzero_index + 1);
+            ordering != Ordering::Less
+                && has_prefix(version_numbers, &bound_numbers[..fixed_length])
+        }
+        // `~3.9` allows `3.9.x`, `~3` allows `3.x`
+        "~" => {
+            let fixed_length = bound_numbers.len().min(2);
+            ordering != Ordering::Less
+                && has_prefix(version_numbers, &bound_numbers[..fixed_length])
+        }
+        // PEP 440 `~=3.9` allows `3.x` from 3.9, `~=3.9.1` allows `3.9.x` from 3.9.1
+        "~=" => {
+            let fixed_length = bound_numbers.len().saturating_sub(1).max(1);
+            ordering != Ordering::Less
+                && has_prefix(version_numbers, &bound_numbers[..fixed_length])
+        }
+        _ => true,
+    }
+}
+
+/// Compare segment by segment; a version shorter than the bound (`18` against
+/// `18.17`) names a whole release line and compares equal
+fn compare_versions(version_numbers: &[u64], bound_numbers: &[u64]) -> Ordering {
+    for segment_index in 0..version_numbers.len().max(bound_numbers.len()) {
+        let Some(version_number) = version_numbers.get(segment_index) else {
+            return Ordering::Equal;
+        };
+        let bound_number = bound_numbers.get(segment_index).unwrap_or(&0);
+        match version_number.cmp(bound_number) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    Ordering::Equal
+}
+
+fn has_prefix(version_numbers: &[u64], prefix_numbers: &[u64]) -> bool {
+    version_numbers
+        .iter()
+        .zip(prefix_numbers.iter())
+        .all(|(version_number, prefix_number)| version_number == prefix_number)
+}
+
+/// `1.22.3` becomes `[1, 22, 3]`; parsing stops at the first non-numeric segment
+fn numeric_segments(version_string: &str) -> Vec<u64> {
+    version_string
+        .trim()
+        .trim_start_matches('v')
+        .split(['.', '-', '+'])
+        .map_while(|segment| segment.parse::<u64>().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version_source(version: &str, source: &str) -> VersionSource {
+        VersionSource {
+            version: version.to_string(),
+            source: source.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_version_satisfies() {
+        assert_eq!(version_satisfies("18", ">=16"), Some(true));
+        assert_eq!(version_satisfies("14.21.3", ">=16"), Some(false));
+        assert_eq!(version_satisfies("3.11", ">=3.9,<3.12"), Some(true));
+        assert_eq!(version_satisfies("3.12", ">=3.9,<3.12"), Some(false));
+        assert_eq!(version_satisfies("18.19.0", "^18.2"), Some(true));
+        assert_eq!(version_satisfies("19.0.0", "^18.2"), Some(false));
+        assert_eq!(version_satisfies("3.9.7", "~3.9"), Some(true));
+        assert_eq!(version_satisfies("4.0", "~=3.9"), Some(false));
+        assert_eq!(version_satisfies("22", ">=16 <21 || 22"), Some(true));
+        assert_eq!(version_satisfies("20", "18.x"), Some(false));
+        assert_eq!(version_satisfies("1.21", ">=1.22.0"), Some(false));
+        assert_eq!(version_satisfies("stable", ">=1.70"), None);
+        assert_eq!(version_satisfies("20", "lts/*"), None);
+    }
+
+    #[test]
+    fn test_check_version_consistency() {
+        let node_consistency = check_version_consistency(
+            "JavaScript",
+            vec![
+                version_source("18", ".nvmrc"),
+                version_source(">=16", "package.json engines.node"),
+                version_source("20", "Dockerfile"),
+            ],
+            vec![
+                version_source("16", ".github/workflows/ci.yml"),
+                version_source("18", ".github/workflows/ci.yml"),
+                version_source("20", ".github/workflows/ci.yml"),
+            ],
+        )
+        .unwrap();
+        assert!(!node_consistency.is_consistent());
+        assert_eq!(node_consistency.conflicts.len(), 1);
+        assert_eq!(node_consistency.conflicts[0].version, "18");
+        assert_eq!(node_consistency.conflicts[0].conflicting_version, "20");
+        assert_eq!(
+            node_consistency.conflicts[0].conflicting_source,
+            "Dockerfile"
+        );
+
+        let go_consistency = check_version_consistency(
+            "Go",
+            vec![
+                version_source(">=1.22", "go.mod go"),
+                version_source("1.21.5", "go.mod toolchain"),
+            ],
+            vec![version_source("1.22", ".gitlab-ci.yml")],
+        )
+        .unwrap();
+        let conflicting_sources: Vec<&str> = go_consistency
+            .conflicts
+            .iter()
+            .map(|conflict| conflict.conflicting_source.as_str())
+            .collect();
+        assert_eq!(
+            conflicting_sources,
+            vec!["go.mod toolchain", ".gitlab-ci.yml"]
+        );
+
+        assert!(check_version_consistency(
+            "Python",
+            vec![version_source("3.11", ".python-version")],
+            Vec::new()
+        )
+        .is_none());
+    }
+}